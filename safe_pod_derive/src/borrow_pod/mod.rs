@@ -0,0 +1,46 @@
+// Define modules
+mod r#struct;
+
+use proc_macro2::TokenStream;
+use syn::{spanned::Spanned, Data, DeriveInput, Error, GenericParam};
+
+/// Implementation of derive macro for BorrowPod trait
+pub fn derive_borrow_pod_impl(input: DeriveInput) -> TokenStream {
+    // If the type that derives BorrowPod is a union
+    // return error
+    if let Data::Union(d) = &input.data {
+        return Error::new(
+            d.union_token.span,
+            "Union types cannot derive BorrowPod"
+        ).to_compile_error();
+    }
+
+    // If the type that derives BorrowPod is an enum
+    // return error
+    if let Data::Enum(d) = &input.data {
+        return Error::new(
+            d.enum_token.span,
+            "Enums cannot derive BorrowPod, derive Pod instead"
+        ).to_compile_error();
+    }
+
+    // Borrowed fields need the lifetime of the input buffer,
+    // which is the struct's only generic parameter
+    let lifetime = match input.generics.params.iter().collect::<Vec<_>>().as_slice() {
+        [GenericParam::Lifetime(l)] => l.lifetime.clone(),
+        _ => {
+            return Error::new(
+                input.generics.span(),
+                "BorrowPod can only be derived for structs with exactly one lifetime parameter"
+            ).to_compile_error();
+        }
+    };
+
+    // If the type that derives BorrowPod is a struct
+    if let Data::Struct(d) = &input.data {
+        let name = input.ident;
+        return r#struct::derive_struct_impl(name, &lifetime, d);
+    }
+
+    return TokenStream::new();
+}
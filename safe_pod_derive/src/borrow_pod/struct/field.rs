@@ -0,0 +1,252 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{spanned::Spanned, Error, Field, Ident, Lifetime, Type};
+
+use crate::dyn_pod::r#struct::field::{collection_decode_expression, conditional_value, field_attribute, vec_element};
+use crate::pod::attributes::FieldAttr;
+use crate::pod::r#struct::field::field_expressions as pod_field_expressions;
+
+/// Generated expressions for a single struct field
+pub struct FieldExpressions {
+    /// Reads the field into its binding from little endian bytes
+    pub decode_le: TokenStream,
+    /// Reads the field into its binding from big endian bytes
+    pub decode_be: TokenStream,
+}
+
+/// Generates the expressions for a struct field, where `binding`
+/// is the variable the field is read into and `lifetime` is the
+/// lifetime of the input buffer
+pub fn field_expressions(field: &Field, binding: &Ident, lifetime: &Lifetime) -> Result<FieldExpressions, TokenStream> {
+    let span = field.span();
+
+    // Get pod attribute
+    let attribute = field_attribute(field)?;
+
+    // Bit fields, skipped fields, padding, positioned fields, constants, validation,
+    // custom codecs and computed fields are only generated for a fixed layout
+    if attribute.bits.is_some() || attribute.skip.is_some()
+        || attribute.pad_before.is_some() || attribute.pad_after.is_some()
+        || attribute.position.is_some() || attribute.align.is_some()
+        || attribute.constant.is_some() || attribute.assert.is_some() || attribute.range.is_some()
+        || attribute.with.is_some() || attribute.map.is_some() || attribute.try_map.is_some()
+        || attribute.calc.is_some() {
+        return Err(Error::new(
+            span,
+            "bits, skip, padding, absolute offsets, align, const, assert, range, with, map, try_map and calc are only supported when deriving Pod"
+        ).to_compile_error());
+    }
+
+    // Conditional fields
+    if let Some(condition) = &attribute.condition {
+        let value_ty = conditional_value(field, &attribute)?;
+
+        let generate = |decode: Ident| -> TokenStream {
+            quote_spanned! {span =>
+                let #binding = if #condition {
+                    let (value, len) = <#value_ty as safe_pod::BorrowPod<#lifetime>>::#decode(&buffer[offset..])?;
+                    offset += len;
+                    Some(value)
+                } else {
+                    None
+                };
+            }
+        };
+
+        return Ok(FieldExpressions {
+            decode_le: generate(format_ident!("decode_borrowed_le")),
+            decode_be: generate(format_ident!("decode_borrowed_be")),
+        });
+    }
+
+    // Collections
+    if attribute.count.is_some() || attribute.len_prefix.is_some() || attribute.until_eof {
+        if attribute.scale.is_some() || attribute.offset.is_some() {
+            return Err(Error::new(
+                span,
+                "count, len_prefix and until_eof fields cannot be scaled"
+            ).to_compile_error());
+        }
+
+        if let Some(convert) = borrowed_slice(&field.ty) {
+            return Ok(FieldExpressions {
+                decode_le: slice_decode_expression(span, &attribute, binding, &convert, &format_ident!("decode_le")),
+                decode_be: slice_decode_expression(span, &attribute, binding, &convert, &format_ident!("decode_be")),
+            });
+        }
+
+        let element = match vec_element(&field.ty) {
+            Some(e) => e,
+            None => {
+                return Err(Error::new(
+                    span,
+                    "count, len_prefix and until_eof fields must be of type Vec<$type>, &[u8] or &str"
+                ).to_compile_error());
+            }
+        };
+
+        // Elements may borrow from the buffer as well
+        let generate = |decode: Ident, decode_borrowed: Ident| -> TokenStream {
+            collection_decode_expression(
+                span,
+                &attribute,
+                binding,
+                &element,
+                &decode,
+                &quote! { <#element as safe_pod::BorrowPod<#lifetime>>::#decode_borrowed }
+            )
+        };
+
+        return Ok(FieldExpressions {
+            decode_le: generate(format_ident!("decode_le"), format_ident!("decode_borrowed_le")),
+            decode_be: generate(format_ident!("decode_be"), format_ident!("decode_borrowed_be")),
+        });
+    }
+
+    // Scaled fields have a fixed size, so reuse the Pod expressions
+    if attribute.scale.is_some() || attribute.offset.is_some() {
+        let expressions = pod_field_expressions(field, binding, &quote! { self.#binding })?;
+
+        return Ok(FieldExpressions {
+            decode_le: expressions.from_le,
+            decode_be: expressions.from_be,
+        });
+    }
+
+    // Slices without a length can't be read
+    if borrowed_slice(&field.ty).is_some() {
+        return Err(Error::new(
+            span,
+            "&[u8] and &str fields need a count, len_prefix or until_eof attribute"
+        ).to_compile_error());
+    }
+
+    let ty = field.ty.clone();
+
+    Ok(FieldExpressions {
+        decode_le: quote_spanned! {span =>
+            let #binding = {
+                let (value, len) = <#ty as safe_pod::BorrowPod<#lifetime>>::decode_borrowed_le(&buffer[offset..])?;
+                offset += len;
+                value
+            };
+        },
+        decode_be: quote_spanned! {span =>
+            let #binding = {
+                let (value, len) = <#ty as safe_pod::BorrowPod<#lifetime>>::decode_borrowed_be(&buffer[offset..])?;
+                offset += len;
+                value
+            };
+        },
+    })
+}
+
+// Gets the expression turning the borrowed `bytes` into
+// the field's value, if the field is a `&[u8]` or `&str`
+fn borrowed_slice(ty: &Type) -> Option<TokenStream> {
+    let reference = match ty {
+        Type::Reference(r) if r.mutability.is_none() => r,
+        _ => return None
+    };
+
+    match &*reference.elem {
+        Type::Slice(s) => match &*s.elem {
+            Type::Path(p) if p.path.is_ident("u8") => return Some(quote! { bytes }),
+            _ => return None
+        },
+        Type::Path(p) if p.path.is_ident("str") => {
+            return Some(quote! {
+                std::str::from_utf8(bytes).map_err(|_| safe_pod::PodError::OutOfRange)?
+            });
+        },
+        _ => return None
+    }
+}
+
+// Generates the expression borrowing a `&[u8]` or `&str` field
+// from the buffer, where `convert` turns the bytes into the field's
+// value and `decode` is the `DynPod` method reading a length prefix
+fn slice_decode_expression(span: Span, attribute: &FieldAttr, binding: &Ident, convert: &TokenStream, decode: &Ident) -> TokenStream {
+    let count = match (&attribute.count, &attribute.len_prefix) {
+        (Some(c), _) => quote_spanned! {span => safe_pod::LengthInt::to_length(#c)? },
+        (None, Some(p)) => quote_spanned! {span =>
+            {
+                let (value, len) = <#p as safe_pod::DynPod>::#decode(&buffer[offset..])?;
+                offset += len;
+                safe_pod::LengthInt::to_length(value)?
+            }
+        },
+        (None, None) => quote_spanned! {span => buffer.len() - offset },
+    };
+
+    quote_spanned! {span =>
+        let #binding = {
+            let count = #count;
+            let bytes = buffer[offset..].get(..count).ok_or(safe_pod::PodError::OutOfSpace)?;
+            offset += count;
+            #convert
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+    use crate::test_utils::named_fields;
+
+    #[test]
+    fn len_prefix_str_field() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo<'a> {
+                #[pod(len_prefix = u8)]
+                name: &'a str,
+            }
+        });
+        let binding = input[0].ident.clone().unwrap();
+        let lifetime: Lifetime = parse_quote! { 'a };
+
+        // Define expected output
+        let expected_decode_be = quote! {
+            let name = {
+                let count = {
+                    let (value, len) = <u8 as safe_pod::DynPod>::decode_be(&buffer[offset..])?;
+                    offset += len;
+                    safe_pod::LengthInt::to_length(value)?
+                };
+                let bytes = buffer[offset..].get(..count).ok_or(safe_pod::PodError::OutOfSpace)?;
+                offset += count;
+                std::str::from_utf8(bytes).map_err(|_| safe_pod::PodError::OutOfRange)?
+            };
+        }.to_string();
+
+        // Output
+        let output = match field_expressions(&input[0], &binding, &lifetime) {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(expected_decode_be, output.decode_be.to_string());
+    }
+
+    #[test]
+    fn slice_field_without_length() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo<'a> {
+                payload: &'a [u8],
+            }
+        });
+        let binding = input[0].ident.clone().unwrap();
+        let lifetime: Lifetime = parse_quote! { 'a };
+
+        // Output
+        let output = field_expressions(&input[0], &binding, &lifetime);
+
+        // Test
+        assert!(output.is_err())
+    }
+}
@@ -0,0 +1,26 @@
+// Define modules
+mod tuple;
+mod named;
+mod field;
+
+use proc_macro2::TokenStream;
+use syn::{DataStruct, Error, Fields, Ident, Lifetime};
+
+/// Implementation of derive macro for structs
+pub fn derive_struct_impl(name: Ident, lifetime: &Lifetime, struct_data: &DataStruct) -> TokenStream {
+    // Match struct type and delegate to appropriate impl functions
+    match &struct_data.fields {
+        Fields::Unit => {
+            return Error::new(
+                name.span(),
+                "Unit structs cannot derive BorrowPod, derive DynPod instead"
+            ).to_compile_error();
+        },
+        Fields::Unnamed(f) => {
+            return tuple::derive_tuple_struct_impl(&name, lifetime, f);
+        },
+        Fields::Named(f) => {
+            return named::derive_named_struct_impl(&name, lifetime, f);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Error, FieldsNamed, Ident, Lifetime};
+
+use super::field;
+use crate::dyn_pod::r#struct::field::field_attribute;
+
+/// Implementation of derive macro for named structs
+pub fn derive_named_struct_impl(name: &Ident, lifetime: &Lifetime, struct_data: &FieldsNamed) -> TokenStream {
+    // Define field names
+    let mut field_names: Vec<Ident> = Vec::new();
+
+    // Define decode le expressions
+    let mut decode_le_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define decode be expressions
+    let mut decode_be_expressions: Vec<TokenStream> = Vec::new();
+
+    // Go over fields and generate expressions
+    let mut until_eof = false;
+    for field in &struct_data.named {
+        // Get field name
+        let field_name = field.ident.clone().unwrap();
+
+        // Nothing is left to decode after an until_eof field
+        if until_eof {
+            return Error::new(
+                field.span(),
+                "until_eof field must be the last field of the struct"
+            ).to_compile_error();
+        }
+
+        // Count must be read before the field it counts
+        match field_attribute(field) {
+            Ok(a) => {
+                if let Some(count) = a.count {
+                    if !field_names.contains(&count) {
+                        return Error::new(
+                            count.span(),
+                            "count must name a field declared before this one"
+                        ).to_compile_error();
+                    }
+                }
+                until_eof = a.until_eof;
+            },
+            Err(e) => return e
+        }
+
+        // Generate expressions
+        let expressions = match field::field_expressions(field, &field_name, lifetime) {
+            Ok(e) => e,
+            Err(e) => return e
+        };
+
+        field_names.push(field_name);
+        decode_le_expressions.push(expressions.decode_le);
+        decode_be_expressions.push(expressions.decode_be);
+    }
+
+    // Generate implementation
+    quote! {
+        impl<#lifetime> safe_pod::BorrowPod<#lifetime> for #name<#lifetime> {
+            #[inline]
+            fn decode_borrowed_le(buffer: &#lifetime [u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                let mut offset = 0usize;
+
+                #(#decode_le_expressions)*
+
+                Ok((Self{ #(#field_names),* }, offset))
+            }
+
+            #[inline]
+            fn decode_borrowed_be(buffer: &#lifetime [u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                let mut offset = 0usize;
+
+                #(#decode_be_expressions)*
+
+                Ok((Self{ #(#field_names),* }, offset))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse2, Data, DeriveInput, Fields, GenericParam};
+
+    use super::*;
+
+    #[test]
+    fn named_struct_borrowed_fields() {
+        // Define input
+        let input_stream = quote! {
+            struct MyNamedStruct<'a> {
+                my_field1: u16,
+                #[pod(count = my_field1)]
+                my_field2: &'a [u8],
+                my_field3: &'a [u8; 4]
+            }
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+        let name_input = input.ident;
+        let lifetime_input = match input.generics.params.first() {
+            Some(GenericParam::Lifetime(l)) => l.lifetime.clone(),
+            _ => unreachable!()
+        };
+        let fields_input = match input.data {
+            Data::Struct(ds) => {
+                match ds.fields {
+                    Fields::Named(f) => {
+                        f
+                    },
+                    _ => unreachable!()
+                }
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_output = quote! {
+            impl<'a> safe_pod::BorrowPod<'a> for MyNamedStruct<'a> {
+                #[inline]
+                fn decode_borrowed_le(buffer: &'a [u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                    let mut offset = 0usize;
+
+                    let my_field1 = {
+                        let (value, len) = <u16 as safe_pod::BorrowPod<'a>>::decode_borrowed_le(&buffer[offset..])?;
+                        offset += len;
+                        value
+                    };
+                    let my_field2 = {
+                        let count = safe_pod::LengthInt::to_length(my_field1)?;
+                        let bytes = buffer[offset..].get(..count).ok_or(safe_pod::PodError::OutOfSpace)?;
+                        offset += count;
+                        bytes
+                    };
+                    let my_field3 = {
+                        let (value, len) = <&'a [u8; 4] as safe_pod::BorrowPod<'a>>::decode_borrowed_le(&buffer[offset..])?;
+                        offset += len;
+                        value
+                    };
+
+                    Ok((Self{ my_field1, my_field2, my_field3 }, offset))
+                }
+
+                #[inline]
+                fn decode_borrowed_be(buffer: &'a [u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                    let mut offset = 0usize;
+
+                    let my_field1 = {
+                        let (value, len) = <u16 as safe_pod::BorrowPod<'a>>::decode_borrowed_be(&buffer[offset..])?;
+                        offset += len;
+                        value
+                    };
+                    let my_field2 = {
+                        let count = safe_pod::LengthInt::to_length(my_field1)?;
+                        let bytes = buffer[offset..].get(..count).ok_or(safe_pod::PodError::OutOfSpace)?;
+                        offset += count;
+                        bytes
+                    };
+                    let my_field3 = {
+                        let (value, len) = <&'a [u8; 4] as safe_pod::BorrowPod<'a>>::decode_borrowed_be(&buffer[offset..])?;
+                        offset += len;
+                        value
+                    };
+
+                    Ok((Self{ my_field1, my_field2, my_field3 }, offset))
+                }
+            }
+        }.to_string();
+
+        // Output
+        let output = derive_named_struct_impl(&name_input, &lifetime_input, &fields_input).to_string();
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+}
@@ -0,0 +1,182 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Error, FieldsUnnamed, Ident, Lifetime};
+
+use super::field;
+use crate::dyn_pod::r#struct::field::field_attribute;
+
+/// Implementation of derive macro for tuple structs
+pub fn derive_tuple_struct_impl(name: &Ident, lifetime: &Lifetime, struct_data: &FieldsUnnamed) -> TokenStream {
+    // Define temporary variable names
+    let mut temp_names: Vec<Ident> = Vec::new();
+
+    // Define decode le expressions
+    let mut decode_le_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define decode be expressions
+    let mut decode_be_expressions: Vec<TokenStream> = Vec::new();
+
+    // Go over fields and generate expressions
+    let mut until_eof = false;
+    for (n, field) in struct_data.unnamed.iter().enumerate() {
+        // Generate temporary name
+        let temp_name = Ident::new(&format!("temp_{}", n), field.span());
+        temp_names.push(temp_name.clone());
+
+        // Nothing is left to decode after an until_eof field
+        if until_eof {
+            return Error::new(
+                field.span(),
+                "until_eof field must be the last field of the struct"
+            ).to_compile_error();
+        }
+
+        // Count and if name fields, so tuple structs can't use them
+        match field_attribute(field) {
+            Ok(a) if a.count.is_some() || a.condition.is_some() => {
+                return Error::new(
+                    field.span(),
+                    "count and if attributes are only supported in structs with named fields"
+                ).to_compile_error();
+            },
+            Ok(a) => until_eof = a.until_eof,
+            Err(e) => return e
+        }
+
+        // Generate expressions
+        let expressions = match field::field_expressions(field, &temp_name, lifetime) {
+            Ok(e) => e,
+            Err(e) => return e
+        };
+
+        decode_le_expressions.push(expressions.decode_le);
+        decode_be_expressions.push(expressions.decode_be);
+    }
+
+    // Generate implementation
+    quote! {
+        impl<#lifetime> safe_pod::BorrowPod<#lifetime> for #name<#lifetime> {
+            #[inline]
+            fn decode_borrowed_le(buffer: &#lifetime [u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                let mut offset = 0usize;
+
+                #(#decode_le_expressions)*
+
+                Ok((Self(#(#temp_names),*), offset))
+            }
+
+            #[inline]
+            fn decode_borrowed_be(buffer: &#lifetime [u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                let mut offset = 0usize;
+
+                #(#decode_be_expressions)*
+
+                Ok((Self(#(#temp_names),*), offset))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse2, Data, DeriveInput, Fields, GenericParam};
+
+    use super::*;
+
+    #[test]
+    fn tuple_struct_until_eof() {
+        // Define input
+        let input_stream = quote! {
+            struct MyTupleStruct<'a>(#[pod(until_eof)] &'a str);
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+        let name_input = input.ident;
+        let lifetime_input = match input.generics.params.first() {
+            Some(GenericParam::Lifetime(l)) => l.lifetime.clone(),
+            _ => unreachable!()
+        };
+        let fields_input = match input.data {
+            Data::Struct(ds) => {
+                match ds.fields {
+                    Fields::Unnamed(f) => {
+                        f
+                    },
+                    _ => unreachable!()
+                }
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_output = quote! {
+            impl<'a> safe_pod::BorrowPod<'a> for MyTupleStruct<'a> {
+                #[inline]
+                fn decode_borrowed_le(buffer: &'a [u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                    let mut offset = 0usize;
+
+                    let temp_0 = {
+                        let count = buffer.len() - offset;
+                        let bytes = buffer[offset..].get(..count).ok_or(safe_pod::PodError::OutOfSpace)?;
+                        offset += count;
+                        std::str::from_utf8(bytes).map_err(|_| safe_pod::PodError::OutOfRange)?
+                    };
+
+                    Ok((Self(temp_0), offset))
+                }
+
+                #[inline]
+                fn decode_borrowed_be(buffer: &'a [u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                    let mut offset = 0usize;
+
+                    let temp_0 = {
+                        let count = buffer.len() - offset;
+                        let bytes = buffer[offset..].get(..count).ok_or(safe_pod::PodError::OutOfSpace)?;
+                        offset += count;
+                        std::str::from_utf8(bytes).map_err(|_| safe_pod::PodError::OutOfRange)?
+                    };
+
+                    Ok((Self(temp_0), offset))
+                }
+            }
+        }.to_string();
+
+        // Output
+        let output = derive_tuple_struct_impl(&name_input, &lifetime_input, &fields_input).to_string();
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn tuple_struct_field_after_until_eof() {
+        // Define input
+        let input_stream = quote! {
+            struct MyTupleStruct<'a>(#[pod(until_eof)] &'a [u8], u16);
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+        let name_input = input.ident;
+        let lifetime_input = match input.generics.params.first() {
+            Some(GenericParam::Lifetime(l)) => l.lifetime.clone(),
+            _ => unreachable!()
+        };
+        let fields_input = match input.data {
+            Data::Struct(ds) => {
+                match ds.fields {
+                    Fields::Unnamed(f) => {
+                        f
+                    },
+                    _ => unreachable!()
+                }
+            },
+            _ => unreachable!()
+        };
+
+        // Output
+        let output = derive_tuple_struct_impl(&name_input, &lifetime_input, &fields_input);
+
+        // Test
+        assert!(output.to_string().contains("until_eof field must be the last field of the struct"))
+    }
+}
@@ -0,0 +1,34 @@
+// Define modules
+pub mod r#struct;
+
+use proc_macro2::TokenStream;
+use syn::{Data, DeriveInput, Error};
+
+/// Implementation of derive macro for DynPod trait
+pub fn derive_dyn_pod_impl(input: DeriveInput) -> TokenStream {
+    // If the type that derives DynPod is a union
+    // return error
+    if let Data::Union(d) = &input.data {
+        return Error::new(
+            d.union_token.span,
+            "Union types cannot derive DynPod"
+        ).to_compile_error();
+    }
+
+    // If the type that derives DynPod is an enum
+    // return error, enums that derive Pod are DynPod already
+    if let Data::Enum(d) = &input.data {
+        return Error::new(
+            d.enum_token.span,
+            "Enums cannot derive DynPod, derive Pod instead"
+        ).to_compile_error();
+    }
+
+    // If the type that derives DynPod is a struct
+    if let Data::Struct(d) = &input.data {
+        let name = input.ident;
+        return r#struct::derive_struct_impl(name, d);
+    }
+
+    return TokenStream::new();
+}
@@ -0,0 +1,647 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{spanned::Spanned, Error, Expr, Field, GenericArgument, Ident, PathArguments, Type};
+
+use crate::pod::attributes::FieldAttr;
+use crate::pod::r#struct::field::field_expressions as pod_field_expressions;
+
+/// Generated expressions for a single struct field
+pub struct FieldExpressions {
+    /// Size of the field's byte representation
+    pub len: TokenStream,
+    /// Reads the field into its binding from little endian bytes
+    pub decode_le: TokenStream,
+    /// Reads the field into its binding from big endian bytes
+    pub decode_be: TokenStream,
+    /// Writes the field as little endian bytes
+    pub encode_le: TokenStream,
+    /// Writes the field as big endian bytes
+    pub encode_be: TokenStream,
+}
+
+/// Gets the `#[pod(...)]` attribute of a field
+pub fn field_attribute(field: &Field) -> Result<FieldAttr, TokenStream> {
+    match FieldAttr::from_attributes(&field.attrs) {
+        Ok(fa) => return Ok(fa),
+        Err(e) => match e {
+            "not found" => return Ok(FieldAttr::default()),
+            _ => {
+                return Err(Error::new(
+                    field.span(),
+                    format!("Error while parsing #[pod(...)] attribute: {}", e)
+                ).to_compile_error());
+            }
+        }
+    }
+}
+
+/// Generates the expressions for a struct field, where `binding`
+/// is the variable the field is read into, `access` is the
+/// expression the field is written from, `length_of` is the
+/// `Vec` field whose length this field holds, if any, and
+/// `fields_before` are the names of the fields declared before it
+pub fn field_expressions(
+    field: &Field,
+    binding: &Ident,
+    access: &TokenStream,
+    length_of: Option<&TokenStream>,
+    fields_before: &[Ident]
+) -> Result<FieldExpressions, TokenStream> {
+    let span = field.span();
+
+    // Get pod attribute
+    let attribute = field_attribute(field)?;
+
+    // Bit fields, skipped fields, padding, positioned fields, constants, validation,
+    // custom codecs and computed fields are only generated for a fixed layout
+    if attribute.bits.is_some() || attribute.skip.is_some()
+        || attribute.pad_before.is_some() || attribute.pad_after.is_some()
+        || attribute.position.is_some() || attribute.align.is_some()
+        || attribute.constant.is_some() || attribute.assert.is_some() || attribute.range.is_some()
+        || attribute.with.is_some() || attribute.map.is_some() || attribute.try_map.is_some()
+        || attribute.calc.is_some() {
+        return Err(Error::new(
+            span,
+            "bits, skip, padding, absolute offsets, align, const, assert, range, with, map, try_map and calc are only supported when deriving Pod"
+        ).to_compile_error());
+    }
+
+    // Conditional fields
+    if let Some(condition) = &attribute.condition {
+        if length_of.is_some() {
+            return Err(Error::new(
+                span,
+                "if fields cannot be used as count"
+            ).to_compile_error());
+        }
+
+        return conditional_expressions(field, binding, access, condition, &attribute, fields_before);
+    }
+
+    // Collections
+    if attribute.count.is_some() || attribute.len_prefix.is_some() || attribute.until_eof {
+        return vec_expressions(field, binding, access, attribute);
+    }
+
+    // Scaled fields have a fixed size, so reuse the Pod expressions
+    if attribute.scale.is_some() || attribute.offset.is_some() {
+        if length_of.is_some() {
+            return Err(Error::new(
+                span,
+                "Scaled fields cannot be used as count"
+            ).to_compile_error());
+        }
+
+        let expressions = pod_field_expressions(field, binding, access)?;
+
+        return Ok(FieldExpressions {
+            len: expressions.size,
+            decode_le: expressions.from_le,
+            decode_be: expressions.from_be,
+            encode_le: expressions.to_le,
+            encode_be: expressions.to_be,
+        });
+    }
+
+    let ty = field.ty.clone();
+
+    // Fields holding the length of a collection are written
+    // from its length, so the two can't disagree
+    let (len, value) = match length_of {
+        Some(v) => {
+            let count = quote! { <#ty as safe_pod::LengthInt>::from_length(#v.len()) };
+            (
+                quote_spanned! {span =>
+                    match #count {
+                        Ok(count) => safe_pod::DynPod::encoded_len(&count),
+                        Err(_) => safe_pod::DynPod::encoded_len(&#access)
+                    }
+                },
+                quote! { #count? }
+            )
+        },
+        None => (quote_spanned! {span => safe_pod::DynPod::encoded_len(&#access)}, access.clone())
+    };
+
+    Ok(FieldExpressions {
+        len,
+        decode_le: quote_spanned! {span =>
+            let #binding = {
+                let (value, len) = <#ty as safe_pod::DynPod>::decode_le(&buffer[offset..])?;
+                offset += len;
+                value
+            };
+        },
+        decode_be: quote_spanned! {span =>
+            let #binding = {
+                let (value, len) = <#ty as safe_pod::DynPod>::decode_be(&buffer[offset..])?;
+                offset += len;
+                value
+            };
+        },
+        encode_le: quote_spanned! {span =>
+            offset += safe_pod::DynPod::encode_le(&#value, &mut buffer[offset..])?;
+        },
+        encode_be: quote_spanned! {span =>
+            offset += safe_pod::DynPod::encode_be(&#value, &mut buffer[offset..])?;
+        },
+    })
+}
+
+/// Gets the element type of a `Vec<T>`
+pub fn vec_element(ty: &Type) -> Option<Type> {
+    generic_argument(ty, "Vec")
+}
+
+/// Gets the value type of an `Option<T>`
+pub fn option_value(ty: &Type) -> Option<Type> {
+    generic_argument(ty, "Option")
+}
+
+/// Checks the `if` attribute of a field, returning the type of its
+/// value, as conditional fields must be an `Option<T>` that isn't
+/// a collection or scaled
+pub fn conditional_value(field: &Field, attribute: &FieldAttr) -> Result<Type, TokenStream> {
+    let span = field.span();
+
+    if attribute.count.is_some() || attribute.len_prefix.is_some() || attribute.until_eof
+        || attribute.scale.is_some() || attribute.offset.is_some() {
+        return Err(Error::new(
+            span,
+            "if fields cannot be collections or scaled"
+        ).to_compile_error());
+    }
+
+    match option_value(&field.ty) {
+        Some(v) => return Ok(v),
+        None => {
+            return Err(Error::new(
+                span,
+                "if fields must be of type Option<$type>"
+            ).to_compile_error());
+        }
+    }
+}
+
+// Gets the type argument of a `name<T>` type
+fn generic_argument(ty: &Type, name: &str) -> Option<Type> {
+    let segment = match ty {
+        Type::Path(p) => p.path.segments.last()?,
+        _ => return None
+    };
+
+    if segment.ident != name {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(a) => match a.args.first() {
+            Some(GenericArgument::Type(t)) => return Some(t.clone()),
+            _ => return None
+        },
+        _ => return None
+    }
+}
+
+// Generates expressions for an `Option<T>` field that is only in the
+// bytes if `condition`, which can refer to the fields before it, is true.
+// On encode, the fields the condition refers to are cloned from `self`,
+// and the condition must match whether the field is `Some`
+fn conditional_expressions(
+    field: &Field,
+    binding: &Ident,
+    access: &TokenStream,
+    condition: &Expr,
+    attribute: &FieldAttr,
+    fields_before: &[Ident]
+) -> Result<FieldExpressions, TokenStream> {
+    let span = field.span();
+    let value_ty = conditional_value(field, attribute)?;
+
+    // Fields the condition refers to
+    let mut referenced: Vec<Ident> = Vec::new();
+    find_idents(condition.to_token_stream(), &mut |ident| {
+        if fields_before.contains(ident) && !referenced.contains(ident) {
+            referenced.push(ident.clone());
+        }
+    });
+
+    let name = binding.to_string().trim_start_matches("r#").to_string();
+    let check = quote! {
+        {
+            #(
+                #[allow(unused_variables)]
+                let #referenced = ::core::clone::Clone::clone(&self.#referenced);
+            )*
+            let present: bool = #condition;
+            if present != #access.is_some() {
+                return Err(safe_pod::PodError::Invalid {
+                    field: #name,
+                    message: String::from("Some and None must match the if condition")
+                });
+            }
+        }
+    };
+
+    // Generates the expressions for one byte order
+    let generate = |decode: Ident, encode: Ident| -> (TokenStream, TokenStream) {
+        let decode_expression = quote_spanned! {span =>
+            let #binding = if #condition {
+                let (value, len) = <#value_ty as safe_pod::DynPod>::#decode(&buffer[offset..])?;
+                offset += len;
+                Some(value)
+            } else {
+                None
+            };
+        };
+
+        let encode_expression = quote_spanned! {span =>
+            #check
+            if let Some(value) = &#access {
+                offset += safe_pod::DynPod::#encode(value, &mut buffer[offset..])?;
+            }
+        };
+
+        (decode_expression, encode_expression)
+    };
+
+    let (decode_le, encode_le) = generate(format_ident!("decode_le"), format_ident!("encode_le"));
+    let (decode_be, encode_be) = generate(format_ident!("decode_be"), format_ident!("encode_be"));
+
+    Ok(FieldExpressions {
+        len: quote_spanned! {span =>
+            match &#access {
+                Some(value) => safe_pod::DynPod::encoded_len(value),
+                None => 0
+            }
+        },
+        decode_le,
+        decode_be,
+        encode_le,
+        encode_be,
+    })
+}
+
+// Calls `f` on every identifier in `tokens`, including nested groups
+fn find_idents(tokens: TokenStream, f: &mut impl FnMut(&Ident)) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => f(&ident),
+            TokenTree::Group(group) => find_idents(group.stream(), f),
+            _ => {}
+        }
+    }
+}
+
+// Generates expressions for a `Vec<T>` field whose length is
+// given by an earlier field, a length prefix or the end of input
+fn vec_expressions(field: &Field, binding: &Ident, access: &TokenStream, attribute: FieldAttr) -> Result<FieldExpressions, TokenStream> {
+    let span = field.span();
+
+    if attribute.scale.is_some() || attribute.offset.is_some() {
+        return Err(Error::new(
+            span,
+            "count, len_prefix and until_eof fields cannot be scaled"
+        ).to_compile_error());
+    }
+
+    let element = match vec_element(&field.ty) {
+        Some(e) => e,
+        None => {
+            return Err(Error::new(
+                span,
+                "count, len_prefix and until_eof fields must be of type Vec<$type>"
+            ).to_compile_error());
+        }
+    };
+
+    // Encoded length of the elements
+    let elements_len = quote! { #access.iter().map(safe_pod::DynPod::encoded_len).sum::<usize>() };
+
+    let len = match &attribute.len_prefix {
+        Some(p) => quote_spanned! {span =>
+            match <#p as safe_pod::LengthInt>::from_length(#access.len()) {
+                Ok(count) => safe_pod::DynPod::encoded_len(&count),
+                Err(_) => 0
+            } + #elements_len
+        },
+        None => quote_spanned! {span => #elements_len}
+    };
+
+    // Generates the expressions for one byte order
+    let generate = |decode: Ident, encode: Ident| -> (TokenStream, TokenStream) {
+        let decode_expression = collection_decode_expression(
+            span,
+            &attribute,
+            binding,
+            &element,
+            &decode,
+            &quote! { <#element as safe_pod::DynPod>::#decode }
+        );
+
+        let prefix_expression = match &attribute.len_prefix {
+            Some(p) => quote_spanned! {span =>
+                offset += safe_pod::DynPod::#encode(
+                    &<#p as safe_pod::LengthInt>::from_length(#access.len())?,
+                    &mut buffer[offset..]
+                )?;
+            },
+            None => TokenStream::new()
+        };
+
+        let encode_expression = quote_spanned! {span =>
+            #prefix_expression
+            for value in #access.iter() {
+                offset += safe_pod::DynPod::#encode(value, &mut buffer[offset..])?;
+            }
+        };
+
+        (decode_expression, encode_expression)
+    };
+
+    let (decode_le, encode_le) = generate(format_ident!("decode_le"), format_ident!("encode_le"));
+    let (decode_be, encode_be) = generate(format_ident!("decode_be"), format_ident!("encode_be"));
+
+    Ok(FieldExpressions { len, decode_le, decode_be, encode_le, encode_be })
+}
+
+/// Generates the expression reading a `Vec<T>` field into its binding,
+/// where `decode` is the `DynPod` method reading a length prefix
+/// and `decode_element` is the function reading one element
+pub fn collection_decode_expression(
+    span: Span,
+    attribute: &FieldAttr,
+    binding: &Ident,
+    element: &Type,
+    decode: &Ident,
+    decode_element: &TokenStream
+) -> TokenStream {
+    match (&attribute.count, &attribute.len_prefix) {
+        (Some(c), _) => quote_spanned! {span =>
+            let #binding = {
+                let count = safe_pod::LengthInt::to_length(#c)?;
+                let mut values: Vec<#element> = Vec::with_capacity(count.min(buffer.len() - offset));
+                for _ in 0..count {
+                    let (value, len) = #decode_element(&buffer[offset..])?;
+                    offset += len;
+                    values.push(value);
+                }
+                values
+            };
+        },
+        (None, Some(p)) => quote_spanned! {span =>
+            let #binding = {
+                let count = {
+                    let (value, len) = <#p as safe_pod::DynPod>::#decode(&buffer[offset..])?;
+                    offset += len;
+                    safe_pod::LengthInt::to_length(value)?
+                };
+                let mut values: Vec<#element> = Vec::with_capacity(count.min(buffer.len() - offset));
+                for _ in 0..count {
+                    let (value, len) = #decode_element(&buffer[offset..])?;
+                    offset += len;
+                    values.push(value);
+                }
+                values
+            };
+        },
+        (None, None) => quote_spanned! {span =>
+            let #binding = {
+                let mut values: Vec<#element> = Vec::new();
+                while offset < buffer.len() {
+                    let (value, len) = #decode_element(&buffer[offset..])?;
+                    if len == 0 {
+                        break;
+                    }
+                    offset += len;
+                    values.push(value);
+                }
+                values
+            };
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::named_fields;
+
+    #[test]
+    fn count_field() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                num_entries: u16,
+                #[pod(count = num_entries)]
+                entries: Vec<u32>,
+            }
+        });
+        let binding = input[0].ident.clone().unwrap();
+        let access = quote! { self.num_entries };
+        let length_of = quote! { self.entries };
+
+        // Define expected output
+        let expected_encode_le = quote! {
+            offset += safe_pod::DynPod::encode_le(
+                &<u16 as safe_pod::LengthInt>::from_length(self.entries.len())?,
+                &mut buffer[offset..]
+            )?;
+        }.to_string();
+
+        // Output
+        let output = match field_expressions(&input[0], &binding, &access, Some(&length_of), &[]) {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(expected_encode_le, output.encode_le.to_string());
+    }
+
+    #[test]
+    fn count_vec_field() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                num_entries: u16,
+                #[pod(count = num_entries)]
+                entries: Vec<u32>,
+            }
+        });
+        let binding = input[1].ident.clone().unwrap();
+        let access = quote! { self.entries };
+
+        // Define expected output
+        let expected_len = quote! {
+            self.entries.iter().map(safe_pod::DynPod::encoded_len).sum::<usize>()
+        }.to_string();
+        let expected_decode_le = quote! {
+            let entries = {
+                let count = safe_pod::LengthInt::to_length(num_entries)?;
+                let mut values: Vec<u32> = Vec::with_capacity(count.min(buffer.len() - offset));
+                for _ in 0..count {
+                    let (value, len) = <u32 as safe_pod::DynPod>::decode_le(&buffer[offset..])?;
+                    offset += len;
+                    values.push(value);
+                }
+                values
+            };
+        }.to_string();
+        let expected_encode_be = quote! {
+            for value in self.entries.iter() {
+                offset += safe_pod::DynPod::encode_be(value, &mut buffer[offset..])?;
+            }
+        }.to_string();
+
+        // Output
+        let output = match field_expressions(&input[1], &binding, &access, None, &[]) {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(expected_len, output.len.to_string());
+        assert_eq!(expected_decode_le, output.decode_le.to_string());
+        assert_eq!(expected_encode_be, output.encode_be.to_string());
+    }
+
+    #[test]
+    fn len_prefix_field() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                #[pod(len_prefix = u32)]
+                entries: Vec<u8>,
+            }
+        });
+        let binding = input[0].ident.clone().unwrap();
+        let access = quote! { self.entries };
+
+        // Define expected output
+        let expected_decode_be = quote! {
+            let entries = {
+                let count = {
+                    let (value, len) = <u32 as safe_pod::DynPod>::decode_be(&buffer[offset..])?;
+                    offset += len;
+                    safe_pod::LengthInt::to_length(value)?
+                };
+                let mut values: Vec<u8> = Vec::with_capacity(count.min(buffer.len() - offset));
+                for _ in 0..count {
+                    let (value, len) = <u8 as safe_pod::DynPod>::decode_be(&buffer[offset..])?;
+                    offset += len;
+                    values.push(value);
+                }
+                values
+            };
+        }.to_string();
+        let expected_encode_le = quote! {
+            offset += safe_pod::DynPod::encode_le(
+                &<u32 as safe_pod::LengthInt>::from_length(self.entries.len())?,
+                &mut buffer[offset..]
+            )?;
+            for value in self.entries.iter() {
+                offset += safe_pod::DynPod::encode_le(value, &mut buffer[offset..])?;
+            }
+        }.to_string();
+
+        // Output
+        let output = match field_expressions(&input[0], &binding, &access, None, &[]) {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(expected_decode_be, output.decode_be.to_string());
+        assert_eq!(expected_encode_le, output.encode_le.to_string());
+    }
+
+    #[test]
+    fn until_eof_field_not_vec() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                #[pod(until_eof)]
+                entries: [u8; 4],
+            }
+        });
+        let binding = input[0].ident.clone().unwrap();
+
+        // Output
+        let output = field_expressions(&input[0], &binding, &quote! { self.entries }, None, &[]);
+
+        // Test
+        assert!(output.is_err())
+    }
+
+    #[test]
+    fn conditional_field() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                flags: u8,
+                version: u8,
+                #[pod(if = flags & 1 != 0)]
+                ext: Option<u32>,
+            }
+        });
+        let binding = input[2].ident.clone().unwrap();
+        let fields_before = [input[0].ident.clone().unwrap(), input[1].ident.clone().unwrap()];
+
+        // Define expected output
+        let expected_decode_le = quote! {
+            let ext = if flags & 1 != 0 {
+                let (value, len) = <u32 as safe_pod::DynPod>::decode_le(&buffer[offset..])?;
+                offset += len;
+                Some(value)
+            } else {
+                None
+            };
+        }.to_string();
+        let expected_encode_be = quote! {
+            {
+                #[allow(unused_variables)]
+                let flags = ::core::clone::Clone::clone(&self.flags);
+                let present: bool = flags & 1 != 0;
+                if present != self.ext.is_some() {
+                    return Err(safe_pod::PodError::Invalid {
+                        field: "ext",
+                        message: String::from("Some and None must match the if condition")
+                    });
+                }
+            }
+            if let Some(value) = &self.ext {
+                offset += safe_pod::DynPod::encode_be(value, &mut buffer[offset..])?;
+            }
+        }.to_string();
+
+        // Output
+        let output = match field_expressions(&input[2], &binding, &quote! { self.ext }, None, &fields_before) {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(expected_decode_le, output.decode_le.to_string());
+        assert_eq!(expected_encode_be, output.encode_be.to_string());
+    }
+
+    #[test]
+    fn conditional_field_not_option() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                flags: u8,
+                #[pod(if = flags != 0)]
+                ext: u32,
+            }
+        });
+        let binding = input[1].ident.clone().unwrap();
+
+        // Output
+        let output = field_expressions(&input[1], &binding, &quote! { self.ext }, None, &[]);
+
+        // Test
+        assert!(output.is_err())
+    }
+}
@@ -0,0 +1,284 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Error, FieldsNamed, Ident};
+
+use super::field;
+
+/// Implementation of derive macro for named structs
+pub fn derive_named_struct_impl(name: &Ident, struct_data: &FieldsNamed) -> TokenStream {
+    // Define length expressions
+    let mut len_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define field names
+    let mut field_names: Vec<Ident> = Vec::new();
+
+    // Define decode le expressions
+    let mut decode_le_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define decode be expressions
+    let mut decode_be_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define encode le expressions
+    let mut encode_le_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define encode be expressions
+    let mut encode_be_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define fields holding the length of a collection,
+    // with the collection they hold the length of
+    let mut length_fields: Vec<(Ident, TokenStream)> = Vec::new();
+
+    // Go over fields and find length fields
+    let mut declared: Vec<Ident> = Vec::new();
+    let mut until_eof = false;
+    for field in &struct_data.named {
+        let field_name = field.ident.clone().unwrap();
+
+        let attribute = match field::field_attribute(field) {
+            Ok(a) => a,
+            Err(e) => return e
+        };
+
+        // Nothing is left to decode after an until_eof field
+        if until_eof {
+            return Error::new(
+                field.span(),
+                "until_eof field must be the last field of the struct"
+            ).to_compile_error();
+        }
+        until_eof = attribute.until_eof;
+
+        if let Some(count) = attribute.count {
+            // Count must be read before the collection
+            if !declared.contains(&count) {
+                return Error::new(
+                    count.span(),
+                    "count must name a field declared before this one"
+                ).to_compile_error();
+            }
+
+            if length_fields.iter().any(|(c, _)| *c == count) {
+                return Error::new(
+                    count.span(),
+                    "count field can only hold the length of one field"
+                ).to_compile_error();
+            }
+
+            length_fields.push((count, quote! { self.#field_name }));
+        }
+
+        declared.push(field_name);
+    }
+
+    // Go over fields and generate expressions
+    for field in &struct_data.named {
+        // Get field name
+        let field_name = field.ident.clone().unwrap();
+        field_names.push(field_name.clone());
+
+        // Generate expressions
+        let length_of = length_fields.iter()
+            .find(|(c, _)| *c == field_name)
+            .map(|(_, v)| v);
+        let expressions = match field::field_expressions(
+            field,
+            &field_name,
+            &quote! { self.#field_name },
+            length_of,
+            &field_names[..field_names.len() - 1]
+        ) {
+            Ok(e) => e,
+            Err(e) => return e
+        };
+
+        len_expressions.push(expressions.len);
+        decode_le_expressions.push(expressions.decode_le);
+        decode_be_expressions.push(expressions.decode_be);
+        encode_le_expressions.push(expressions.encode_le);
+        encode_be_expressions.push(expressions.encode_be);
+    }
+
+    // Generate implementation
+    quote! {
+        impl safe_pod::DynPod for #name {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0usize #(+ #len_expressions)*
+            }
+
+            #[inline]
+            fn decode_le(buffer: &[u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                let mut offset = 0usize;
+
+                #(#decode_le_expressions)*
+
+                Ok((Self{ #(#field_names),* }, offset))
+            }
+
+            #[inline]
+            fn decode_be(buffer: &[u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                let mut offset = 0usize;
+
+                #(#decode_be_expressions)*
+
+                Ok((Self{ #(#field_names),* }, offset))
+            }
+
+            #[inline]
+            fn encode_le(&self, buffer: &mut [u8]) -> Result<usize, safe_pod::PodError> {
+                let mut offset = 0usize;
+
+                #(#encode_le_expressions)*
+
+                Ok(offset)
+            }
+
+            #[inline]
+            fn encode_be(&self, buffer: &mut [u8]) -> Result<usize, safe_pod::PodError> {
+                let mut offset = 0usize;
+
+                #(#encode_be_expressions)*
+
+                Ok(offset)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse2, Data, DeriveInput, Fields};
+
+    use super::*;
+
+    #[test]
+    fn named_struct_fixed_and_dynamic_fields() {
+        // Define input
+        let input_stream = quote! {
+            struct MyNamedStruct{
+                my_field1: u16,
+                my_field2: Uleb128
+            }
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+        let name_input = input.ident;
+        let fields_input = match input.data {
+            Data::Struct(ds) => {
+                match ds.fields {
+                    Fields::Named(f) => {
+                        f
+                    },
+                    _ => unreachable!()
+                }
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_output = quote! {
+            impl safe_pod::DynPod for MyNamedStruct {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0usize
+                        + safe_pod::DynPod::encoded_len(&self.my_field1)
+                        + safe_pod::DynPod::encoded_len(&self.my_field2)
+                }
+
+                #[inline]
+                fn decode_le(buffer: &[u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                    let mut offset = 0usize;
+
+                    let my_field1 = {
+                        let (value, len) = <u16 as safe_pod::DynPod>::decode_le(&buffer[offset..])?;
+                        offset += len;
+                        value
+                    };
+                    let my_field2 = {
+                        let (value, len) = <Uleb128 as safe_pod::DynPod>::decode_le(&buffer[offset..])?;
+                        offset += len;
+                        value
+                    };
+
+                    Ok((Self{ my_field1, my_field2 }, offset))
+                }
+
+                #[inline]
+                fn decode_be(buffer: &[u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                    let mut offset = 0usize;
+
+                    let my_field1 = {
+                        let (value, len) = <u16 as safe_pod::DynPod>::decode_be(&buffer[offset..])?;
+                        offset += len;
+                        value
+                    };
+                    let my_field2 = {
+                        let (value, len) = <Uleb128 as safe_pod::DynPod>::decode_be(&buffer[offset..])?;
+                        offset += len;
+                        value
+                    };
+
+                    Ok((Self{ my_field1, my_field2 }, offset))
+                }
+
+                #[inline]
+                fn encode_le(&self, buffer: &mut [u8]) -> Result<usize, safe_pod::PodError> {
+                    let mut offset = 0usize;
+
+                    offset += safe_pod::DynPod::encode_le(&self.my_field1, &mut buffer[offset..])?;
+                    offset += safe_pod::DynPod::encode_le(&self.my_field2, &mut buffer[offset..])?;
+
+                    Ok(offset)
+                }
+
+                #[inline]
+                fn encode_be(&self, buffer: &mut [u8]) -> Result<usize, safe_pod::PodError> {
+                    let mut offset = 0usize;
+
+                    offset += safe_pod::DynPod::encode_be(&self.my_field1, &mut buffer[offset..])?;
+                    offset += safe_pod::DynPod::encode_be(&self.my_field2, &mut buffer[offset..])?;
+
+                    Ok(offset)
+                }
+            }
+        }.to_string();
+
+        // Output
+        let output = derive_named_struct_impl(&name_input, &fields_input).to_string();
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn named_struct_field_after_until_eof() {
+        // Define input
+        let input_stream = quote! {
+            struct MyNamedStruct{
+                #[pod(until_eof)]
+                my_field1: Vec<u8>,
+                my_field2: u16
+            }
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+        let name_input = input.ident;
+        let fields_input = match input.data {
+            Data::Struct(ds) => {
+                match ds.fields {
+                    Fields::Named(f) => {
+                        f
+                    },
+                    _ => unreachable!()
+                }
+            },
+            _ => unreachable!()
+        };
+
+        // Output
+        let output = derive_named_struct_impl(&name_input, &fields_input);
+
+        // Test
+        assert!(output.to_string().contains("until_eof field must be the last field of the struct"))
+    }
+}
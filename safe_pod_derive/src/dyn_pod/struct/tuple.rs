@@ -0,0 +1,208 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Error, FieldsUnnamed, Ident, Index};
+
+use super::field;
+
+/// Implementation of derive macro for tuple structs
+pub fn derive_tuple_struct_impl(name: &Ident, struct_data: &FieldsUnnamed) -> TokenStream {
+    // Define length expressions
+    let mut len_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define temporary variable names
+    let mut temp_names: Vec<Ident> = Vec::new();
+
+    // Define decode le expressions
+    let mut decode_le_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define decode be expressions
+    let mut decode_be_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define encode le expressions
+    let mut encode_le_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define encode be expressions
+    let mut encode_be_expressions: Vec<TokenStream> = Vec::new();
+
+    // Go over fields and generate expressions
+    let mut until_eof = false;
+    for (n, field) in struct_data.unnamed.iter().enumerate() {
+        // Generate temporary name
+        let temp_name = Ident::new(&format!("temp_{}", n), field.span());
+        temp_names.push(temp_name.clone());
+
+        // Nothing is left to decode after an until_eof field
+        if until_eof {
+            return Error::new(
+                field.span(),
+                "until_eof field must be the last field of the struct"
+            ).to_compile_error();
+        }
+
+        // Count and if name fields, so tuple structs can't use them
+        match field::field_attribute(field) {
+            Ok(a) if a.count.is_some() || a.condition.is_some() => {
+                return Error::new(
+                    field.span(),
+                    "count and if attributes are only supported in structs with named fields"
+                ).to_compile_error();
+            },
+            Ok(a) => until_eof = a.until_eof,
+            Err(e) => return e
+        }
+
+        // Generate expressions
+        let field_index = Index::from(n);
+        let expressions = match field::field_expressions(
+            field,
+            &temp_name,
+            &quote! { self.#field_index },
+            None,
+            &[]
+        ) {
+            Ok(e) => e,
+            Err(e) => return e
+        };
+
+        len_expressions.push(expressions.len);
+        decode_le_expressions.push(expressions.decode_le);
+        decode_be_expressions.push(expressions.decode_be);
+        encode_le_expressions.push(expressions.encode_le);
+        encode_be_expressions.push(expressions.encode_be);
+    }
+
+    // Generate implementation
+    quote! {
+        impl safe_pod::DynPod for #name {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0usize #(+ #len_expressions)*
+            }
+
+            #[inline]
+            fn decode_le(buffer: &[u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                let mut offset = 0usize;
+
+                #(#decode_le_expressions)*
+
+                Ok((Self(#(#temp_names),*), offset))
+            }
+
+            #[inline]
+            fn decode_be(buffer: &[u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                let mut offset = 0usize;
+
+                #(#decode_be_expressions)*
+
+                Ok((Self(#(#temp_names),*), offset))
+            }
+
+            #[inline]
+            fn encode_le(&self, buffer: &mut [u8]) -> Result<usize, safe_pod::PodError> {
+                let mut offset = 0usize;
+
+                #(#encode_le_expressions)*
+
+                Ok(offset)
+            }
+
+            #[inline]
+            fn encode_be(&self, buffer: &mut [u8]) -> Result<usize, safe_pod::PodError> {
+                let mut offset = 0usize;
+
+                #(#encode_be_expressions)*
+
+                Ok(offset)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse2, Data, DeriveInput, Fields};
+
+    use super::*;
+
+    #[test]
+    fn tuple_struct_one_field() {
+        // Define input
+        let input_stream = quote! {
+            struct MyTupleStruct(Sleb128);
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+        let name_input = input.ident;
+        let fields_input = match input.data {
+            Data::Struct(ds) => {
+                match ds.fields {
+                    Fields::Unnamed(f) => {
+                        f
+                    },
+                    _ => unreachable!()
+                }
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_output = quote! {
+            impl safe_pod::DynPod for MyTupleStruct {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0usize + safe_pod::DynPod::encoded_len(&self.0)
+                }
+
+                #[inline]
+                fn decode_le(buffer: &[u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                    let mut offset = 0usize;
+
+                    let temp_0 = {
+                        let (value, len) = <Sleb128 as safe_pod::DynPod>::decode_le(&buffer[offset..])?;
+                        offset += len;
+                        value
+                    };
+
+                    Ok((Self(temp_0), offset))
+                }
+
+                #[inline]
+                fn decode_be(buffer: &[u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                    let mut offset = 0usize;
+
+                    let temp_0 = {
+                        let (value, len) = <Sleb128 as safe_pod::DynPod>::decode_be(&buffer[offset..])?;
+                        offset += len;
+                        value
+                    };
+
+                    Ok((Self(temp_0), offset))
+                }
+
+                #[inline]
+                fn encode_le(&self, buffer: &mut [u8]) -> Result<usize, safe_pod::PodError> {
+                    let mut offset = 0usize;
+
+                    offset += safe_pod::DynPod::encode_le(&self.0, &mut buffer[offset..])?;
+
+                    Ok(offset)
+                }
+
+                #[inline]
+                fn encode_be(&self, buffer: &mut [u8]) -> Result<usize, safe_pod::PodError> {
+                    let mut offset = 0usize;
+
+                    offset += safe_pod::DynPod::encode_be(&self.0, &mut buffer[offset..])?;
+
+                    Ok(offset)
+                }
+            }
+        }.to_string();
+
+        // Output
+        let output = derive_tuple_struct_impl(&name_input, &fields_input).to_string();
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+}
@@ -0,0 +1,36 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Implementation of derive macro for unit structs
+pub fn derive_unit_struct_impl(name: &Ident) -> TokenStream {
+    // Implement DynPod for unit struct
+    quote! {
+        impl safe_pod::DynPod for #name {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0
+            }
+
+            #[inline]
+            fn decode_le(_buffer: &[u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                Ok((Self { }, 0))
+            }
+
+            #[inline]
+            fn decode_be(_buffer: &[u8]) -> Result<(Self, usize), safe_pod::PodError> {
+                Ok((Self { }, 0))
+            }
+
+            #[inline]
+            fn encode_le(&self, _buffer: &mut [u8]) -> Result<usize, safe_pod::PodError> {
+                Ok(0)
+            }
+
+            #[inline]
+            fn encode_be(&self, _buffer: &mut [u8]) -> Result<usize, safe_pod::PodError> {
+                Ok(0)
+            }
+        }
+    }
+}
@@ -1,8 +1,16 @@
 //! Derive macros for ``safe_pod`` crate.
 
+// Explicit returns are used throughout the generators
+#![allow(clippy::needless_return)]
+
 // Declare modules
 mod zeroable;
 mod pod;
+mod dyn_pod;
+mod borrow_pod;
+mod pod_flags;
+#[cfg(test)]
+mod test_utils;
 
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
@@ -76,6 +84,234 @@ pub fn derive_zeroable(input: TokenStream) -> TokenStream {
 /// 
 /// <br />
 /// 
+/// A struct field of type `f64` can be stored as `raw * scale + offset`
+/// in an integer by setting `repr($type)`, `scale = $expression` and
+/// `offset = $expression` in its `#[pod(...)]` attribute. Encoding rounds
+/// to nearest and saturates at the bounds of the integer type.
+/// ```
+/// #[derive(Debug, Pod)]
+/// struct Reading {
+///     #[pod(repr(i16), scale = 0.01, offset = -40.0)]
+///     temperature: f64,
+/// }
+/// ```
+/// 
+/// <br />
+/// 
+/// For named structs the derive also generates an `OFFSET_$FIELD` constant
+/// per field, and `read_$field_le`/`read_$field_be` and
+/// `write_$field_le`/`write_$field_be` helpers that decode or encode only that
+/// field of an encoded struct, in place.
+/// ```
+/// #[derive(Debug, Zeroable, Pod)]
+/// struct FileHeader {
+///     magic: u32,
+///     timestamp: u64,
+/// }
+/// 
+/// FileHeader::write_timestamp_le(&mut header_bytes, now)?;
+/// let timestamp = FileHeader::read_timestamp_le(&header_bytes)?;
+/// assert_eq!(FileHeader::OFFSET_TIMESTAMP, 4);
+/// ```
+/// 
+/// <br />
+/// 
+/// Setting `view` in a named struct's `#[pod(...)]` attribute also generates
+/// `FooView<'a>` and `FooViewMut<'a>` over encoded bytes. Their getters decode
+/// single fields at their offsets, and the mutable view's `set_$field` setters
+/// re-encode them in place. A field whose type has views can be marked with
+/// `#[pod(view)]` to get a nested view instead of a decoded value.
+/// ```
+/// #[derive(Debug, Zeroable, Pod)]
+/// #[pod(view)]
+/// struct Entry {
+///     id: u32,
+///     #[pod(view)]
+///     header: Header,
+/// }
+/// 
+/// let entry = EntryView::new_le(&buffer)?;
+/// let id = entry.id()?;
+/// let kind = entry.header()?.kind()?;
+/// ```
+/// 
+/// <br />
+/// 
+/// Integer, `bool` and enum fields marked `#[pod(bits = N)]` share storage
+/// units with their neighbouring bit fields. The struct's `#[pod(...)]`
+/// attribute can set the unit type with `storage($type)` (`u8` by default)
+/// and the bit numbering with `bit_order = msb` (default) or `bit_order = lsb`.
+/// Enums need a primitive integer `repr` to be used as bit fields.
+/// ```
+/// #[derive(Debug, Zeroable, Pod)]
+/// #[pod(bit_order = lsb, storage(u16))]
+/// struct Control {
+///     #[pod(bits = 1)]
+///     enabled: bool,
+///     #[pod(bits = 3)]
+///     mode: Mode,
+///     #[pod(bits = 12)]
+///     timeout: u16,
+/// }
+/// ```
+/// 
+/// <br />
+/// 
+/// A field marked `skip` isn't encoded and is filled with `Zeroable::zeroed`
+/// on decode, or with `Default::default` if marked `skip = default`.
+/// `pad_before = $integer` and `pad_after = $integer` add padding bytes
+/// around a field, which are written as zeros and ignored on decode,
+/// unless the struct's `#[pod(...)]` attribute has `strict`, in which
+/// case they must be zeros. `Reserved<N, C>` fields carry their own check
+/// instead, so a strict struct requires it spelled out as
+/// `Reserved<N, Strict>` or `Reserved<N, Lenient>`.
+/// ```
+/// #[derive(Debug, Zeroable, Pod)]
+/// #[pod(strict)]
+/// struct Entry {
+///     kind: u8,
+///     #[pod(pad_before = 1)]
+///     length: u16,
+///     #[pod(skip)]
+///     visited: bool,
+/// }
+/// ```
+/// 
+/// <br />
+/// 
+/// The struct's `#[pod(...)]` attribute can have `magic = $bytes`, a
+/// byte string that comes before the fields, and a field can have
+/// `const = $expression`, a value the field always holds. Both are
+/// always written on encode, and decoding fails with `PodError::BadMagic`
+/// if the bytes don't match. Structs with a magic number also get a
+/// `matches` function, which checks if a buffer starts with it.
+/// ```
+/// #[derive(Debug, Zeroable, Pod)]
+/// #[pod(magic = b"RIFF")]
+/// struct Chunk {
+///     size: u32,
+///     #[pod(const = 1)]
+///     version: u16,
+/// }
+/// ```
+/// 
+/// <br />
+/// 
+/// Decoded fields can be checked with `assert = $expression`, which can
+/// refer to the struct's fields by name, and `range = $range`, which
+/// needs the field to implement `Debug`. The struct's `#[pod(...)]`
+/// attribute can also have `validate = $path`, a function taking the
+/// decoded struct and returning `Result<(), PodError>`. Failed checks
+/// return `PodError::Invalid` with the field name and a message.
+/// ```
+/// #[derive(Debug, Zeroable, Pod)]
+/// #[pod(validate = check_position)]
+/// struct Position {
+///     #[pod(assert = version <= 3)]
+///     version: u8,
+///     #[pod(range = -90.0..=90.0)]
+///     latitude: f32,
+///     #[pod(range = -180.0..=180.0)]
+///     longitude: f32,
+/// }
+/// 
+/// fn check_position(position: &Position) -> Result<(), PodError> {
+///     if position.version == 0 && position.latitude != 0.0 {
+///         return Err(PodError::Invalid { field: "latitude", message: String::from("version 0 has no position") });
+///     }
+///     Ok(())
+/// }
+/// ```
+/// 
+/// <br />
+/// 
+/// A field can have a custom codec with `with = $module`, where the module
+/// has a `SIZE` constant and `from_le_bytes`, `from_be_bytes`, `to_le_bytes`
+/// and `to_be_bytes` functions shaped like the ones of `Pod`, which get
+/// exactly `SIZE` bytes. `map = $function` and `try_map = $function` decode
+/// the type in `repr($type)` and convert it, with `try_map` returning
+/// `Result<T, PodError>`, and encode the field through the `From<&T>`
+/// implementation of the type in `repr($type)`.
+/// ```
+/// #[derive(Debug, Zeroable, Pod)]
+/// struct Reading {
+///     #[pod(with = bcd_time)]
+///     time: Time,
+///     #[pod(repr(u8), try_map = Mode::from_raw)]
+///     mode: Mode,
+///     #[pod(repr(i16), map = Celsius::from_tenths)]
+///     temperature: Celsius,
+/// }
+/// ```
+/// 
+/// <br />
+/// 
+/// A field with `calc = $expression` is computed on encode, ignoring its
+/// value, where the expression can use `self` and `Self`. Decoding keeps
+/// the decoded value, unless the field also has `check`, in which case it
+/// must match the computed value, failing with `PodError::Invalid`
+/// otherwise. The field's write helpers and view setter write the value
+/// they're given, which a checked field then has to match to decode.
+/// ```
+/// #[derive(Debug, Zeroable, Pod)]
+/// struct Header {
+///     #[pod(calc = Self::SIZE as u16, check)]
+///     header_size: u16,
+///     #[pod(calc = self.entries.len() as u8)]
+///     count: u8,
+///     entries: [u32; 4],
+/// }
+/// ```
+/// 
+/// <br />
+/// 
+/// A field with `offset = $integer` starts at that absolute offset, spelled
+/// `at = $integer` on fields with `repr` or `scale`, whose `offset` is added
+/// to the value, and a field with `align = $integer` starts at the next
+/// multiple of it. The struct's
+/// `#[pod(...)]` attribute can have `size = $integer`, padding the struct
+/// to exactly that size. The bytes in between are zeros like padding, and
+/// fields overlapping an offset or exceeding the size fail to compile.
+/// ```
+/// #[derive(Debug, Zeroable, Pod)]
+/// #[pod(size = 512)]
+/// struct BootSector {
+///     jump: [u8; 3],
+///     #[pod(offset = 0x0b)]
+///     bytes_per_sector: u16,
+///     #[pod(align = 8)]
+///     volume_id: u64,
+///     #[pod(offset = 0x1fe)]
+///     signature: u16,
+/// }
+/// ```
+/// 
+/// <br />
+/// 
+/// The struct's `#[pod(...)]` attribute can have `layout = "c"`, which
+/// aligns every field and pads the end like a C compiler for the target set
+/// with `target = "x86_64"` (the default), `target = "i386"` or
+/// `target = "arm32"`. Fields must be primitives, arrays or types with a
+/// `C_ALIGN` constant, which C layout structs get. With `twin = $type`, the
+/// offsets and size are checked against a `#[repr(C)]` struct with the same
+/// field names when compiling for the target.
+/// ```
+/// #[repr(C)]
+/// struct RawHeader {
+///     kind: u8,
+///     length: u32,
+/// }
+/// 
+/// #[derive(Debug, Zeroable, Pod)]
+/// #[pod(layout = "c", twin = RawHeader)]
+/// struct Header {
+///     kind: u8,
+///     length: u32,
+/// }
+/// ```
+/// 
+/// <br />
+/// 
 /// In order to derive the `Pod` trait for an enum,
 /// it must be a [unit-like enum](https://doc.rust-lang.org/reference/items/enumerations.html#unit-only-enum).
 /// The enum must have the `#[pod(...)]` atrribute with the inner attribute `repr($type)`
@@ -104,4 +340,168 @@ pub fn derive_pod(input: TokenStream) -> TokenStream {
     pod::derive_pod_impl(
         parse_macro_input!(input as DeriveInput)
     ).into()
-}
\ No newline at end of file
+}
+
+/// Derive macro for the DynPod trait
+/// 
+/// # Example
+/// In order to derive the `DynPod` trait for a struct,
+/// all of its' fields must implement `DynPod`. Every type
+/// that implements `Pod` implements `DynPod` as well, so fixed
+/// and variable size fields can be mixed. Fields are read one
+/// after another and the struct's encoded length is the sum of
+/// its fields' encoded lengths.
+/// ```
+/// #[derive(Debug, DynPod)]
+/// struct Record {
+///     tag: u8,
+///     length: Uleb128,
+///     checksum: u32,
+/// }
+/// 
+/// let (record, bytes_read) = Record::decode_le(&[1, 0xac, 0x02, 0, 0, 0, 0])?;
+/// let mut bytes_from_record = vec![0u8; record.encoded_len()];
+/// let bytes_written = record.encode_be(&mut bytes_from_record)?;
+/// 
+/// println!("Record from {} bytes: {:#?}", bytes_read, record);
+/// println!("Record wrote {} bytes to byte buffer: {:#?}", bytes_written, bytes_from_record);
+/// ```
+/// 
+/// <br />
+/// 
+/// A field of type `Vec<T>` needs one of the following in its `#[pod(...)]`
+/// attribute to know how many elements to read:
+/// - `count = $field` reads as many elements as an earlier integer field says.
+///   On encode the earlier field is written from the `Vec`'s length.
+/// - `len_prefix = $type` reads the number of elements as a `$type`
+///   right before the elements.
+/// - `until_eof` reads elements until the end of the buffer, so it must be
+///   on the last field.
+/// ```
+/// #[derive(Debug, DynPod)]
+/// struct Table {
+///     num_entries: u16,
+///     #[pod(count = num_entries)]
+///     entries: Vec<u32>,
+///     #[pod(len_prefix = u8)]
+///     name: Vec<u8>,
+///     #[pod(until_eof)]
+///     trailer: Vec<u8>,
+/// }
+/// ```
+/// 
+/// <br />
+/// 
+/// A field of type `Option<T>` with `if = $expression` is only read if the
+/// expression, which can refer to earlier fields by name, is true. On encode
+/// the fields the expression refers to are cloned to evaluate it, and it
+/// must be true exactly when the field is `Some`, failing with
+/// `PodError::Invalid` otherwise. `BorrowPod` supports `if` fields as well.
+/// ```
+/// #[derive(Debug, DynPod)]
+/// struct Header {
+///     flags: u8,
+///     #[pod(if = flags & 0x01 != 0)]
+///     extension: Option<u32>,
+/// }
+/// ```
+#[proc_macro_derive(DynPod, attributes(pod))]
+pub fn derive_dyn_pod(input: TokenStream) -> TokenStream {
+    dyn_pod::derive_dyn_pod_impl(
+        parse_macro_input!(input as DeriveInput)
+    ).into()
+}
+
+/// Derive macro for the BorrowPod trait
+/// 
+/// # Example
+/// In order to derive the `BorrowPod` trait for a struct, it must
+/// have exactly one lifetime parameter, which is the lifetime of
+/// the input buffer. Fields can be any `BorrowPod` type, including
+/// every `DynPod` type and `&'a [u8; N]`. Fields of type `&'a [u8]`
+/// and `&'a str` borrow their bytes from the buffer, and need a
+/// `count`, `len_prefix` or `until_eof` attribute like `Vec` fields do.
+/// ```
+/// #[derive(Debug, BorrowPod)]
+/// struct Packet<'a> {
+///     kind: u8,
+///     length: u16,
+///     #[pod(count = length)]
+///     payload: &'a [u8],
+///     #[pod(len_prefix = u8)]
+///     name: &'a str,
+///     checksum: &'a [u8; 4],
+/// }
+/// 
+/// let buffer = [1, 2, 0, 0xaa, 0xbb, 2, b'h', b'i', 1, 2, 3, 4];
+/// let (packet, bytes_read) = Packet::decode_borrowed_le(&buffer)?;
+/// 
+/// println!("Packet from {} bytes: {:#?}", bytes_read, packet);
+/// ```
+#[proc_macro_derive(BorrowPod, attributes(pod))]
+pub fn derive_borrow_pod(input: TokenStream) -> TokenStream {
+    borrow_pod::derive_borrow_pod_impl(
+        parse_macro_input!(input as DeriveInput)
+    ).into()
+}
+
+/// Derive macro for the PodFlags trait
+/// 
+/// # Example
+/// In order to derive the `PodFlags` trait, the type must have the
+/// `#[pod(...)]` attribute with the inner attribute `repr($type)` set
+/// to `u8`, `u16`, `u32` or `u64`. The derive also implements `Pod`,
+/// set operations (`empty`, `all`, `contains`, `insert`, `remove`,
+/// `iter`, `|` and `&`) and a `Debug` that lists the set flags by name.
+/// 
+/// A struct with named `bool` fields is a set of flags itself, and
+/// needs `Zeroable` derived as well. An enum with unit variants gets
+/// a `{Name}Flags` set type. Flags take consecutive bits from bit 0,
+/// unless their `#[pod(...)]` attribute has `bit = $integer`.
+/// ```
+/// #[derive(Clone, Copy, Zeroable, PodFlags)]
+/// #[pod(repr(u8))]
+/// struct Permissions {
+///     read: bool,
+///     write: bool,
+///     #[pod(bit = 7)]
+///     execute: bool,
+/// }
+/// 
+/// #[derive(Debug, Clone, Copy, PodFlags)]
+/// #[pod(repr(u32))]
+/// enum Feature {
+///     Compression,
+///     Encryption,
+///     #[pod(bit = 4)]
+///     Checksum,
+/// }
+/// 
+/// let features = Feature::Compression | Feature::Checksum;
+/// assert!(features.contains(Feature::Checksum));
+/// println!("{:?}", features); // FeatureFlags(Compression | Checksum)
+/// ```
+/// 
+/// <br />
+/// 
+/// Bits that aren't flags are handled on decode according to
+/// `unknown_bits = $policy` in the type's `#[pod(...)]` attribute:
+/// - `reject` (default) fails with `PodError::OutOfRange`.
+/// - `preserve` keeps them so they are encoded again. Structs
+///   keep them in a field of the repr type marked `#[pod(unknown)]`.
+/// - `drop` clears them.
+/// ```
+/// #[derive(Clone, Copy, Zeroable, PodFlags)]
+/// #[pod(repr(u16), unknown_bits = preserve)]
+/// struct Options {
+///     verbose: bool,
+///     #[pod(unknown)]
+///     other: u16,
+/// }
+/// ```
+#[proc_macro_derive(PodFlags, attributes(pod))]
+pub fn derive_pod_flags(input: TokenStream) -> TokenStream {
+    pod_flags::derive_pod_flags_impl(
+        parse_macro_input!(input as DeriveInput)
+    ).into()
+}
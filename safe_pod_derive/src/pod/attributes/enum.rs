@@ -11,17 +11,11 @@ pub struct EnumAttr {
 impl EnumAttr {
     // Extracts EnumAttr from an attribute vector
     pub fn from_attributes(attributes: &Vec<Attribute>) -> Result<Self, &'static str> {
-        // Define fields
-        let mut repr: Option<Type> = None;
-
         // Get inner attributes
-        let attrs = match utils::get_pod(attributes) {
-            Ok(a) => a,
-            Err(e) => return Err(e)
-        };
+        let attrs = utils::get_pod(attributes)?;
 
         // Get attributes
-        repr = match utils::get_repr(&attrs) {
+        let repr: Option<Type> = match utils::get_repr(&attrs) {
             Ok(r) => Some(r),
             Err(e) => match e {
                 "not found" => None,
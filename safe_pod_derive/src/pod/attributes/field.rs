@@ -0,0 +1,503 @@
+use syn::{Attribute, Expr, ExprLit, Ident, Lit, Path, Type};
+
+use super::utils;
+
+/// How a field that isn't encoded is filled on decode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Skip {
+    /// With `Zeroable::zeroed`
+    Zeroed,
+    /// With `Default::default`
+    Default,
+}
+
+/// `pod` attribute struct for a struct field
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct FieldAttr {
+    pub repr: Option<Type>,
+    pub scale: Option<Expr>,
+    pub offset: Option<Expr>,
+    pub position: Option<u32>,
+    pub align: Option<u32>,
+    pub count: Option<Ident>,
+    pub len_prefix: Option<Type>,
+    pub until_eof: bool,
+    pub view: bool,
+    pub bits: Option<u32>,
+    pub skip: Option<Skip>,
+    pub pad_before: Option<u32>,
+    pub pad_after: Option<u32>,
+    pub constant: Option<Expr>,
+    pub assert: Option<Expr>,
+    pub range: Option<Expr>,
+    pub with: Option<Path>,
+    pub map: Option<Path>,
+    pub try_map: Option<Path>,
+    pub calc: Option<Expr>,
+    pub check: bool,
+    pub condition: Option<Expr>,
+}
+
+impl FieldAttr {
+    // Extracts FieldAttr from an attribute vector
+    pub fn from_attributes(attributes: &Vec<Attribute>) -> Result<Self, &'static str> {
+        // Get inner attributes
+        let attrs = utils::get_pod(attributes)?;
+
+        // Get attributes
+        let repr: Option<Type> = match utils::get_repr(&attrs) {
+            Ok(r) => Some(r),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let scale: Option<Expr> = match utils::get_scale(&attrs) {
+            Ok(s) => Some(s),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let offset: Option<Expr> = match utils::get_offset(&attrs) {
+            Ok(o) => Some(o),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let position: Option<u32> = match utils::get_at(&attrs) {
+            Ok(p) => Some(p),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        // Fields without repr or scale are placed with offset, and fields
+        // with them with at, since their offset is added to the value
+        let (offset, position): (Option<Expr>, Option<u32>) = match (offset, position) {
+            (Some(_), Some(_)) if repr.is_none() && scale.is_none() => {
+                return Err("at is only for fields with repr or scale, use offset = $integer");
+            },
+            (Some(o), None) if repr.is_none() && scale.is_none() => match &o {
+                Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) => match i.base10_parse::<u32>() {
+                    Ok(p) => (None, Some(p)),
+                    Err(_) => return Err("offset of a field without repr must be of shape offset = $integer")
+                },
+                _ => return Err("offset of a field without repr must be of shape offset = $integer")
+            },
+            (None, Some(_)) if repr.is_none() && scale.is_none() => {
+                return Err("at is only for fields with repr or scale, use offset = $integer");
+            },
+            (o, p) => (o, p)
+        };
+
+        let align: Option<u32> = match utils::get_align(&attrs) {
+            Ok(a) if a.is_power_of_two() => Some(a),
+            Ok(_) => return Err("align must be a power of two"),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let count: Option<Ident> = match utils::get_count(&attrs) {
+            Ok(c) => Some(c),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let len_prefix: Option<Type> = match utils::get_len_prefix(&attrs) {
+            Ok(l) => Some(l),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let until_eof = match utils::get_until_eof(&attrs) {
+            Ok(_) => true,
+            Err(e) => match e {
+                "not found" => false,
+                _ => return Err(e)
+            }
+        };
+
+        let view = match utils::get_view(&attrs) {
+            Ok(_) => true,
+            Err(e) => match e {
+                "not found" => false,
+                _ => return Err(e)
+            }
+        };
+
+        let bits: Option<u32> = match utils::get_bits(&attrs) {
+            Ok(b) => Some(b),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let skip: Option<Skip> = match utils::get_skip(&attrs) {
+            Ok(s) => Some(s),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let pad_before: Option<u32> = match utils::get_pad_before(&attrs) {
+            Ok(p) => Some(p),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let pad_after: Option<u32> = match utils::get_pad_after(&attrs) {
+            Ok(p) => Some(p),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let constant: Option<Expr> = match utils::get_const(&attrs) {
+            Ok(c) => Some(c),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let assert: Option<Expr> = match utils::get_assert(&attrs) {
+            Ok(a) => Some(a),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let range: Option<Expr> = match utils::get_range(&attrs) {
+            Ok(r) => Some(r),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let with: Option<Path> = match utils::get_with(&attrs) {
+            Ok(w) => Some(w),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let map: Option<Path> = match utils::get_map(&attrs) {
+            Ok(m) => Some(m),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let try_map: Option<Path> = match utils::get_try_map(&attrs) {
+            Ok(m) => Some(m),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let calc: Option<Expr> = match utils::get_calc(&attrs) {
+            Ok(c) => Some(c),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let check = match utils::get_check(&attrs) {
+            Ok(_) => true,
+            Err(e) => match e {
+                "not found" => false,
+                _ => return Err(e)
+            }
+        };
+
+        let condition: Option<Expr> = match utils::get_if(&attrs) {
+            Ok(c) => Some(c),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        // A collection's length comes from exactly one place
+        if count.is_some() as u8 + len_prefix.is_some() as u8 + until_eof as u8 > 1 {
+            return Err("only one of count, len_prefix and until_eof can be set");
+        }
+
+        // A field is placed by at most one of its position and align
+        if position.is_some() && align.is_some() {
+            return Err("only one of a position and align can be set");
+        }
+
+        // A field has at most one custom codec
+        if with.is_some() as u8 + map.is_some() as u8 + try_map.is_some() as u8 > 1 {
+            return Err("only one of with, map and try_map can be set");
+        }
+
+        // Only computed fields have a value to check against
+        if check && calc.is_none() {
+            return Err("check is only for fields with calc");
+        }
+
+        return Ok(FieldAttr {
+            repr, scale, offset, position, align, count, len_prefix, until_eof, view, bits, skip, pad_before, pad_after, constant,
+            assert, range, with, map, try_map, calc, check, condition
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse2, Data, DeriveInput, Fields};
+    use quote::quote;
+
+    use super::*;
+
+    #[test]
+    fn field_attribute_success() {
+        // Define input
+        let input_stream = quote! {
+            struct Foo {
+                #[pod(repr(i16), scale = 0.01, offset = 273.15)]
+                bar: f64,
+            }
+        };
+
+        let input = match parse2::<DeriveInput>(input_stream).unwrap().data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => f.named.first().unwrap().attrs.clone(),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_output = FieldAttr {
+            repr: Some(parse2::<Type>(quote! { i16 }).unwrap()),
+            scale: Some(parse2::<Expr>(quote! { 0.01 }).unwrap()),
+            offset: Some(parse2::<Expr>(quote! { 273.15 }).unwrap()),
+            ..Default::default()
+        };
+
+        // Output
+        let output = FieldAttr::from_attributes(&input).unwrap();
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn field_attribute_fail() {
+        // Define input
+        let input_stream = quote! {
+            struct Foo {
+                #[pod(offset(3))]
+                bar: f64,
+            }
+        };
+
+        let input = match parse2::<DeriveInput>(input_stream).unwrap().data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => f.named.first().unwrap().attrs.clone(),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_output = Err("offset attribute must be of shape offset = $expression");
+
+        // Output
+        let output = FieldAttr::from_attributes(&input);
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn field_attribute_collection() {
+        // Define input
+        let input_stream = quote! {
+            struct Foo {
+                #[pod(len_prefix = u32)]
+                bar: Vec<u8>,
+                #[pod(count = num_entries, until_eof)]
+                baz: Vec<u8>,
+            }
+        };
+
+        let input: Vec<Vec<Attribute>> = match parse2::<DeriveInput>(input_stream).unwrap().data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => f.named.iter().map(|f| f.attrs.clone()).collect(),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_output = FieldAttr {
+            len_prefix: Some(parse2::<Type>(quote! { u32 }).unwrap()),
+            ..Default::default()
+        };
+
+        // Output
+        let output = FieldAttr::from_attributes(&input[0]).unwrap();
+        let output_fail = FieldAttr::from_attributes(&input[1]);
+
+        // Test
+        assert_eq!(expected_output, output);
+        assert_eq!(Err("only one of count, len_prefix and until_eof can be set"), output_fail)
+    }
+
+    #[test]
+    fn field_attribute_codec() {
+        // Define input
+        let input_stream = quote! {
+            struct Foo {
+                #[pod(repr(u8), try_map = Mode::from_raw)]
+                bar: Mode,
+                #[pod(with = bcd_time, map = Time::new)]
+                baz: Time,
+            }
+        };
+
+        let input: Vec<Vec<Attribute>> = match parse2::<DeriveInput>(input_stream).unwrap().data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => f.named.iter().map(|f| f.attrs.clone()).collect(),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_output = FieldAttr {
+            repr: Some(parse2::<Type>(quote! { u8 }).unwrap()),
+            try_map: Some(parse2::<Path>(quote! { Mode::from_raw }).unwrap()),
+            ..Default::default()
+        };
+
+        // Output
+        let output = FieldAttr::from_attributes(&input[0]).unwrap();
+        let output_fail = FieldAttr::from_attributes(&input[1]);
+
+        // Test
+        assert_eq!(expected_output, output);
+        assert_eq!(Err("only one of with, map and try_map can be set"), output_fail)
+    }
+
+    #[test]
+    fn field_attribute_position() {
+        // Define input
+        let input_stream = quote! {
+            struct Foo {
+                #[pod(offset = 0x40)]
+                bar: u32,
+                #[pod(align = 8)]
+                baz: u64,
+                #[pod(align = 6)]
+                qux: u64,
+                #[pod(repr(i16), scale = 0.01, offset = 0x40, at = 0x40)]
+                quux: f64,
+                #[pod(at = 0x40)]
+                corge: u32,
+                #[pod(offset = 1.5)]
+                grault: u32,
+            }
+        };
+
+        let input: Vec<Vec<Attribute>> = match parse2::<DeriveInput>(input_stream).unwrap().data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => f.named.iter().map(|f| f.attrs.clone()).collect(),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_output = [
+            FieldAttr { position: Some(64), ..Default::default() },
+            FieldAttr { align: Some(8), ..Default::default() },
+            FieldAttr {
+                repr: Some(parse2::<Type>(quote! { i16 }).unwrap()),
+                scale: Some(parse2::<Expr>(quote! { 0.01 }).unwrap()),
+                offset: Some(parse2::<Expr>(quote! { 0x40 }).unwrap()),
+                position: Some(64),
+                ..Default::default()
+            },
+        ];
+
+        // Output
+        let output = [
+            FieldAttr::from_attributes(&input[0]).unwrap(),
+            FieldAttr::from_attributes(&input[1]).unwrap(),
+            FieldAttr::from_attributes(&input[3]).unwrap(),
+        ];
+        let output_fail = FieldAttr::from_attributes(&input[2]);
+        let output_at_fail = FieldAttr::from_attributes(&input[4]);
+        let output_offset_fail = FieldAttr::from_attributes(&input[5]);
+
+        // Test
+        assert_eq!(expected_output, output);
+        assert_eq!(Err("align must be a power of two"), output_fail);
+        assert_eq!(Err("at is only for fields with repr or scale, use offset = $integer"), output_at_fail);
+        assert_eq!(Err("offset of a field without repr must be of shape offset = $integer"), output_offset_fail)
+    }
+
+    #[test]
+    fn field_attribute_calc() {
+        // Define input
+        let input_stream = quote! {
+            struct Foo {
+                #[pod(calc = Self::SIZE as u8, check)]
+                bar: u8,
+                #[pod(check)]
+                baz: u8,
+            }
+        };
+
+        let input: Vec<Vec<Attribute>> = match parse2::<DeriveInput>(input_stream).unwrap().data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => f.named.iter().map(|f| f.attrs.clone()).collect(),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_output = FieldAttr {
+            calc: Some(parse2::<Expr>(quote! { Self::SIZE as u8 }).unwrap()),
+            check: true,
+            ..Default::default()
+        };
+
+        // Output
+        let output = FieldAttr::from_attributes(&input[0]).unwrap();
+        let output_fail = FieldAttr::from_attributes(&input[1]);
+
+        // Test
+        assert_eq!(expected_output, output);
+        assert_eq!(Err("check is only for fields with calc"), output_fail)
+    }
+}
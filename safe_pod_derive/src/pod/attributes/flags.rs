@@ -0,0 +1,133 @@
+use syn::{Attribute, Type};
+
+use super::utils;
+
+/// What decoding a flags type does with bits that aren't flags
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum UnknownBits {
+    /// Decoding fails
+    #[default]
+    Reject,
+    /// The bits are kept and encoded again
+    Preserve,
+    /// The bits are cleared
+    Drop,
+}
+
+/// `pod` attribute struct for a type deriving PodFlags
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct FlagsAttr {
+    pub repr: Option<Type>,
+    pub unknown_bits: UnknownBits,
+}
+
+impl FlagsAttr {
+    // Extracts FlagsAttr from an attribute vector
+    pub fn from_attributes(attributes: &Vec<Attribute>) -> Result<Self, &'static str> {
+        // Get inner attributes
+        let attrs = utils::get_pod(attributes)?;
+
+        // Get attributes
+        let repr: Option<Type> = match utils::get_repr(&attrs) {
+            Ok(r) => Some(r),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let unknown_bits = match utils::get_unknown_bits(&attrs) {
+            Ok(u) => u,
+            Err(e) => match e {
+                "not found" => UnknownBits::default(),
+                _ => return Err(e)
+            }
+        };
+
+        return Ok(FlagsAttr { repr, unknown_bits });
+    }
+}
+
+/// `pod` attribute struct for a single flag, which is
+/// a field or a variant of a type deriving PodFlags
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct FlagAttr {
+    pub bit: Option<u32>,
+    pub unknown: bool,
+}
+
+impl FlagAttr {
+    // Extracts FlagAttr from an attribute vector
+    pub fn from_attributes(attributes: &Vec<Attribute>) -> Result<Self, &'static str> {
+        // Get inner attributes
+        let attrs = utils::get_pod(attributes)?;
+
+        // Get attributes
+        let bit: Option<u32> = match utils::get_bit(&attrs) {
+            Ok(b) => Some(b),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let unknown = match utils::get_unknown(&attrs) {
+            Ok(_) => true,
+            Err(e) => match e {
+                "not found" => false,
+                _ => return Err(e)
+            }
+        };
+
+        return Ok(FlagAttr { bit, unknown });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse2, DeriveInput};
+    use quote::quote;
+
+    use super::*;
+
+    #[test]
+    fn flags_attribute_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(repr(u16), unknown_bits = drop, other())]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+
+        // Output
+        let output = FlagsAttr::from_attributes(&input).unwrap();
+
+        // Test
+        assert!(output.repr.is_some());
+        assert_eq!(output.unknown_bits, UnknownBits::Drop)
+    }
+
+    #[test]
+    fn flag_attribute_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(bit = 3, other2 = val)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+
+        // Define expected output
+        let expected_output = FlagAttr {
+            bit: Some(3),
+            unknown: false,
+        };
+
+        // Output
+        let output = FlagAttr::from_attributes(&input).unwrap();
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+}
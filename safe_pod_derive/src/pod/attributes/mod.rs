@@ -4,7 +4,11 @@ mod r#struct;
 mod r#enum;
 mod field;
 mod variant;
+mod flags;
 
 // Re-exports
+pub use r#struct::{BitOrder, CTarget, StructAttr};
 pub use r#enum::EnumAttr;
-pub use variant::VariantAttr;
\ No newline at end of file
+pub use variant::VariantAttr;
+pub use field::{FieldAttr, Skip};
+pub use flags::{FlagAttr, FlagsAttr, UnknownBits};
\ No newline at end of file
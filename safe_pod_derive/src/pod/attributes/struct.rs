@@ -0,0 +1,243 @@
+use syn::{Attribute, Expr, Path, Type};
+
+use super::utils;
+
+/// Order in which bit fields are packed into their storage units
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    /// The first bit field takes the most significant bits
+    #[default]
+    Msb,
+    /// The first bit field takes the least significant bits
+    Lsb,
+}
+
+/// Target whose C compiler is emulated by `layout = "c"`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CTarget {
+    /// 64-bit x86, where 8 byte values are 8 byte aligned
+    #[default]
+    X86_64,
+    /// 32-bit x86, where 8 byte values are 4 byte aligned
+    I386,
+    /// 32-bit ARM, where 8 byte values are 8 byte aligned
+    Arm32,
+}
+
+/// `pod` attribute struct for a struct
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct StructAttr {
+    pub view: bool,
+    pub bit_order: BitOrder,
+    pub storage: Option<Type>,
+    pub strict: bool,
+    pub magic: Option<Expr>,
+    pub validate: Option<Path>,
+    pub size: Option<u32>,
+    pub c_layout: Option<CTarget>,
+    pub twin: Option<Path>,
+}
+
+impl StructAttr {
+    // Extracts StructAttr from an attribute vector
+    pub fn from_attributes(attributes: &Vec<Attribute>) -> Result<Self, &'static str> {
+        // Get inner attributes
+        let attrs = utils::get_pod(attributes)?;
+
+        // Get attributes
+        let view = match utils::get_view(&attrs) {
+            Ok(_) => true,
+            Err(e) => match e {
+                "not found" => false,
+                _ => return Err(e)
+            }
+        };
+
+        let bit_order = match utils::get_bit_order(&attrs) {
+            Ok(o) => o,
+            Err(e) => match e {
+                "not found" => BitOrder::default(),
+                _ => return Err(e)
+            }
+        };
+
+        let storage: Option<Type> = match utils::get_storage(&attrs) {
+            Ok(s) => Some(s),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let strict = match utils::get_strict(&attrs) {
+            Ok(_) => true,
+            Err(e) => match e {
+                "not found" => false,
+                _ => return Err(e)
+            }
+        };
+
+        let magic: Option<Expr> = match utils::get_magic(&attrs) {
+            Ok(m) => Some(m),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let validate: Option<Path> = match utils::get_validate(&attrs) {
+            Ok(v) => Some(v),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let size: Option<u32> = match utils::get_size(&attrs) {
+            Ok(s) => Some(s),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let c_layout = match utils::get_layout(&attrs) {
+            Ok(_) => true,
+            Err(e) => match e {
+                "not found" => false,
+                _ => return Err(e)
+            }
+        };
+
+        let target: Option<CTarget> = match utils::get_target(&attrs) {
+            Ok(t) => Some(t),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        let twin: Option<Path> = match utils::get_twin(&attrs) {
+            Ok(t) => Some(t),
+            Err(e) => match e {
+                "not found" => None,
+                _ => return Err(e)
+            }
+        };
+
+        // The target and twin only apply to a C layout
+        if !c_layout && (target.is_some() || twin.is_some()) {
+            return Err("target and twin require layout = \"c\"");
+        }
+        let c_layout = c_layout.then(|| target.unwrap_or_default());
+
+        return Ok(StructAttr { view, bit_order, storage, strict, magic, validate, size, c_layout, twin });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse2, DeriveInput};
+    use quote::quote;
+
+    use super::*;
+
+    #[test]
+    fn struct_attribute_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(view, other(), other2 = val)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+
+        // Define expected output
+        let expected_output = StructAttr {
+            view: true,
+            ..Default::default()
+        };
+
+        // Output
+        let output = StructAttr::from_attributes(&input).unwrap();
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn struct_attribute_fail() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(view = true)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+
+        // Define expected output
+        let expected_output = Err("view attribute must be of shape view");
+
+        // Output
+        let output = StructAttr::from_attributes(&input);
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn struct_attribute_bits() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(bit_order = lsb, storage(u16), strict)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+
+        // Define expected output
+        let expected_output = StructAttr {
+            bit_order: BitOrder::Lsb,
+            storage: Some(parse2::<Type>(quote! { u16 }).unwrap()),
+            strict: true,
+            ..Default::default()
+        };
+
+        // Output
+        let output = StructAttr::from_attributes(&input).unwrap();
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn struct_attribute_c_layout() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(layout = "c", target = "i386", twin = ffi::Header)]
+            struct Foo;
+        };
+        let input_stream_fail = quote! {
+            #[pod(target = "arm32")]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let input_fail = parse2::<DeriveInput>(input_stream_fail).unwrap().attrs;
+
+        // Define expected output
+        let expected_output = StructAttr {
+            c_layout: Some(CTarget::I386),
+            twin: Some(parse2::<Path>(quote! { ffi::Header }).unwrap()),
+            ..Default::default()
+        };
+
+        // Output
+        let output = StructAttr::from_attributes(&input).unwrap();
+        let output_fail = StructAttr::from_attributes(&input_fail);
+
+        // Test
+        assert_eq!(expected_output, output);
+        assert_eq!(Err("target and twin require layout = \"c\""), output_fail)
+    }
+}
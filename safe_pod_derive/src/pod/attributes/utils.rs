@@ -1,4 +1,28 @@
-use syn::{punctuated::Punctuated, Attribute, Expr, Meta, Token, Type};
+use quote::ToTokens;
+use syn::{
+    ext::IdentExt, parse::ParseStream, punctuated::Punctuated,
+    Attribute, Expr, ExprLit, Ident, Lit, Meta, MetaNameValue, Path, Token, Type
+};
+
+use super::field::Skip;
+use super::flags::UnknownBits;
+use super::r#struct::{BitOrder, CTarget};
+
+// Parses an inner attribute, allowing `const = $expression` and
+// `if = $expression` even though `const` and `if` are keywords,
+// and any expression after `$name =`
+fn parse_inner(input: ParseStream) -> syn::Result<Meta> {
+    if input.peek(Token![const]) || input.peek(Token![if]) || (input.peek(Ident) && input.peek2(Token![=])) {
+        let ident = Ident::parse_any(input)?;
+        return Ok(Meta::NameValue(MetaNameValue {
+            path: Path::from(ident),
+            eq_token: input.parse()?,
+            value: input.parse()?,
+        }));
+    }
+
+    input.parse()
+}
 
 // Utility function to parse `#[pod(..)]` attribute and return inner attributes
 pub fn get_pod(attributes: &Vec<Attribute>) -> Result<Punctuated<Meta, Token![,]>, &'static str> {
@@ -14,7 +38,7 @@ pub fn get_pod(attributes: &Vec<Attribute>) -> Result<Punctuated<Meta, Token![,]
 
             // Parse content into attributes
             let attrs = match contents.parse_args_with(
-                Punctuated::<Meta, Token![,]>::parse_terminated
+                |input: ParseStream| Punctuated::<Meta, Token![,]>::parse_terminated_with(input, parse_inner)
             ) {
                 Ok(a) => a,
                 Err(_) => return Err("pod attribute values must be a comma separated list")
@@ -29,22 +53,27 @@ pub fn get_pod(attributes: &Vec<Attribute>) -> Result<Punctuated<Meta, Token![,]
     return Err("not found");
 }
 
-// Utility function to parse `repr(type)` attribute
-pub fn get_repr(attributes: &Punctuated<Meta, Token![,]>) -> Result<Type, &'static str> {
+// Utility function to parse `$name($type)` attributes
+fn get_list_type(
+    attributes: &Punctuated<Meta, Token![,]>,
+    name: &str,
+    shape_error: &'static str,
+    type_error: &'static str
+) -> Result<Type, &'static str> {
     // Go over attributes
     for attribute in attributes {
-        // If attribute is `repr`
-        if attribute.path().is_ident("repr") {
+        // If attribute is `name`
+        if attribute.path().is_ident(name) {
             // Get contents
             let content = match attribute.require_list() {
                 Ok(l) => l,
-                Err(_) => return Err("repr attribute must be of shape repr($type)")
+                Err(_) => return Err(shape_error)
             };
 
             // Get type
             let ty = match content.parse_args::<Type>() {
                 Ok(t) => t,
-                Err(_) => return Err("repr attribute must contain a type")
+                Err(_) => return Err(type_error)
             };
 
             // Return type
@@ -55,6 +84,16 @@ pub fn get_repr(attributes: &Punctuated<Meta, Token![,]>) -> Result<Type, &'stat
     return Err("not found");
 }
 
+// Utility function to parse `repr(type)` attribute
+pub fn get_repr(attributes: &Punctuated<Meta, Token![,]>) -> Result<Type, &'static str> {
+    get_list_type(attributes, "repr", "repr attribute must be of shape repr($type)", "repr attribute must contain a type")
+}
+
+// Utility function to parse `storage(type)` attribute
+pub fn get_storage(attributes: &Punctuated<Meta, Token![,]>) -> Result<Type, &'static str> {
+    get_list_type(attributes, "storage", "storage attribute must be of shape storage($type)", "storage attribute must contain a type")
+}
+
 // Utility function to parse `match_expr(expr)` attribute
 pub fn get_match_expr(attributes: &Punctuated<Meta, Token![,]>) -> Result<Expr, &'static str> {
     // Go over attributes
@@ -81,10 +120,330 @@ pub fn get_match_expr(attributes: &Punctuated<Meta, Token![,]>) -> Result<Expr,
     return Err("not found");
 }
 
+// Utility function to parse `$name = $expression` attributes
+fn get_name_value_expr(
+    attributes: &Punctuated<Meta, Token![,]>,
+    name: &str,
+    shape_error: &'static str
+) -> Result<Expr, &'static str> {
+    // Go over attributes
+    for attribute in attributes {
+        // If attribute is `name`
+        if attribute.path().is_ident(name) {
+            // Get value
+            let content = match attribute.require_name_value() {
+                Ok(nv) => nv,
+                Err(_) => return Err(shape_error)
+            };
+
+            // Return expression
+            return Ok(content.value.clone())
+        }
+    }
+
+    return Err("not found");
+}
+
+// Utility function to parse `scale = expr` attribute
+pub fn get_scale(attributes: &Punctuated<Meta, Token![,]>) -> Result<Expr, &'static str> {
+    get_name_value_expr(attributes, "scale", "scale attribute must be of shape scale = $expression")
+}
+
+// Utility function to parse `offset = expr` attribute
+pub fn get_offset(attributes: &Punctuated<Meta, Token![,]>) -> Result<Expr, &'static str> {
+    get_name_value_expr(attributes, "offset", "offset attribute must be of shape offset = $expression")
+}
+
+// Utility function to parse `count = field` attribute
+pub fn get_count(attributes: &Punctuated<Meta, Token![,]>) -> Result<Ident, &'static str> {
+    let shape_error = "count attribute must be of shape count = $field";
+
+    // Get expression
+    let expr = get_name_value_expr(attributes, "count", shape_error)?;
+
+    // Get field name
+    match expr {
+        Expr::Path(p) => match p.path.get_ident() {
+            Some(i) => return Ok(i.clone()),
+            None => return Err(shape_error)
+        },
+        _ => return Err(shape_error)
+    }
+}
+
+// Utility function to parse `len_prefix = type` attribute
+pub fn get_len_prefix(attributes: &Punctuated<Meta, Token![,]>) -> Result<Type, &'static str> {
+    let shape_error = "len_prefix attribute must be of shape len_prefix = $type";
+
+    // Get expression
+    let expr = get_name_value_expr(attributes, "len_prefix", shape_error)?;
+
+    // Reparse expression as type
+    match syn::parse2::<Type>(expr.to_token_stream()) {
+        Ok(t) => return Ok(t),
+        Err(_) => return Err(shape_error)
+    }
+}
+
+// Utility function to parse `$name = $integer` attributes
+fn get_name_value_int(
+    attributes: &Punctuated<Meta, Token![,]>,
+    name: &str,
+    shape_error: &'static str
+) -> Result<u32, &'static str> {
+    // Get expression
+    let expr = get_name_value_expr(attributes, name, shape_error)?;
+
+    // Get integer
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) => match i.base10_parse::<u32>() {
+            Ok(b) => return Ok(b),
+            Err(_) => return Err(shape_error)
+        },
+        _ => return Err(shape_error)
+    }
+}
+
+// Utility function to parse `bits = integer` attribute
+pub fn get_bits(attributes: &Punctuated<Meta, Token![,]>) -> Result<u32, &'static str> {
+    get_name_value_int(attributes, "bits", "bits attribute must be of shape bits = $integer")
+}
+
+// Utility function to parse `bit = integer` attribute
+pub fn get_bit(attributes: &Punctuated<Meta, Token![,]>) -> Result<u32, &'static str> {
+    get_name_value_int(attributes, "bit", "bit attribute must be of shape bit = $integer")
+}
+
+// Utility function to parse `const = expr` attribute
+pub fn get_const(attributes: &Punctuated<Meta, Token![,]>) -> Result<Expr, &'static str> {
+    get_name_value_expr(attributes, "const", "const attribute must be of shape const = $expression")
+}
+
+// Utility function to parse `magic = expr` attribute
+pub fn get_magic(attributes: &Punctuated<Meta, Token![,]>) -> Result<Expr, &'static str> {
+    get_name_value_expr(attributes, "magic", "magic attribute must be of shape magic = $expression")
+}
+
+// Utility function to parse `assert = expr` attribute
+pub fn get_assert(attributes: &Punctuated<Meta, Token![,]>) -> Result<Expr, &'static str> {
+    get_name_value_expr(attributes, "assert", "assert attribute must be of shape assert = $expression")
+}
+
+// Utility function to parse `range = expr` attribute
+pub fn get_range(attributes: &Punctuated<Meta, Token![,]>) -> Result<Expr, &'static str> {
+    get_name_value_expr(attributes, "range", "range attribute must be of shape range = $range")
+}
+
+// Utility function to parse `$name = $path` attributes
+fn get_name_value_path(
+    attributes: &Punctuated<Meta, Token![,]>,
+    name: &str,
+    shape_error: &'static str
+) -> Result<Path, &'static str> {
+    match get_name_value_expr(attributes, name, shape_error)? {
+        Expr::Path(p) => return Ok(p.path),
+        _ => return Err(shape_error)
+    }
+}
+
+// Utility function to parse `validate = path` attribute
+pub fn get_validate(attributes: &Punctuated<Meta, Token![,]>) -> Result<Path, &'static str> {
+    get_name_value_path(attributes, "validate", "validate attribute must be of shape validate = $path")
+}
+
+// Utility function to parse `with = path` attribute
+pub fn get_with(attributes: &Punctuated<Meta, Token![,]>) -> Result<Path, &'static str> {
+    get_name_value_path(attributes, "with", "with attribute must be of shape with = $module")
+}
+
+// Utility function to parse `map = path` attribute
+pub fn get_map(attributes: &Punctuated<Meta, Token![,]>) -> Result<Path, &'static str> {
+    get_name_value_path(attributes, "map", "map attribute must be of shape map = $function")
+}
+
+// Utility function to parse `try_map = path` attribute
+pub fn get_try_map(attributes: &Punctuated<Meta, Token![,]>) -> Result<Path, &'static str> {
+    get_name_value_path(attributes, "try_map", "try_map attribute must be of shape try_map = $function")
+}
+
+// Utility function to parse `calc = expr` attribute
+pub fn get_calc(attributes: &Punctuated<Meta, Token![,]>) -> Result<Expr, &'static str> {
+    get_name_value_expr(attributes, "calc", "calc attribute must be of shape calc = $expression")
+}
+
+// Utility function to parse `if = expr` attribute
+pub fn get_if(attributes: &Punctuated<Meta, Token![,]>) -> Result<Expr, &'static str> {
+    get_name_value_expr(attributes, "if", "if attribute must be of shape if = $expression")
+}
+
+// Utility function to parse `at = integer` attribute
+pub fn get_at(attributes: &Punctuated<Meta, Token![,]>) -> Result<u32, &'static str> {
+    get_name_value_int(attributes, "at", "at attribute must be of shape at = $integer")
+}
+
+// Utility function to parse `align = integer` attribute
+pub fn get_align(attributes: &Punctuated<Meta, Token![,]>) -> Result<u32, &'static str> {
+    get_name_value_int(attributes, "align", "align attribute must be of shape align = $integer")
+}
+
+// Utility function to parse `size = integer` attribute
+pub fn get_size(attributes: &Punctuated<Meta, Token![,]>) -> Result<u32, &'static str> {
+    get_name_value_int(attributes, "size", "size attribute must be of shape size = $integer")
+}
+
+// Utility function to parse `pad_before = integer` attribute
+pub fn get_pad_before(attributes: &Punctuated<Meta, Token![,]>) -> Result<u32, &'static str> {
+    get_name_value_int(attributes, "pad_before", "pad_before attribute must be of shape pad_before = $integer")
+}
+
+// Utility function to parse `pad_after = integer` attribute
+pub fn get_pad_after(attributes: &Punctuated<Meta, Token![,]>) -> Result<u32, &'static str> {
+    get_name_value_int(attributes, "pad_after", "pad_after attribute must be of shape pad_after = $integer")
+}
+
+// Utility function to parse `skip` or `skip = default` attribute
+pub fn get_skip(attributes: &Punctuated<Meta, Token![,]>) -> Result<Skip, &'static str> {
+    let shape_error = "skip attribute must be of shape skip or skip = default";
+
+    // Go over attributes
+    for attribute in attributes {
+        // If attribute is `skip`
+        if attribute.path().is_ident("skip") {
+            match attribute {
+                Meta::Path(_) => return Ok(Skip::Zeroed),
+                Meta::NameValue(nv) => match &nv.value {
+                    Expr::Path(p) if p.path.is_ident("default") => return Ok(Skip::Default),
+                    _ => return Err(shape_error)
+                },
+                Meta::List(_) => return Err(shape_error)
+            }
+        }
+    }
+
+    return Err("not found");
+}
+
+// Utility function to parse `bit_order = msb` or `bit_order = lsb` attribute
+pub fn get_bit_order(attributes: &Punctuated<Meta, Token![,]>) -> Result<BitOrder, &'static str> {
+    let shape_error = "bit_order attribute must be of shape bit_order = msb or bit_order = lsb";
+
+    // Get expression
+    let expr = get_name_value_expr(attributes, "bit_order", shape_error)?;
+
+    // Get order
+    match expr {
+        Expr::Path(p) if p.path.is_ident("msb") => return Ok(BitOrder::Msb),
+        Expr::Path(p) if p.path.is_ident("lsb") => return Ok(BitOrder::Lsb),
+        _ => return Err(shape_error)
+    }
+}
+
+// Utility function to parse `$name = "$string"` attributes
+fn get_name_value_str(
+    attributes: &Punctuated<Meta, Token![,]>,
+    name: &str,
+    shape_error: &'static str
+) -> Result<String, &'static str> {
+    match get_name_value_expr(attributes, name, shape_error)? {
+        Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => return Ok(s.value()),
+        _ => return Err(shape_error)
+    }
+}
+
+// Utility function to parse `layout = "c"` attribute
+pub fn get_layout(attributes: &Punctuated<Meta, Token![,]>) -> Result<(), &'static str> {
+    let shape_error = "layout attribute must be of shape layout = \"c\"";
+
+    match get_name_value_str(attributes, "layout", shape_error)?.as_str() {
+        "c" => return Ok(()),
+        _ => return Err(shape_error)
+    }
+}
+
+// Utility function to parse `target = "x86_64"`, `target = "i386"`
+// or `target = "arm32"` attribute
+pub fn get_target(attributes: &Punctuated<Meta, Token![,]>) -> Result<CTarget, &'static str> {
+    let shape_error = "target attribute must be of shape target = \"x86_64\", target = \"i386\" or target = \"arm32\"";
+
+    match get_name_value_str(attributes, "target", shape_error)?.as_str() {
+        "x86_64" => return Ok(CTarget::X86_64),
+        "i386" => return Ok(CTarget::I386),
+        "arm32" => return Ok(CTarget::Arm32),
+        _ => return Err(shape_error)
+    }
+}
+
+// Utility function to parse `twin = path` attribute
+pub fn get_twin(attributes: &Punctuated<Meta, Token![,]>) -> Result<Path, &'static str> {
+    get_name_value_path(attributes, "twin", "twin attribute must be of shape twin = $type")
+}
+
+// Utility function to parse `unknown_bits = reject`, `unknown_bits = preserve`
+// or `unknown_bits = drop` attribute
+pub fn get_unknown_bits(attributes: &Punctuated<Meta, Token![,]>) -> Result<UnknownBits, &'static str> {
+    let shape_error = "unknown_bits attribute must be of shape unknown_bits = reject, unknown_bits = preserve or unknown_bits = drop";
+
+    // Get expression
+    let expr = get_name_value_expr(attributes, "unknown_bits", shape_error)?;
+
+    // Get policy
+    match expr {
+        Expr::Path(p) if p.path.is_ident("reject") => return Ok(UnknownBits::Reject),
+        Expr::Path(p) if p.path.is_ident("preserve") => return Ok(UnknownBits::Preserve),
+        Expr::Path(p) if p.path.is_ident("drop") => return Ok(UnknownBits::Drop),
+        _ => return Err(shape_error)
+    }
+}
+
+// Utility function to parse `$name` attributes without a value
+fn get_flag(
+    attributes: &Punctuated<Meta, Token![,]>,
+    name: &str,
+    shape_error: &'static str
+) -> Result<(), &'static str> {
+    // Go over attributes
+    for attribute in attributes {
+        // If attribute is `name`
+        if attribute.path().is_ident(name) {
+            // Check shape
+            match attribute.require_path_only() {
+                Ok(_) => return Ok(()),
+                Err(_) => return Err(shape_error)
+            }
+        }
+    }
+
+    return Err("not found");
+}
+
+// Utility function to parse `until_eof` attribute
+pub fn get_until_eof(attributes: &Punctuated<Meta, Token![,]>) -> Result<(), &'static str> {
+    get_flag(attributes, "until_eof", "until_eof attribute must be of shape until_eof")
+}
+
+// Utility function to parse `view` attribute
+pub fn get_view(attributes: &Punctuated<Meta, Token![,]>) -> Result<(), &'static str> {
+    get_flag(attributes, "view", "view attribute must be of shape view")
+}
+
+// Utility function to parse `strict` attribute
+pub fn get_strict(attributes: &Punctuated<Meta, Token![,]>) -> Result<(), &'static str> {
+    get_flag(attributes, "strict", "strict attribute must be of shape strict")
+}
+
+// Utility function to parse `check` attribute
+pub fn get_check(attributes: &Punctuated<Meta, Token![,]>) -> Result<(), &'static str> {
+    get_flag(attributes, "check", "check attribute must be of shape check")
+}
+
+// Utility function to parse `unknown` attribute
+pub fn get_unknown(attributes: &Punctuated<Meta, Token![,]>) -> Result<(), &'static str> {
+    get_flag(attributes, "unknown", "unknown attribute must be of shape unknown")
+}
+
 #[cfg(test)]
 mod tests {
-    use proc_macro2::Span;
-    use syn::{parse2, DeriveInput, Ident, Path, TypePath, Data};
+    use syn::{parse2, DeriveInput, Data};
     use quote::quote;
 
     use super::*;
@@ -284,4 +643,367 @@ mod tests {
         assert_eq!(expected_output, output)
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn get_scale_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(repr(u16), scale = 0.01, offset = 273.15)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Output
+        let output = get_scale(&meta_input);
+
+        // Test
+        assert_eq!(output, Ok(parse2::<Expr>(quote! { 0.01 }).unwrap()));
+        assert_eq!(get_offset(&meta_input), Ok(parse2::<Expr>(quote! { 273.15 }).unwrap()));
+    }
+
+    #[test]
+    fn get_scale_fail() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(scale(0.01))]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Define expected output
+        let expected_output = Err("scale attribute must be of shape scale = $expression");
+
+        // Output
+        let output = get_scale(&meta_input);
+
+        // Test
+        assert_eq!(expected_output, output);
+        assert_eq!(get_offset(&meta_input), Err("not found"));
+    }
+
+    #[test]
+    fn get_count_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(count = num_entries)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Define expected output
+        let expected_output = Ok(parse2::<Ident>(quote! { num_entries }).unwrap());
+
+        // Output
+        let output = get_count(&meta_input);
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn get_count_fail() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(count = 3 + 4)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Define expected output
+        let expected_output = Err("count attribute must be of shape count = $field");
+
+        // Output
+        let output = get_count(&meta_input);
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn get_len_prefix_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(len_prefix = U16Be)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Define expected output
+        let expected_output = Ok(parse2::<Type>(quote! { U16Be }).unwrap());
+
+        // Output
+        let output = get_len_prefix(&meta_input);
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn get_until_eof_fail() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(until_eof(true))]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Define expected output
+        let expected_output = Err("until_eof attribute must be of shape until_eof");
+
+        // Output
+        let output = get_until_eof(&meta_input);
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn get_view_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(view)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Output
+        let output = get_view(&meta_input);
+
+        // Test
+        assert!(output.is_ok())
+    }
+
+    #[test]
+    fn get_bits_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(bits = 4)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Output
+        let output = get_bits(&meta_input);
+
+        // Test
+        assert_eq!(Ok(4), output)
+    }
+
+    #[test]
+    fn get_bits_fail() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(bits = four)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Define expected output
+        let expected_output = Err("bits attribute must be of shape bits = $integer");
+
+        // Output
+        let output = get_bits(&meta_input);
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn get_bit_order_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(bit_order = lsb)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Output
+        let output = get_bit_order(&meta_input);
+
+        // Test
+        assert_eq!(Ok(BitOrder::Lsb), output)
+    }
+
+    #[test]
+    fn get_bit_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(bits = 4, bit = 7)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Output
+        let output = get_bit(&meta_input);
+
+        // Test
+        assert_eq!(Ok(7), output)
+    }
+
+    #[test]
+    fn get_unknown_bits_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(unknown_bits = preserve)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Output
+        let output = get_unknown_bits(&meta_input);
+
+        // Test
+        assert_eq!(Ok(UnknownBits::Preserve), output)
+    }
+
+    #[test]
+    fn get_unknown_bits_fail() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(unknown_bits = keep)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Define expected output
+        let expected_output = Err("unknown_bits attribute must be of shape unknown_bits = reject, unknown_bits = preserve or unknown_bits = drop");
+
+        // Output
+        let output = get_unknown_bits(&meta_input);
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn get_skip_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(skip)]
+            struct Foo;
+        };
+        let input_stream2 = quote! {
+            #[pod(skip = default)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+        let input2 = parse2::<DeriveInput>(input_stream2).unwrap().attrs;
+        let meta_input2 = get_pod(&input2).unwrap();
+
+        // Output
+        let output = get_skip(&meta_input);
+        let output2 = get_skip(&meta_input2);
+
+        // Test
+        assert_eq!(Ok(Skip::Zeroed), output);
+        assert_eq!(Ok(Skip::Default), output2)
+    }
+
+    #[test]
+    fn get_skip_fail() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(skip(zeroed))]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Define expected output
+        let expected_output = Err("skip attribute must be of shape skip or skip = default");
+
+        // Output
+        let output = get_skip(&meta_input);
+
+        // Test
+        assert_eq!(expected_output, output)
+    }
+
+    #[test]
+    fn get_pad_after_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(pad_before = 2, pad_after = 3)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Output
+        let output = get_pad_after(&meta_input);
+
+        // Test
+        assert_eq!(Ok(3), output)
+    }
+
+    #[test]
+    fn get_const_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(const = 0xa1b2c3d4, magic = b"RIFF")]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Output
+        let output = get_const(&meta_input).map(|e| e.to_token_stream().to_string());
+        let output_magic = get_magic(&meta_input).map(|e| e.to_token_stream().to_string());
+
+        // Test
+        assert_eq!(Ok(String::from("0xa1b2c3d4")), output);
+        assert_eq!(Ok(String::from("b\"RIFF\"")), output_magic)
+    }
+
+    #[test]
+    fn get_validation_success() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(assert = a < b, range = -1..=1, validate = checks::foo)]
+            struct Foo;
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap().attrs;
+        let meta_input = get_pod(&input).unwrap();
+
+        // Output
+        let output_assert = get_assert(&meta_input).map(|e| e.to_token_stream().to_string());
+        let output_range = get_range(&meta_input).map(|e| e.to_token_stream().to_string());
+        let output_validate = get_validate(&meta_input).map(|p| p.to_token_stream().to_string());
+
+        // Test
+        assert_eq!(Ok(String::from("a < b")), output_assert);
+        assert_eq!(Ok(String::from("- 1 ..= 1")), output_range);
+        assert_eq!(Ok(String::from("checks :: foo")), output_validate)
+    }
+}
@@ -11,17 +11,11 @@ pub struct VariantAttr {
 impl VariantAttr {
     // Extracts EnumAttr from an attribute vector
     pub fn from_attributes(attributes: &Vec<Attribute>) -> Result<Self, &'static str> {
-        // Define fields
-        let mut match_expr: Option<Expr> = None;
-
         // Get inner attributes
-        let attrs = match utils::get_pod(attributes) {
-            Ok(a) => a,
-            Err(e) => return Err(e)
-        };
+        let attrs = utils::get_pod(attributes)?;
 
         // Get attributes
-        match_expr = match utils::get_match_expr(&attrs) {
+        let match_expr: Option<Expr> = match utils::get_match_expr(&attrs) {
             Ok(e) => Some(e),
             Err(e) => match e {
                 "not found" => None,
@@ -3,7 +3,7 @@ mod unit;
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{spanned::Spanned, Attribute, DataEnum, Error, Fields, Ident};
+use syn::{spanned::Spanned, Attribute, DataEnum, Error, Fields, Ident, Type};
 
 use super::attributes::{EnumAttr, VariantAttr};
 
@@ -129,8 +129,38 @@ pub fn derive_enum_impl(name: Ident, attributes: Vec<Attribute>, enum_data: &Dat
         }
     }
 
-    
+    // Enums with a primitive integer repr can be stored in bit fields
+    let primitive_repr = ["u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64"].iter()
+        .any(|p| matches!(&repr, Type::Path(t) if t.path.is_ident(p)));
+    let bit_field_impl = match primitive_repr {
+        true => quote! {
+            impl safe_pod::BitField for #name {
+                #[inline]
+                fn from_bits(bits: u64, width: u32) -> Result<Self, safe_pod::PodError> {
+                    let val = match <#repr as safe_pod::BitField>::from_bits(bits, width)? {
+                        #(#from_le_expressions)*
+                        _ => { return Err(safe_pod::PodError::OutOfRange); }
+                    };
+
+                    Ok(val)
+                }
+
+                #[inline]
+                fn to_bits(&self, width: u32) -> Result<u64, safe_pod::PodError> {
+                    let val: #repr = match &self {
+                        #(#to_le_expressions)*
+                    };
+
+                    safe_pod::BitField::to_bits(&val, width)
+                }
+            }
+        },
+        false => TokenStream::new()
+    };
+
     quote! {
+        #bit_field_impl
+
         impl safe_pod::Pod for #name {
             const SIZE: usize = <#repr as safe_pod::Pod>::SIZE ;
 
@@ -213,6 +243,29 @@ mod tests {
 
         // Define expected output
         let expected_output = quote! {
+            impl safe_pod::BitField for UnitLike {
+                #[inline]
+                fn from_bits(bits: u64, width: u32) -> Result<Self, safe_pod::PodError> {
+                    let val = match <u8 as safe_pod::BitField>::from_bits(bits, width)? {
+                        0 => { Self::Foo },
+                        1 => { Self::Bar },
+                        _ => { return Err(safe_pod::PodError::OutOfRange); }
+                    };
+
+                    Ok(val)
+                }
+
+                #[inline]
+                fn to_bits(&self, width: u32) -> Result<u64, safe_pod::PodError> {
+                    let val: u8 = match &self {
+                        Self::Foo => { 0 },
+                        Self::Bar => { 1 },
+                    };
+
+                    safe_pod::BitField::to_bits(&val, width)
+                }
+            }
+
             impl safe_pod::Pod for UnitLike {
                 const SIZE: usize = <u8 as safe_pod::Pod>::SIZE ;
 
@@ -1,6 +1,6 @@
 // Define modules
-mod attributes;
-mod r#struct;
+pub mod attributes;
+pub mod r#struct;
 mod r#enum;
 
 use proc_macro2::TokenStream;
@@ -20,7 +20,7 @@ pub fn derive_pod_impl(input: DeriveInput) -> TokenStream {
     // If the type that derives Pod is a struct
     if let Data::Struct(d) = &input.data {
         let name = input.ident;
-        return r#struct::derive_struct_impl(name, d);
+        return r#struct::derive_struct_impl(name, input.vis, input.attrs, d);
     }
 
     // If the type that derives Pod is an enum
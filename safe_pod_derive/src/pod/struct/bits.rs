@@ -0,0 +1,1018 @@
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{parse_quote, spanned::Spanned, Error, Expr, Field, Ident, PathArguments, Type};
+
+use super::field::{self, FieldExpressions};
+use super::super::attributes::{BitOrder, CTarget, FieldAttr, Skip, StructAttr};
+
+/// A struct field with the variable it is read into
+/// and the expression it is written from
+pub struct LayoutField<'a> {
+    pub field: &'a Field,
+    pub binding: Ident,
+    pub access: TokenStream,
+}
+
+/// Fields of a struct in encoding order, with consecutive
+/// bit fields grouped into runs
+pub enum LayoutItem<'a> {
+    /// A field with bytes of its own
+    Field(LayoutField<'a>),
+    /// Bit fields sharing storage units
+    Run(Box<BitRun<'a>>),
+    /// Bytes that are written as zeros, and must be zeros
+    /// on decode if `strict`
+    Padding { size: u32, strict: bool },
+    /// A field that isn't encoded, and is filled on decode
+    Skipped(LayoutField<'a>, Skip),
+    /// The struct's magic number, which is checked on decode
+    Magic(Expr),
+    /// Zeros up to an absolute offset, an alignment or the struct's
+    /// size, with the compile time check that the bytes before fit
+    Gap { size: TokenStream, strict: bool, check: Option<TokenStream> },
+}
+
+/// A bit field in a run
+pub struct RunField<'a> {
+    pub field: LayoutField<'a>,
+    width: u32,
+    shift: u32,
+}
+
+/// Consecutive bit fields packed into storage units, which are
+/// read into and written from a `u64` accumulator
+pub struct BitRun<'a> {
+    pub fields: Vec<RunField<'a>>,
+    storage: Type,
+    unit_bits: u32,
+    units: u32,
+    order: BitOrder,
+    accumulator: Ident,
+}
+
+/// Groups struct fields into layout items according to the struct's
+/// `bit_order`, `storage`, `strict`, `magic`, `size` and `layout` attributes
+pub fn layout<'a>(fields: Vec<LayoutField<'a>>, attribute: &StructAttr) -> Result<Vec<LayoutItem<'a>>, TokenStream> {
+    let storage: Type = attribute.storage.clone().unwrap_or_else(|| parse_quote! { u8 });
+    let unit_bits: u32 = match &storage {
+        Type::Path(p) if p.path.is_ident("u8") => 8,
+        Type::Path(p) if p.path.is_ident("u16") => 16,
+        Type::Path(p) if p.path.is_ident("u32") => 32,
+        Type::Path(p) if p.path.is_ident("u64") => 64,
+        _ => {
+            return Err(Error::new(
+                storage.span(),
+                "storage must be one of u8, u16, u32 and u64"
+            ).to_compile_error());
+        }
+    };
+
+    // Get the struct's alignment in C layout
+    let c_align = match attribute.c_layout {
+        Some(target) => Some(c_struct_align(fields.iter().map(|f| f.field), target)?),
+        None => None
+    };
+
+    // Define layout items, starting with the magic number
+    let mut items: Vec<LayoutItem<'a>> = Vec::new();
+    if let Some(magic) = &attribute.magic {
+        items.push(LayoutItem::Magic(magic.clone()));
+    }
+
+    // Define bit fields of the current run, with their widths
+    let mut run: Vec<(LayoutField<'a>, u32)> = Vec::new();
+
+    // Define the offset of the last gap and the index of the item after it,
+    // so offsets are computed from there instead of from the start
+    let mut anchor: (TokenStream, usize) = (quote! { 0usize }, 0);
+
+    // Go over fields and group bit fields
+    for layout_field in fields {
+        let span = layout_field.field.span();
+
+        // Get pod attribute
+        let field_attribute = match FieldAttr::from_attributes(&layout_field.field.attrs) {
+            Ok(fa) => fa,
+            Err(e) => match e {
+                "not found" => FieldAttr::default(),
+                _ => {
+                    return Err(Error::new(
+                        span,
+                        format!("Error while parsing #[pod(...)] attribute: {}", e)
+                    ).to_compile_error());
+                }
+            }
+        };
+
+        // Skipped fields take no bytes, so they don't end the run
+        if let Some(skip) = field_attribute.skip {
+            if field_attribute != (FieldAttr { skip: Some(skip), ..Default::default() }) {
+                return Err(Error::new(
+                    span,
+                    "skip fields cannot have other pod attributes"
+                ).to_compile_error());
+            }
+
+            items.push(LayoutItem::Skipped(layout_field, skip));
+            continue;
+        }
+
+        let width = match field_attribute.bits {
+            Some(w) => w,
+            None => {
+                // Reserved bytes don't follow the struct's strict, so their
+                // check must be spelled out for it not to be missed
+                if attribute.strict && is_default_reserved(&layout_field.field.ty) {
+                    return Err(Error::new(
+                        span,
+                        "Reserved fields of a strict struct must be Reserved<N, Strict> or Reserved<N, Lenient>"
+                    ).to_compile_error());
+                }
+
+                // A field with bytes of its own ends the run
+                if !run.is_empty() {
+                    let run_index = items.len();
+                    items.push(LayoutItem::Run(Box::new(bit_run(
+                        std::mem::take(&mut run), &storage, unit_bits, attribute.bit_order, run_index
+                    )?)));
+                }
+
+                if let Some(size @ 1..) = field_attribute.pad_before {
+                    items.push(LayoutItem::Padding { size, strict: attribute.strict });
+                }
+
+                // Fill up to the field's absolute offset or alignment
+                if let Some(position) = field_attribute.position {
+                    let target = Literal::usize_suffixed(position as usize).to_token_stream();
+                    let message = format!("field at offset {} overlaps the fields before it", position);
+                    push_gap(&mut items, &mut anchor, target, attribute.strict, Some((span, message)))?;
+                }
+                if let Some(align) = field_align(layout_field.field, &field_attribute, attribute.c_layout) {
+                    let before = offset_since(&items, &anchor)?;
+                    push_gap(&mut items, &mut anchor, quote! { (#before).next_multiple_of(#align) }, attribute.strict, None)?;
+                }
+
+                items.push(LayoutItem::Field(layout_field));
+                if let Some(size @ 1..) = field_attribute.pad_after {
+                    items.push(LayoutItem::Padding { size, strict: attribute.strict });
+                }
+                continue;
+            }
+        };
+
+        if attribute.c_layout.is_some() {
+            return Err(Error::new(
+                span,
+                "bits fields are not supported with layout = \"c\""
+            ).to_compile_error());
+        }
+
+        if width == 0 || width > 64 {
+            return Err(Error::new(
+                span,
+                "bits must be between 1 and 64"
+            ).to_compile_error());
+        }
+
+        if field_attribute.scale.is_some() || field_attribute.offset.is_some() || field_attribute.view
+            || field_attribute.position.is_some() || field_attribute.align.is_some()
+            || field_attribute.pad_before.is_some() || field_attribute.pad_after.is_some()
+            || field_attribute.constant.is_some() || field_attribute.with.is_some()
+            || field_attribute.map.is_some() || field_attribute.try_map.is_some() {
+            return Err(Error::new(
+                span,
+                "bits fields cannot be scaled, padded, positioned, constant, mapped or have views"
+            ).to_compile_error());
+        }
+
+        run.push((layout_field, width));
+    }
+
+    if !run.is_empty() {
+        let run_index = items.len();
+        items.push(LayoutItem::Run(Box::new(bit_run(run, &storage, unit_bits, attribute.bit_order, run_index)?)));
+    }
+
+    // Pad to a multiple of the struct's alignment like a C compiler
+    if let Some(align) = c_align {
+        let before = offset_since(&items, &anchor)?;
+        push_gap(&mut items, &mut anchor, quote! { (#before).next_multiple_of(#align) }, attribute.strict, None)?;
+    }
+
+    // Fill up to the struct's size
+    if let Some(size) = attribute.size {
+        let target = Literal::usize_suffixed(size as usize).to_token_stream();
+        let message = format!("fields exceed the struct size of {} bytes", size);
+        push_gap(&mut items, &mut anchor, target, attribute.strict, Some((Span::call_site(), message)))?;
+    }
+
+    Ok(items)
+}
+
+// Gets the alignment of a type in C on `target`, if it's
+// a primitive or an array of primitives
+fn primitive_align(ty: &Type, target: CTarget) -> Option<u32> {
+    let ident = match ty {
+        Type::Array(a) => return primitive_align(&a.elem, target),
+        Type::Path(p) => p.path.get_ident()?.to_string(),
+        _ => return None
+    };
+
+    match (ident.as_str(), target) {
+        ("u8" | "i8" | "bool", _) => return Some(1),
+        ("u16" | "i16", _) => return Some(2),
+        ("u32" | "i32" | "f32" | "char", _) => return Some(4),
+        ("u64" | "i64" | "f64", CTarget::I386) => return Some(4),
+        ("u64" | "i64" | "f64", _) => return Some(8),
+        ("u128" | "i128", CTarget::Arm32) => return Some(8),
+        ("u128" | "i128", _) => return Some(16),
+        _ => return None
+    }
+}
+
+// Gets the alignment a field starts at, which is the larger of its `align`
+// attribute and, in C layout, the alignment of its type or `repr`. Other
+// types than primitives have their alignment in a `C_ALIGN` constant
+fn field_align(field: &Field, attribute: &FieldAttr, c_layout: Option<CTarget>) -> Option<TokenStream> {
+    let span = field.span();
+    let ty = attribute.repr.as_ref().unwrap_or(&field.ty);
+
+    let natural = match c_layout.map(|target| primitive_align(ty, target)) {
+        None | Some(Some(1)) => None,
+        Some(Some(a)) => Some(Literal::usize_suffixed(a as usize).to_token_stream()),
+        Some(None) => Some(quote_spanned! {span => <#ty>::C_ALIGN }),
+    };
+    let align = attribute.align.map(|a| Literal::usize_suffixed(a as usize));
+
+    match (natural, align) {
+        (None, None) => return None,
+        (Some(n), None) => return Some(n),
+        (None, Some(a)) => return Some(a.to_token_stream()),
+        (Some(n), Some(a)) => return Some(quote! { { let natural = #n; if natural > #a { natural } else { #a } } }),
+    }
+}
+
+/// Gets the alignment of a struct in C layout on `target`,
+/// which is the largest alignment of its encoded fields
+pub fn c_struct_align<'a>(fields: impl Iterator<Item = &'a Field>, target: CTarget) -> Result<TokenStream, TokenStream> {
+    let mut aligns: Vec<TokenStream> = Vec::new();
+    for field in fields {
+        let field_attribute = match FieldAttr::from_attributes(&field.attrs) {
+            Ok(fa) => fa,
+            Err(e) => match e {
+                "not found" => FieldAttr::default(),
+                _ => {
+                    return Err(Error::new(
+                        field.span(),
+                        format!("Error while parsing #[pod(...)] attribute: {}", e)
+                    ).to_compile_error());
+                }
+            }
+        };
+
+        if field_attribute.skip.is_none() {
+            aligns.extend(field_align(field, &field_attribute, Some(target)));
+        }
+    }
+
+    if aligns.is_empty() {
+        return Ok(quote! { 1usize });
+    }
+
+    Ok(quote! {
+        {
+            let mut align = 1usize;
+            #(
+                let field = #aligns;
+                if field > align {
+                    align = field;
+                }
+            )*
+            align
+        }
+    })
+}
+
+// Gets the offset after the layout items so far, from the last gap
+fn offset_since(items: &[LayoutItem], anchor: &(TokenStream, usize)) -> Result<TokenStream, TokenStream> {
+    let (offset, index) = anchor;
+    let sizes = items[*index..].iter()
+        .map(|item| item.expressions().map(|e| e.size))
+        .collect::<Result<Vec<TokenStream>, TokenStream>>()?;
+
+    Ok(quote! { #offset #(+ #sizes)* })
+}
+
+// Pushes a gap filling up to `target`, checking the layout items before
+// fit if `overlap` has the span and message of the error
+fn push_gap(
+    items: &mut Vec<LayoutItem>,
+    anchor: &mut (TokenStream, usize),
+    target: TokenStream,
+    strict: bool,
+    overlap: Option<(Span, String)>
+) -> Result<(), TokenStream> {
+    let before = offset_since(items, anchor)?;
+    let check = overlap.map(|(span, message)| quote_spanned! {span =>
+        const _: () = assert!(#before <= #target, #message);
+    });
+
+    items.push(LayoutItem::Gap { size: quote! { (#target).saturating_sub(#before) }, strict, check });
+    *anchor = (target, items.len());
+
+    Ok(())
+}
+
+// Creates a run from bit fields and their widths, placing
+// the fields in whole storage units
+fn bit_run<'a>(
+    fields: Vec<(LayoutField<'a>, u32)>,
+    storage: &Type,
+    unit_bits: u32,
+    order: BitOrder,
+    run_index: usize
+) -> Result<BitRun<'a>, TokenStream> {
+    let total_bits: u32 = fields.iter().map(|(_, w)| w).sum();
+    let units = total_bits.div_ceil(unit_bits);
+
+    if units * unit_bits > 64 {
+        return Err(Error::new(
+            fields[0].0.field.span(),
+            "consecutive bit fields can take at most 64 bits of storage"
+        ).to_compile_error());
+    }
+
+    // Place fields, from the most significant bits for msb first
+    // and from the least significant bits for lsb first
+    let mut position = 0u32;
+    let mut run_fields: Vec<RunField<'a>> = Vec::new();
+    for (field, width) in fields {
+        let shift = match order {
+            BitOrder::Msb => units * unit_bits - position - width,
+            BitOrder::Lsb => position,
+        };
+
+        run_fields.push(RunField { field, width, shift });
+        position += width;
+    }
+
+    Ok(BitRun {
+        fields: run_fields,
+        storage: storage.clone(),
+        unit_bits,
+        units,
+        order,
+        accumulator: format_ident!("bits_{}", run_index),
+    })
+}
+
+impl LayoutItem<'_> {
+    /// Generates the expressions for the item
+    pub fn expressions(&self) -> Result<FieldExpressions, TokenStream> {
+        match self {
+            LayoutItem::Field(f) => return field::field_expressions(f.field, &f.binding, &f.access),
+            LayoutItem::Run(r) => return Ok(r.expressions()),
+            LayoutItem::Padding { size, strict } => {
+                let size = Literal::usize_suffixed(*size as usize);
+                return Ok(padding_expressions(&quote! { #size }, *strict));
+            },
+            LayoutItem::Gap { size, strict, .. } => return Ok(padding_expressions(size, *strict)),
+            LayoutItem::Magic(magic) => return Ok(magic_expressions(magic)),
+            LayoutItem::Skipped(f, skip) => {
+                let binding = &f.binding;
+                let ty = &f.field.ty;
+                let fill = match skip {
+                    Skip::Zeroed => quote! { <#ty as safe_pod::Zeroable>::zeroed() },
+                    Skip::Default => quote! { <#ty as ::core::default::Default>::default() },
+                };
+
+                return Ok(FieldExpressions {
+                    size: quote! { 0usize },
+                    from_le: quote! { let #binding = #fill; },
+                    from_be: quote! { let #binding = #fill; },
+                    to_le: TokenStream::new(),
+                    to_be: TokenStream::new(),
+                });
+            }
+        }
+    }
+
+    /// Gets the compile time check of a gap, if any
+    pub fn check(&self) -> Option<&TokenStream> {
+        match self {
+            LayoutItem::Gap { check, .. } => return check.as_ref(),
+            _ => return None
+        }
+    }
+}
+
+// Generates expressions checking the magic number on decode
+// and writing it on encode
+fn magic_expressions(magic: &Expr) -> FieldExpressions {
+    let from = quote! {
+        {
+            let magic: &[u8] = #magic;
+            let found = buffer.get(offset..offset + magic.len()).ok_or(safe_pod::PodError::OutOfSpace)?;
+            if found != magic {
+                return Err(safe_pod::PodError::BadMagic { expected: magic.to_vec(), found: found.to_vec() });
+            }
+            offset += magic.len();
+        }
+    };
+    let to = quote! {
+        {
+            let magic: &[u8] = #magic;
+            buffer.get_mut(offset..offset + magic.len()).ok_or(safe_pod::PodError::OutOfSpace)?.copy_from_slice(magic);
+            offset += magic.len();
+        }
+    };
+
+    FieldExpressions {
+        size: quote! { { let magic: &[u8] = #magic; magic.len() } },
+        from_le: from.clone(),
+        from_be: from,
+        to_le: to.clone(),
+        to_be: to,
+    }
+}
+
+// Whether a type is `Reserved<N>`, with the default lenient check
+fn is_default_reserved(ty: &Type) -> bool {
+    let segment = match ty {
+        Type::Path(p) => match p.path.segments.last() {
+            Some(s) => s,
+            None => return false
+        },
+        _ => return false
+    };
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(a) => segment.ident == "Reserved" && a.args.len() == 1,
+        _ => false
+    }
+}
+
+// Generates expressions skipping `size` padding bytes on decode,
+// checking they are zeros if `strict`, and writing zeros on encode
+fn padding_expressions(size: &TokenStream, strict: bool) -> FieldExpressions {
+
+    let from = match strict {
+        true => quote! {
+            if buffer.get(offset..offset + #size).ok_or(safe_pod::PodError::OutOfSpace)?.iter().any(|b| *b != 0) {
+                return Err(safe_pod::PodError::OutOfRange);
+            }
+            offset += #size;
+        },
+        false => quote! {
+            offset += #size;
+        }
+    };
+    let to = quote! {
+        buffer.get_mut(offset..offset + #size).ok_or(safe_pod::PodError::OutOfSpace)?.fill(0);
+        offset += #size;
+    };
+
+    FieldExpressions {
+        size: quote! { #size },
+        from_le: from.clone(),
+        from_be: from,
+        to_le: to.clone(),
+        to_be: to,
+    }
+}
+
+impl BitRun<'_> {
+    /// Size of the run's storage units in bytes
+    pub fn size(&self) -> TokenStream {
+        let size = Literal::usize_suffixed((self.units * self.unit_bits / 8) as usize);
+        quote! { #size }
+    }
+
+    // Shift of a storage unit in the accumulator
+    fn unit_shift(&self, unit: u32) -> u32 {
+        match self.order {
+            BitOrder::Msb => (self.units - unit - 1) * self.unit_bits,
+            BitOrder::Lsb => unit * self.unit_bits,
+        }
+    }
+
+    /// Reads the storage units at `offset` into the accumulator,
+    /// where `from` is the `Pod` method reading a unit
+    pub fn read(&self, from: &Ident) -> TokenStream {
+        let storage = &self.storage;
+        let accumulator = &self.accumulator;
+
+        let terms = (0..self.units).map(|unit| {
+            let unit_offset = match unit {
+                0 => quote! { offset },
+                u => {
+                    let u = Literal::usize_suffixed((u * self.unit_bits / 8) as usize);
+                    quote! { offset + #u }
+                }
+            };
+            let mut term = quote! { <#storage as safe_pod::Pod>::#from(&buffer[#unit_offset..])? };
+            if self.unit_bits < 64 {
+                term = quote! { u64::from(#term) };
+            }
+
+            match self.unit_shift(unit) {
+                0 => term,
+                s => {
+                    let s = Literal::u32_suffixed(s);
+                    quote! { #term << #s }
+                }
+            }
+        });
+
+        quote! {
+            let #accumulator = #(#terms)|*;
+        }
+    }
+
+    /// Writes the accumulator as storage units at `offset`,
+    /// where `to` is the `Pod` method writing a unit
+    pub fn write(&self, to: &Ident) -> TokenStream {
+        let storage = &self.storage;
+        let accumulator = &self.accumulator;
+
+        let writes = (0..self.units).map(|unit| {
+            let mut value = match self.unit_shift(unit) {
+                0 => quote! { #accumulator },
+                s => {
+                    let s = Literal::u32_suffixed(s);
+                    quote! { (#accumulator >> #s) }
+                }
+            };
+            if self.unit_bits < 64 {
+                value = quote! { #value as #storage };
+            }
+
+            quote! {
+                offset += safe_pod::Pod::#to(&(#value), &mut buffer[offset..])?;
+            }
+        });
+
+        quote! {
+            #(#writes)*
+        }
+    }
+
+    // Gets the bits of a field as a value
+    fn to_bits(&self, run_field: &RunField, access: &TokenStream) -> TokenStream {
+        let ty = &run_field.field.field.ty;
+        let width = Literal::u32_suffixed(run_field.width);
+
+        match run_field.shift {
+            0 => quote! { <#ty as safe_pod::BitField>::to_bits(&#access, #width)? },
+            s => {
+                let s = Literal::u32_suffixed(s);
+                quote! { <#ty as safe_pod::BitField>::to_bits(&#access, #width)? << #s }
+            }
+        }
+    }
+
+    /// Reads field `index` from the accumulator into its binding
+    pub fn extract(&self, index: usize) -> TokenStream {
+        let run_field = &self.fields[index];
+        let span = run_field.field.field.span();
+        let ty = &run_field.field.field.ty;
+        let binding = &run_field.field.binding;
+        let accumulator = &self.accumulator;
+        let width = Literal::u32_suffixed(run_field.width);
+
+        let bits = match run_field.shift {
+            0 => quote! { #accumulator },
+            s => {
+                let s = Literal::u32_suffixed(s);
+                quote! { #accumulator >> #s }
+            }
+        };
+
+        quote_spanned! {span =>
+            let #binding = <#ty as safe_pod::BitField>::from_bits(#bits, #width)?;
+        }
+    }
+
+    /// Builds the accumulator from all fields
+    pub fn assemble(&self) -> TokenStream {
+        let accumulator = &self.accumulator;
+        let terms = self.fields.iter().map(|f| self.to_bits(f, &f.field.access));
+
+        quote! {
+            let #accumulator = #(#terms)|*;
+        }
+    }
+
+    /// Replaces the bits of field `index` in the accumulator
+    /// with the bits of `value`
+    pub fn replace(&self, index: usize, value: &TokenStream) -> TokenStream {
+        let run_field = &self.fields[index];
+        let accumulator = &self.accumulator;
+
+        let field_mask = (u64::MAX >> (64 - run_field.width)) << run_field.shift;
+        let keep = Literal::u64_suffixed(!field_mask);
+        let bits = self.to_bits(run_field, value);
+
+        quote! {
+            let #accumulator = #accumulator & #keep | #bits;
+        }
+    }
+
+    /// Generates the expressions for the whole run
+    pub fn expressions(&self) -> FieldExpressions {
+        let size = self.size();
+
+        let read_le = self.read(&format_ident!("from_le_bytes"));
+        let read_be = self.read(&format_ident!("from_be_bytes"));
+        let extracts: Vec<TokenStream> = (0..self.fields.len()).map(|i| self.extract(i)).collect();
+        let assemble = self.assemble();
+        let write_le = self.write(&format_ident!("to_le_bytes"));
+        let write_be = self.write(&format_ident!("to_be_bytes"));
+
+        FieldExpressions {
+            size: size.clone(),
+            from_le: quote! {
+                #read_le
+                offset += #size;
+                #(#extracts)*
+            },
+            from_be: quote! {
+                #read_be
+                offset += #size;
+                #(#extracts)*
+            },
+            to_le: quote! {
+                #assemble
+                #write_le
+            },
+            to_be: quote! {
+                #assemble
+                #write_be
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::named_fields;
+
+    // Gets the layout fields of named fields
+    fn layout_fields(fields: &[Field]) -> Vec<LayoutField<'_>> {
+        fields.iter().map(|f| {
+            let binding = f.ident.clone().unwrap();
+            LayoutField { field: f, binding: binding.clone(), access: quote! { self.#binding } }
+        }).collect()
+    }
+
+    #[test]
+    fn bit_run_msb_first() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                #[pod(bits = 4)]
+                version: u8,
+                #[pod(bits = 4)]
+                ihl: u8,
+            }
+        });
+
+        // Define expected output
+        let expected_from_le = quote! {
+            let bits_0 = u64::from(<u8 as safe_pod::Pod>::from_le_bytes(&buffer[offset..])?);
+            offset += 1usize;
+            let version = <u8 as safe_pod::BitField>::from_bits(bits_0 >> 4u32, 4u32)?;
+            let ihl = <u8 as safe_pod::BitField>::from_bits(bits_0, 4u32)?;
+        }.to_string();
+        let expected_to_le = quote! {
+            let bits_0 = <u8 as safe_pod::BitField>::to_bits(&self.version, 4u32)? << 4u32
+                | <u8 as safe_pod::BitField>::to_bits(&self.ihl, 4u32)?;
+            offset += safe_pod::Pod::to_le_bytes(&(bits_0 as u8), &mut buffer[offset..])?;
+        }.to_string();
+
+        // Output
+        let items = match layout(layout_fields(&input), &StructAttr::default()) {
+            Ok(i) => i,
+            Err(_) => unreachable!()
+        };
+        let output = match items[0].expressions() {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(1, items.len());
+        assert_eq!(expected_from_le, output.from_le.to_string());
+        assert_eq!(expected_to_le, output.to_le.to_string());
+    }
+
+    #[test]
+    fn bit_run_lsb_first_units() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                #[pod(bits = 11)]
+                id: u16,
+                #[pod(bits = 1)]
+                flag: bool,
+                other: u8,
+            }
+        });
+        let attribute = StructAttr {
+            bit_order: BitOrder::Lsb,
+            ..Default::default()
+        };
+
+        // Define expected output
+        let expected_from_be = quote! {
+            let bits_0 = u64::from(<u8 as safe_pod::Pod>::from_be_bytes(&buffer[offset..])?)
+                | u64::from(<u8 as safe_pod::Pod>::from_be_bytes(&buffer[offset + 1usize..])?) << 8u32;
+            offset += 2usize;
+            let id = <u16 as safe_pod::BitField>::from_bits(bits_0, 11u32)?;
+            let flag = <bool as safe_pod::BitField>::from_bits(bits_0 >> 11u32, 1u32)?;
+        }.to_string();
+
+        // Output
+        let items = match layout(layout_fields(&input), &attribute) {
+            Ok(i) => i,
+            Err(_) => unreachable!()
+        };
+        let output = match items[0].expressions() {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(2, items.len());
+        assert_eq!(expected_from_be, output.from_be.to_string());
+    }
+
+    #[test]
+    fn bit_run_too_long() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                #[pod(bits = 60)]
+                a: u64,
+                #[pod(bits = 8)]
+                b: u8,
+            }
+        });
+
+        // Output
+        let output = layout(layout_fields(&input), &StructAttr::default());
+
+        // Test
+        assert!(output.is_err())
+    }
+
+    #[test]
+    fn layout_padding_and_skip() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                #[pod(skip = default)]
+                cache: Vec<u8>,
+                #[pod(pad_before = 2)]
+                length: u16,
+            }
+        });
+
+        // Define expected output
+        let expected_skip = quote! {
+            let cache = <Vec<u8> as ::core::default::Default>::default();
+        }.to_string();
+        let expected_padding = quote! {
+            if buffer.get(offset..offset + 2usize).ok_or(safe_pod::PodError::OutOfSpace)?.iter().any(|b| *b != 0) {
+                return Err(safe_pod::PodError::OutOfRange);
+            }
+            offset += 2usize;
+        }.to_string();
+
+        // Output
+        let attribute = StructAttr { strict: true, ..Default::default() };
+        let items = match layout(layout_fields(&input), &attribute) {
+            Ok(i) => i,
+            Err(_) => unreachable!()
+        };
+        let outputs: Vec<FieldExpressions> = items.iter().map(|i| match i.expressions() {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        }).collect();
+
+        // Test
+        assert_eq!(3, items.len());
+        assert_eq!(expected_skip, outputs[0].from_le.to_string());
+        assert!(outputs[0].to_le.is_empty());
+        assert_eq!(expected_padding, outputs[1].from_be.to_string());
+    }
+
+    #[test]
+    fn layout_strict_reserved() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                kind: u8,
+                checked: Reserved<2, Strict>,
+                ignored: safe_pod::Reserved<2, Lenient>,
+            }
+        });
+        let unspelled = named_fields(quote! {
+            struct Foo {
+                kind: u8,
+                reserved: Reserved<2>,
+            }
+        });
+
+        // Output
+        let attribute = StructAttr { strict: true, ..Default::default() };
+        let items = layout(layout_fields(&input), &attribute);
+        let unspelled_items = layout(layout_fields(&unspelled), &attribute);
+        let lenient_items = layout(layout_fields(&unspelled), &StructAttr::default());
+
+        // Test
+        assert!(matches!(items, Ok(i) if i.len() == 3));
+        assert!(unspelled_items.is_err_and(|e| e.to_string().contains(
+            "Reserved fields of a strict struct must be Reserved<N, Strict> or Reserved<N, Lenient>"
+        )));
+        assert!(lenient_items.is_ok());
+    }
+
+    #[test]
+    fn layout_magic() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                length: u16,
+            }
+        });
+
+        // Define expected output
+        let expected_to = quote! {
+            {
+                let magic: &[u8] = b"RIFF";
+                buffer.get_mut(offset..offset + magic.len()).ok_or(safe_pod::PodError::OutOfSpace)?.copy_from_slice(magic);
+                offset += magic.len();
+            }
+        }.to_string();
+
+        // Output
+        let attribute = StructAttr { magic: Some(parse_quote! { b"RIFF" }), ..Default::default() };
+        let items = match layout(layout_fields(&input), &attribute) {
+            Ok(i) => i,
+            Err(_) => unreachable!()
+        };
+        let output = match items[0].expressions() {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(2, items.len());
+        assert!(matches!(items[0], LayoutItem::Magic(_)));
+        assert_eq!(expected_to, output.to_le.to_string());
+    }
+
+    #[test]
+    fn layout_offset_and_size() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                kind: u8,
+                #[pod(offset = 0x10)]
+                length: u32,
+            }
+        });
+
+        // Define expected output
+        let expected_size = quote! {
+            (16usize).saturating_sub(0usize + <u8 as safe_pod::Pod>::SIZE)
+        }.to_string();
+        let expected_check = quote! {
+            const _: () = assert!(
+                0usize + <u8 as safe_pod::Pod>::SIZE <= 16usize,
+                "field at offset 16 overlaps the fields before it"
+            );
+        }.to_string();
+        let expected_end_size = quote! {
+            (512usize).saturating_sub(16usize + <u32 as safe_pod::Pod>::SIZE)
+        }.to_string();
+
+        // Output
+        let attribute = StructAttr { size: Some(512), ..Default::default() };
+        let items = match layout(layout_fields(&input), &attribute) {
+            Ok(i) => i,
+            Err(_) => unreachable!()
+        };
+        let output = match items[1].expressions() {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+        let output_end = match items[3].expressions() {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(4, items.len());
+        assert_eq!(expected_size, output.size.to_string());
+        assert_eq!(Some(expected_check), items[1].check().map(|c| c.to_string()));
+        assert_eq!(expected_end_size, output_end.size.to_string());
+    }
+
+    #[test]
+    fn layout_c() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                kind: u8,
+                length: u64,
+                inner: Bar,
+            }
+        });
+
+        // Define expected output
+        let expected_size = quote! {
+            ((0usize + <u8 as safe_pod::Pod>::SIZE).next_multiple_of(4usize)).saturating_sub(0usize + <u8 as safe_pod::Pod>::SIZE)
+        }.to_string();
+        let expected_end_size = quote! {
+            (
+                (((0usize + <u8 as safe_pod::Pod>::SIZE).next_multiple_of(4usize) + <u64 as safe_pod::Pod>::SIZE)
+                    .next_multiple_of(<Bar>::C_ALIGN) + <Bar as safe_pod::Pod>::SIZE)
+                    .next_multiple_of({
+                        let mut align = 1usize;
+                        let field = 4usize;
+                        if field > align {
+                            align = field;
+                        }
+                        let field = <Bar>::C_ALIGN;
+                        if field > align {
+                            align = field;
+                        }
+                        align
+                    })
+            ).saturating_sub(
+                ((0usize + <u8 as safe_pod::Pod>::SIZE).next_multiple_of(4usize) + <u64 as safe_pod::Pod>::SIZE)
+                    .next_multiple_of(<Bar>::C_ALIGN) + <Bar as safe_pod::Pod>::SIZE
+            )
+        }.to_string();
+
+        // Output
+        let attribute = StructAttr { c_layout: Some(CTarget::I386), ..Default::default() };
+        let items = match layout(layout_fields(&input), &attribute) {
+            Ok(i) => i,
+            Err(_) => unreachable!()
+        };
+        let output = match items[1].expressions() {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+        let output_end = match items[5].expressions() {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(6, items.len());
+        assert!(items[1].check().is_none());
+        assert_eq!(expected_size, output.size.to_string());
+        assert_eq!(expected_end_size, output_end.size.to_string());
+    }
+
+    #[test]
+    fn layout_scaled_at() {
+        // Define input
+        let input = named_fields(quote! {
+            struct Foo {
+                kind: u8,
+                #[pod(repr(i16), scale = 0.1, offset = -40.0, at = 4)]
+                temperature: f32,
+            }
+        });
+
+        // Define expected output
+        let expected_size = quote! {
+            (4usize).saturating_sub(0usize + <u8 as safe_pod::Pod>::SIZE)
+        }.to_string();
+
+        // Output
+        let items = match layout(layout_fields(&input), &StructAttr::default()) {
+            Ok(i) => i,
+            Err(_) => unreachable!()
+        };
+        let output = match items[1].expressions() {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+        let output_field = match items[2].expressions() {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(3, items.len());
+        assert_eq!(expected_size, output.size.to_string());
+        assert!(output_field.from_le.to_string().contains("- 40.0"));
+    }
+}
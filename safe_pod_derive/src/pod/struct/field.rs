@@ -0,0 +1,640 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{spanned::Spanned, Error, Expr, Field, Ident};
+
+use super::super::attributes::FieldAttr;
+
+/// Generated expressions for a single struct field
+pub struct FieldExpressions {
+    /// Size of the field's byte representation
+    pub size: TokenStream,
+    /// Reads the field into its binding from little endian bytes
+    pub from_le: TokenStream,
+    /// Reads the field into its binding from big endian bytes
+    pub from_be: TokenStream,
+    /// Writes the field as little endian bytes
+    pub to_le: TokenStream,
+    /// Writes the field as big endian bytes
+    pub to_be: TokenStream,
+}
+
+/// Generates the expressions for a struct field, where `binding`
+/// is the variable the field is read into and `access` is the
+/// expression the field is written from
+pub fn field_expressions(field: &Field, binding: &Ident, access: &TokenStream) -> Result<FieldExpressions, TokenStream> {
+    let span = field.span();
+
+    // Get pod attribute
+    let attribute = match FieldAttr::from_attributes(&field.attrs) {
+        Ok(fa) => fa,
+        Err(e) => match e {
+            "not found" => FieldAttr::default(),
+            _ => {
+                return Err(Error::new(
+                    span,
+                    format!("Error while parsing #[pod(...)] attribute: {}", e)
+                ).to_compile_error());
+            }
+        }
+    };
+
+    // Collections and conditional fields have a variable size
+    if attribute.count.is_some() || attribute.len_prefix.is_some() || attribute.until_eof
+        || attribute.condition.is_some() {
+        return Err(Error::new(
+            span,
+            "count, len_prefix, until_eof and if fields are only supported when deriving DynPod"
+        ).to_compile_error());
+    }
+
+    let mapped = attribute.with.is_some() || attribute.map.is_some() || attribute.try_map.is_some();
+
+    // Constant fields
+    if let Some(constant) = &attribute.constant {
+        if attribute.scale.is_some() || attribute.offset.is_some() || mapped {
+            return Err(Error::new(
+                span,
+                "const fields cannot be scaled or mapped"
+            ).to_compile_error());
+        }
+
+        return Ok(const_expressions(field, binding, constant));
+    }
+
+    // Fields with a custom codec
+    if mapped {
+        if attribute.scale.is_some() || attribute.offset.is_some() {
+            return Err(Error::new(
+                span,
+                "with, map and try_map fields cannot be scaled"
+            ).to_compile_error());
+        }
+
+        return mapped_expressions(field, binding, access, attribute);
+    }
+
+    // Scaled fields
+    if attribute.scale.is_some() || attribute.offset.is_some() {
+        return scaled_expressions(field, binding, access, attribute);
+    }
+
+    let ty = field.ty.clone();
+
+    Ok(FieldExpressions {
+        size: quote_spanned! {span => <#ty as safe_pod::Pod>::SIZE},
+        from_le: quote_spanned! {span =>
+            let #binding = <#ty as safe_pod::Pod>::from_le_bytes(&buffer[offset..])?;
+            offset += <#ty as safe_pod::Pod>::SIZE;
+        },
+        from_be: quote_spanned! {span =>
+            let #binding = <#ty as safe_pod::Pod>::from_be_bytes(&buffer[offset..])?;
+            offset += <#ty as safe_pod::Pod>::SIZE;
+        },
+        to_le: quote_spanned! {span =>
+            offset += safe_pod::Pod::to_le_bytes(&#access, &mut buffer[offset..])?;
+        },
+        to_be: quote_spanned! {span =>
+            offset += safe_pod::Pod::to_be_bytes(&#access, &mut buffer[offset..])?;
+        },
+    })
+}
+
+/// Generated expressions for a field computed by its `calc` attribute
+pub struct CalcExpressions {
+    /// Method computing the field from the rest of the struct
+    pub method: TokenStream,
+    /// Expression the field is written from on encode
+    pub access: TokenStream,
+    /// Checks the decoded field, accessed as `value.#member`,
+    /// against the computed value, if the field has `check`
+    pub check: Option<TokenStream>,
+}
+
+/// Generates the expressions of a field's `calc` attribute, if set, where
+/// `member` accesses the field on the struct and `name` is the field name
+/// reported when the decoded value doesn't match the computed one
+pub fn calc_expressions(field: &Field, binding: &Ident, member: &TokenStream, name: &str) -> Result<Option<CalcExpressions>, TokenStream> {
+    let span = field.span();
+    let ty = field.ty.clone();
+
+    // Get pod attribute
+    let attribute = match FieldAttr::from_attributes(&field.attrs) {
+        Ok(fa) => fa,
+        Err(e) => match e {
+            "not found" => FieldAttr::default(),
+            _ => {
+                return Err(Error::new(
+                    span,
+                    format!("Error while parsing #[pod(...)] attribute: {}", e)
+                ).to_compile_error());
+            }
+        }
+    };
+
+    let calc = match &attribute.calc {
+        Some(c) => c,
+        None => return Ok(None)
+    };
+
+    if attribute.constant.is_some() {
+        return Err(Error::new(
+            span,
+            "calc fields cannot be constant"
+        ).to_compile_error());
+    }
+
+    let method = format_ident!("__calc_{}", binding);
+
+    Ok(Some(CalcExpressions {
+        method: quote_spanned! {span =>
+            #[doc(hidden)]
+            #[inline]
+            fn #method(&self) -> #ty {
+                #calc
+            }
+        },
+        access: quote! { self.#method() },
+        check: attribute.check.then(|| quote_spanned! {span =>
+            if value.#member != value.#method() {
+                return Err(safe_pod::PodError::Invalid {
+                    field: #name,
+                    message: format!("expected {:?}, found {:?}", value.#method(), value.#member)
+                });
+            }
+        }),
+    }))
+}
+
+/// Generates the checks of a field's `assert` and `range` attributes,
+/// run on decode once every field is read into its binding, where
+/// `name` is the field name reported when a check fails
+pub fn check_expressions(field: &Field, binding: &Ident, name: &str) -> Result<TokenStream, TokenStream> {
+    let span = field.span();
+
+    // Get pod attribute
+    let attribute = match FieldAttr::from_attributes(&field.attrs) {
+        Ok(fa) => fa,
+        Err(e) => match e {
+            "not found" => FieldAttr::default(),
+            _ => {
+                return Err(Error::new(
+                    span,
+                    format!("Error while parsing #[pod(...)] attribute: {}", e)
+                ).to_compile_error());
+            }
+        }
+    };
+
+    let mut checks = TokenStream::new();
+
+    // Assertions refer to fields by name
+    if let Some(assert) = &attribute.assert {
+        if field.ident.is_none() {
+            return Err(Error::new(
+                span,
+                "assert is only supported on named fields"
+            ).to_compile_error());
+        }
+
+        checks.extend(quote_spanned! {span =>
+            let passed: bool = #assert;
+            if !passed {
+                return Err(safe_pod::PodError::Invalid {
+                    field: #name,
+                    message: String::from(concat!("assertion failed: ", stringify!(#assert)))
+                });
+            }
+        });
+    }
+
+    if let Some(range) = &attribute.range {
+        checks.extend(quote_spanned! {span =>
+            if !(#range).contains(&#binding) {
+                return Err(safe_pod::PodError::Invalid {
+                    field: #name,
+                    message: format!("{:?} is not in range {:?}", #binding, #range)
+                });
+            }
+        });
+    }
+
+    return Ok(checks);
+}
+
+// Generates expressions for a field that always holds `constant`,
+// which is checked on decode and written regardless of the field on encode
+fn const_expressions(field: &Field, binding: &Ident, constant: &Expr) -> FieldExpressions {
+    let span = field.span();
+    let ty = field.ty.clone();
+
+    let generate_from = |from: Ident, to: Ident| -> TokenStream {
+        quote_spanned! {span =>
+            let #binding = {
+                let mut expected = [0u8; <#ty as safe_pod::Pod>::SIZE];
+                <#ty as safe_pod::Pod>::#to(&(#constant), &mut expected)?;
+
+                let found = buffer.get(offset..offset + <#ty as safe_pod::Pod>::SIZE)
+                    .ok_or(safe_pod::PodError::OutOfSpace)?;
+                if found != &expected[..] {
+                    return Err(safe_pod::PodError::BadMagic { expected: expected.to_vec(), found: found.to_vec() });
+                }
+
+                <#ty as safe_pod::Pod>::#from(found)?
+            };
+            offset += <#ty as safe_pod::Pod>::SIZE;
+        }
+    };
+
+    FieldExpressions {
+        size: quote_spanned! {span => <#ty as safe_pod::Pod>::SIZE},
+        from_le: generate_from(format_ident!("from_le_bytes"), format_ident!("to_le_bytes")),
+        from_be: generate_from(format_ident!("from_be_bytes"), format_ident!("to_be_bytes")),
+        to_le: quote_spanned! {span =>
+            offset += <#ty as safe_pod::Pod>::to_le_bytes(&(#constant), &mut buffer[offset..])?;
+        },
+        to_be: quote_spanned! {span =>
+            offset += <#ty as safe_pod::Pod>::to_be_bytes(&(#constant), &mut buffer[offset..])?;
+        },
+    }
+}
+
+// Generates expressions for a field encoded by the functions of the
+// `with` module, or decoded through `repr` and converted by `map` or
+// `try_map`, and encoded through `repr`'s `From<&T>` implementation
+fn mapped_expressions(field: &Field, binding: &Ident, access: &TokenStream, attribute: FieldAttr) -> Result<FieldExpressions, TokenStream> {
+    let span = field.span();
+    let ty = field.ty.clone();
+
+    if let Some(with) = attribute.with {
+        if attribute.repr.is_some() {
+            return Err(Error::new(
+                span,
+                "with fields cannot have repr"
+            ).to_compile_error());
+        }
+
+        // The module only gets its own bytes, so it can index them
+        // without checking the length
+        let bytes = quote_spanned! {span =>
+            buffer.get(offset..offset + #with::SIZE).ok_or(safe_pod::PodError::OutOfSpace)?
+        };
+        let bytes_mut = quote_spanned! {span =>
+            buffer.get_mut(offset..offset + #with::SIZE).ok_or(safe_pod::PodError::OutOfSpace)?
+        };
+
+        return Ok(FieldExpressions {
+            size: quote_spanned! {span => #with::SIZE},
+            from_le: quote_spanned! {span =>
+                let #binding = #with::from_le_bytes(#bytes)?;
+                offset += #with::SIZE;
+            },
+            from_be: quote_spanned! {span =>
+                let #binding = #with::from_be_bytes(#bytes)?;
+                offset += #with::SIZE;
+            },
+            to_le: quote_spanned! {span =>
+                offset += #with::to_le_bytes(&#access, #bytes_mut)?;
+            },
+            to_be: quote_spanned! {span =>
+                offset += #with::to_be_bytes(&#access, #bytes_mut)?;
+            },
+        });
+    }
+
+    let repr = match attribute.repr {
+        Some(r) => r,
+        None => {
+            return Err(Error::new(
+                span,
+                "map and try_map fields must have repr in #[pod(repr($type))] set to the intermediate type"
+            ).to_compile_error());
+        }
+    };
+
+    // Convert the decoded intermediate value
+    let convert = |raw: TokenStream| -> TokenStream {
+        match (&attribute.map, &attribute.try_map) {
+            (Some(map), _) => quote_spanned! {span => #map(#raw)},
+            (_, Some(try_map)) => quote_spanned! {span => #try_map(#raw)?},
+            _ => unreachable!()
+        }
+    };
+    let from_le = convert(quote_spanned! {span => <#repr as safe_pod::Pod>::from_le_bytes(&buffer[offset..])?});
+    let from_be = convert(quote_spanned! {span => <#repr as safe_pod::Pod>::from_be_bytes(&buffer[offset..])?});
+
+    Ok(FieldExpressions {
+        size: quote_spanned! {span => <#repr as safe_pod::Pod>::SIZE},
+        from_le: quote_spanned! {span =>
+            let #binding = #from_le;
+            offset += <#repr as safe_pod::Pod>::SIZE;
+        },
+        from_be: quote_spanned! {span =>
+            let #binding = #from_be;
+            offset += <#repr as safe_pod::Pod>::SIZE;
+        },
+        to_le: quote_spanned! {span =>
+            offset += safe_pod::Pod::to_le_bytes(&<#repr as From<&#ty>>::from(&#access), &mut buffer[offset..])?;
+        },
+        to_be: quote_spanned! {span =>
+            offset += safe_pod::Pod::to_be_bytes(&<#repr as From<&#ty>>::from(&#access), &mut buffer[offset..])?;
+        },
+    })
+}
+
+// Generates expressions for an `f64` field stored
+// as `raw * scale + offset` in an integer of type `repr`
+fn scaled_expressions(field: &Field, binding: &Ident, access: &TokenStream, attribute: FieldAttr) -> Result<FieldExpressions, TokenStream> {
+    let span = field.span();
+
+    let repr = match attribute.repr {
+        Some(r) => r,
+        None => {
+            return Err(Error::new(
+                span,
+                "Scaled fields must have repr in #[pod(repr($type))] set to an integer type"
+            ).to_compile_error());
+        }
+    };
+
+    let scale = match attribute.scale {
+        Some(s) => quote! { (#s) as f64 },
+        None => quote! { 1f64 },
+    };
+
+    let value_offset = match attribute.offset {
+        Some(o) => quote! { (#o) as f64 },
+        None => quote! { 0f64 },
+    };
+
+    Ok(FieldExpressions {
+        size: quote_spanned! {span => <#repr as safe_pod::Pod>::SIZE},
+        from_le: quote_spanned! {span =>
+            let #binding = safe_pod::FixedInt::to_f64(
+                <#repr as safe_pod::Pod>::from_le_bytes(&buffer[offset..])?
+            ) * #scale + #value_offset;
+            offset += <#repr as safe_pod::Pod>::SIZE;
+        },
+        from_be: quote_spanned! {span =>
+            let #binding = safe_pod::FixedInt::to_f64(
+                <#repr as safe_pod::Pod>::from_be_bytes(&buffer[offset..])?
+            ) * #scale + #value_offset;
+            offset += <#repr as safe_pod::Pod>::SIZE;
+        },
+        to_le: quote_spanned! {span =>
+            offset += safe_pod::Pod::to_le_bytes(
+                &<#repr as safe_pod::FixedInt>::from_f64_saturating((#access - #value_offset) / #scale),
+                &mut buffer[offset..]
+            )?;
+        },
+        to_be: quote_spanned! {span =>
+            offset += safe_pod::Pod::to_be_bytes(
+                &<#repr as safe_pod::FixedInt>::from_f64_saturating((#access - #value_offset) / #scale),
+                &mut buffer[offset..]
+            )?;
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse2, Data, DeriveInput, Fields};
+
+    use super::*;
+
+    #[test]
+    fn scaled_field() {
+        // Define input
+        let input_stream = quote! {
+            struct Foo {
+                #[pod(repr(i16), scale = 0.01, offset = -40.0)]
+                temperature: f64,
+            }
+        };
+
+        let input = match parse2::<DeriveInput>(input_stream).unwrap().data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => f.named.first().unwrap().clone(),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        };
+        let binding = input.ident.clone().unwrap();
+        let access = quote! { self.temperature };
+
+        // Define expected output
+        let expected_size = quote! { <i16 as safe_pod::Pod>::SIZE }.to_string();
+        let expected_from_le = quote! {
+            let temperature = safe_pod::FixedInt::to_f64(
+                <i16 as safe_pod::Pod>::from_le_bytes(&buffer[offset..])?
+            ) * (0.01) as f64 + (-40.0) as f64;
+            offset += <i16 as safe_pod::Pod>::SIZE;
+        }.to_string();
+        let expected_to_be = quote! {
+            offset += safe_pod::Pod::to_be_bytes(
+                &<i16 as safe_pod::FixedInt>::from_f64_saturating((self.temperature - (-40.0) as f64) / (0.01) as f64),
+                &mut buffer[offset..]
+            )?;
+        }.to_string();
+
+        // Output
+        let output = match field_expressions(&input, &binding, &access) {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(expected_size, output.size.to_string());
+        assert_eq!(expected_from_le, output.from_le.to_string());
+        assert_eq!(expected_to_be, output.to_be.to_string());
+    }
+
+    #[test]
+    fn scaled_field_without_repr() {
+        // Define input
+        let input_stream = quote! {
+            struct Foo {
+                #[pod(scale = 0.5)]
+                bar: f64,
+            }
+        };
+
+        let input = match parse2::<DeriveInput>(input_stream).unwrap().data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => f.named.first().unwrap().clone(),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        };
+        let binding = input.ident.clone().unwrap();
+
+        // Output
+        let output = field_expressions(&input, &binding, &quote! { self.bar });
+
+        // Test
+        assert!(output.is_err())
+    }
+
+    #[test]
+    fn const_field() {
+        // Define input
+        let input_stream = quote! {
+            struct Foo {
+                #[pod(const = 7)]
+                version: u16,
+            }
+        };
+
+        let input = match parse2::<DeriveInput>(input_stream).unwrap().data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => f.named.first().unwrap().clone(),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        };
+        let binding = input.ident.clone().unwrap();
+
+        // Define expected output
+        let expected_to_le = quote! {
+            offset += <u16 as safe_pod::Pod>::to_le_bytes(&(7), &mut buffer[offset..])?;
+        }.to_string();
+        let expected_error = quote! {
+            return Err(safe_pod::PodError::BadMagic { expected: expected.to_vec(), found: found.to_vec() });
+        }.to_string();
+
+        // Output
+        let output = match field_expressions(&input, &binding, &quote! { self.version }) {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(expected_to_le, output.to_le.to_string());
+        assert!(output.from_be.to_string().contains(&expected_error));
+    }
+
+    #[test]
+    fn asserted_field() {
+        // Define input
+        let input_stream = quote! {
+            struct Foo {
+                #[pod(assert = version <= 3)]
+                version: u8,
+            }
+        };
+
+        let input = match parse2::<DeriveInput>(input_stream).unwrap().data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => f.named.first().unwrap().clone(),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        };
+        let binding = input.ident.clone().unwrap();
+
+        // Define expected output
+        let expected_output = quote! {
+            let passed: bool = version <= 3;
+            if !passed {
+                return Err(safe_pod::PodError::Invalid {
+                    field: "version",
+                    message: String::from(concat!("assertion failed: ", stringify!(version <= 3)))
+                });
+            }
+        }.to_string();
+
+        // Output
+        let output = match check_expressions(&input, &binding, "version") {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(expected_output, output.to_string());
+    }
+
+    #[test]
+    fn mapped_field() {
+        // Define input
+        let input_stream = quote! {
+            struct Foo {
+                #[pod(repr(u8), try_map = Mode::from_raw)]
+                mode: Mode,
+                #[pod(with = bcd_time)]
+                time: Time,
+            }
+        };
+
+        let fields: Vec<Field> = match parse2::<DeriveInput>(input_stream).unwrap().data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => f.named.into_iter().collect(),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_from_le = quote! {
+            let mode = Mode::from_raw(<u8 as safe_pod::Pod>::from_le_bytes(&buffer[offset..])?)?;
+            offset += <u8 as safe_pod::Pod>::SIZE;
+        }.to_string();
+        let expected_to_be = quote! {
+            offset += safe_pod::Pod::to_be_bytes(&<u8 as From<&Mode>>::from(&self.mode), &mut buffer[offset..])?;
+        }.to_string();
+        let expected_with_from_be = quote! {
+            let time = bcd_time::from_be_bytes(
+                buffer.get(offset..offset + bcd_time::SIZE).ok_or(safe_pod::PodError::OutOfSpace)?
+            )?;
+            offset += bcd_time::SIZE;
+        }.to_string();
+        let expected_with_to_le = quote! {
+            offset += bcd_time::to_le_bytes(
+                &self.time,
+                buffer.get_mut(offset..offset + bcd_time::SIZE).ok_or(safe_pod::PodError::OutOfSpace)?
+            )?;
+        }.to_string();
+
+        // Output
+        let mode_binding = fields[0].ident.clone().unwrap();
+        let time_binding = fields[1].ident.clone().unwrap();
+        let output = match field_expressions(&fields[0], &mode_binding, &quote! { self.mode }) {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+        let output_with = match field_expressions(&fields[1], &time_binding, &quote! { self.time }) {
+            Ok(o) => o,
+            Err(_) => unreachable!()
+        };
+
+        // Test
+        assert_eq!(expected_from_le, output.from_le.to_string());
+        assert_eq!(expected_to_be, output.to_be.to_string());
+        assert_eq!(expected_with_from_be, output_with.from_be.to_string());
+        assert_eq!(expected_with_to_le, output_with.to_le.to_string());
+    }
+
+    #[test]
+    fn mapped_field_without_repr() {
+        // Define input
+        let input_stream = quote! {
+            struct Foo {
+                #[pod(map = Mode::from_raw)]
+                mode: Mode,
+            }
+        };
+
+        let input = match parse2::<DeriveInput>(input_stream).unwrap().data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => f.named.first().unwrap().clone(),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        };
+        let binding = input.ident.clone().unwrap();
+
+        // Output
+        let output = field_expressions(&input, &binding, &quote! { self.mode });
+
+        // Test
+        assert!(output.is_err())
+    }
+}
@@ -2,23 +2,130 @@
 mod unit;
 mod tuple;
 mod named;
+pub mod field;
+mod bits;
+mod offsets;
+mod view;
 
 use proc_macro2::TokenStream;
-use syn::{DataStruct, Fields, Ident};
+use quote::quote;
+use syn::{Attribute, DataStruct, Error, Fields, Ident, Visibility};
+
+use super::attributes::{FieldAttr, StructAttr};
 
 /// Implementation of derive macro for structs
-// TODO: add generics and attributes to function input
-pub fn derive_struct_impl(name: Ident, struct_data: &DataStruct) -> TokenStream {
+// TODO: add generics to function input
+pub fn derive_struct_impl(name: Ident, visibility: Visibility, attributes: Vec<Attribute>, struct_data: &DataStruct) -> TokenStream {
+    // Get pod attribute
+    let pod_attribute = match StructAttr::from_attributes(&attributes) {
+        Ok(pa) => pa,
+        Err(e) => match e {
+            "not found" => StructAttr::default(),
+            _ => {
+                return Error::new(
+                    name.span(),
+                    format!("Error while parsing #[pod(...)] attribute: {}", e)
+                ).to_compile_error();
+            }
+        }
+    };
+
+    // Nested views need views on the struct
+    let view_fields = struct_data.fields.iter()
+        .any(|f| FieldAttr::from_attributes(&f.attrs).is_ok_and(|a| a.view));
+    if view_fields && !pod_attribute.view {
+        return Error::new(
+            name.span(),
+            "view fields require #[pod(view)] on the struct"
+        ).to_compile_error();
+    }
+
+    // Views are generated for named structs only
+    if pod_attribute.view && !matches!(struct_data.fields, Fields::Named(_)) {
+        return Error::new(
+            name.span(),
+            "view attribute is only supported in structs with named fields"
+        ).to_compile_error();
+    }
+
+    // Twins are checked through the offset constants of named structs
+    if pod_attribute.twin.is_some() && !matches!(struct_data.fields, Fields::Named(_)) {
+        return Error::new(
+            name.span(),
+            "twin attribute is only supported in structs with named fields"
+        ).to_compile_error();
+    }
+
+    // Structs with a C layout have their alignment,
+    // so they can be nested in other C layout structs
+    let align_impl = match pod_attribute.c_layout {
+        Some(target) => {
+            let align = match bits::c_struct_align(struct_data.fields.iter(), target) {
+                Ok(a) => a,
+                Err(e) => return e
+            };
+
+            quote! {
+                #[allow(dead_code)]
+                impl #name {
+                    /// Alignment of the struct in C on the target of its layout
+                    #visibility const C_ALIGN: usize = #align;
+                }
+            }
+        },
+        None => TokenStream::new()
+    };
+
+    // Structs with a magic number can be probed for it
+    let matches_impl = match &pod_attribute.magic {
+        Some(magic) => quote! {
+            #[allow(dead_code)]
+            impl #name {
+                /// Check if `buffer` starts with the magic number
+                #[inline]
+                #visibility fn matches(buffer: &[u8]) -> bool {
+                    let magic: &[u8] = #magic;
+                    buffer.starts_with(magic)
+                }
+            }
+        },
+        None => TokenStream::new()
+    };
+
     // Match struct type and delegate to appropriate impl functions
     match &struct_data.fields {
         Fields::Unit => {
+            if pod_attribute.magic.is_some() {
+                return Error::new(
+                    name.span(),
+                    "magic attribute is not supported in unit structs"
+                ).to_compile_error();
+            }
+
             return unit::derive_unit_struct_impl(&name);
         },
         Fields::Unnamed(f) => {
-            return tuple::derive_tuple_struct_impl(&name, f);
+            let pod_impl = tuple::derive_tuple_struct_impl(&name, &pod_attribute, f);
+            return quote! {
+                #pod_impl
+                #matches_impl
+                #align_impl
+            };
         },
         Fields::Named(f) => {
-            return named::derive_named_struct_impl(&name, f);
+            let pod_impl = named::derive_named_struct_impl(&name, &pod_attribute, f);
+            let offsets_impl = offsets::derive_offsets_impl(&name, &visibility, &pod_attribute, f);
+            let view_impl = match pod_attribute.view {
+                true => view::derive_view_impl(&name, &visibility, f),
+                false => TokenStream::new()
+            };
+            return quote! {
+                #pod_impl
+                #offsets_impl
+                #view_impl
+                #matches_impl
+                #align_impl
+            };
         }
     }
 }
@@ -1,9 +1,13 @@
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, FieldsNamed, Ident};
+use quote::quote;
+use syn::{FieldsNamed, Ident};
+
+use super::bits::{self, LayoutField};
+use super::field;
+use super::super::attributes::StructAttr;
 
 /// Implementation of derive macro for named structs
-pub fn derive_named_struct_impl(name: &Ident, struct_data: &FieldsNamed) -> TokenStream {
+pub fn derive_named_struct_impl(name: &Ident, attribute: &StructAttr, struct_data: &FieldsNamed) -> TokenStream {
     // Define size expressions
     let mut size_expressions: Vec<TokenStream> = Vec::new();
 
@@ -22,50 +26,96 @@ pub fn derive_named_struct_impl(name: &Ident, struct_data: &FieldsNamed) -> Toke
     // Define to be expressions
     let mut to_be_expressions: Vec<TokenStream> = Vec::new();
 
-    // Go over fields and generate expressions
-    for field in &struct_data.named {
-        let span = field.span();
-        let ty = field.ty.clone();
-        
-        // Generate size expression
-        size_expressions.push(
-            quote_spanned! {span => <#ty as safe_pod::Pod>::SIZE}
-        );
+    // Define checks run once every field is decoded
+    let mut check_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define methods of computed fields, and the checks
+    // of those marked check, run once the struct is decoded
+    let mut calc_methods: Vec<TokenStream> = Vec::new();
+    let mut calc_checks: Vec<TokenStream> = Vec::new();
 
+    // Go over fields and group bit fields
+    let mut layout_fields: Vec<LayoutField> = Vec::new();
+    for field in &struct_data.named {
         // Get field name
         let field_name = field.ident.clone().unwrap();
         field_names.push(field_name.clone());
 
-        // Generate from le expression
-        from_le_expressions.push(
-            quote_spanned! {span => 
-                let #field_name = <#ty as safe_pod::Pod>::from_le_bytes(&buffer[offset..])?;
-                offset += <#ty as safe_pod::Pod>::SIZE;
-            }
-        );
+        let name_str = field_name.to_string().trim_start_matches("r#").to_string();
+        match field::check_expressions(field, &field_name, &name_str) {
+            Ok(c) => check_expressions.push(c),
+            Err(e) => return e
+        }
 
-        // Generate from be expression
-        from_be_expressions.push(
-            quote_spanned! {span => 
-                let #field_name = <#ty as safe_pod::Pod>::from_be_bytes(&buffer[offset..])?;
-                offset += <#ty as safe_pod::Pod>::SIZE;
-            }
-        );
+        let mut access = quote! { self.#field_name };
+        match field::calc_expressions(field, &field_name, &quote! { #field_name }, &name_str) {
+            Ok(Some(c)) => {
+                calc_methods.push(c.method);
+                calc_checks.extend(c.check);
+                access = c.access;
+            },
+            Ok(None) => {},
+            Err(e) => return e
+        }
 
-        // Generate to le expression
-        to_le_expressions.push(
-            quote_spanned! {span => 
-                offset += safe_pod::Pod::to_le_bytes(&self.#field_name, &mut buffer[offset..])?;
+        layout_fields.push(LayoutField {
+            field,
+            binding: field_name.clone(),
+            access,
+        });
+    }
+
+    let items = match bits::layout(layout_fields, attribute) {
+        Ok(i) => i,
+        Err(e) => return e
+    };
+
+    // Go over layout items and generate expressions
+    for item in &items {
+        // Generate expressions
+        let expressions = match item.expressions() {
+            Ok(e) => e,
+            Err(e) => return e
+        };
+
+        size_expressions.push(expressions.size);
+        from_le_expressions.push(expressions.from_le);
+        from_be_expressions.push(expressions.from_be);
+        to_le_expressions.push(expressions.to_le);
+        to_be_expressions.push(expressions.to_be);
+    }
+
+    // Construct the decoded struct, checking computed fields
+    // and passing it to the validation hook if set
+    let construct = match (&attribute.validate, calc_checks.is_empty()) {
+        (None, true) => quote! {
+            Ok(Self{ #(#field_names),* })
+        },
+        (validate, _) => {
+            let validate = validate.iter();
+            quote! {
+                let value = Self{ #(#field_names),* };
+                #(#calc_checks)*
+                #(#validate(&value)?;)*
+
+                Ok(value)
             }
-        );
-        
-        // Generate to be expression
-        to_be_expressions.push(
-            quote_spanned! {span => 
-                offset += safe_pod::Pod::to_be_bytes(&self.#field_name, &mut buffer[offset..])?;
+        }
+    };
+
+    // Computed fields are encoded from their methods
+    let calc_impl = match calc_methods.is_empty() {
+        true => TokenStream::new(),
+        false => quote! {
+            #[allow(dead_code)]
+            impl #name {
+                #(#calc_methods)*
             }
-        );
-    }
+        }
+    };
+
+    // Gaps check at compile time that the fields before them fit
+    let checks = items.iter().filter_map(|item| item.check());
 
     // Generate implementation
     quote! {
@@ -82,8 +132,9 @@ pub fn derive_named_struct_impl(name: &Ident, struct_data: &FieldsNamed) -> Toke
                 let mut offset = 0usize;
 
                 #(#from_le_expressions)*
+                #(#check_expressions)*
 
-                Ok(Self{ #(#field_names),* })
+                #construct
             }
 
             #[inline]
@@ -96,8 +147,9 @@ pub fn derive_named_struct_impl(name: &Ident, struct_data: &FieldsNamed) -> Toke
                 let mut offset = 0usize;
 
                 #(#from_be_expressions)*
+                #(#check_expressions)*
 
-                Ok(Self{ #(#field_names),* })
+                #construct
             }
 
             #[inline]
@@ -126,6 +178,10 @@ pub fn derive_named_struct_impl(name: &Ident, struct_data: &FieldsNamed) -> Toke
                 Ok(offset)
             }
         }
+
+        #calc_impl
+
+        #(#checks)*
     }
 }
 
@@ -222,7 +278,7 @@ mod tests {
         }.to_string();
 
         // Output
-        let output = derive_named_struct_impl(&name_input, &fields_input).to_string();
+        let output = derive_named_struct_impl(&name_input, &StructAttr::default(), &fields_input).to_string();
 
         // Test
         assert_eq!(expected_output, output)
@@ -333,9 +389,118 @@ mod tests {
         }.to_string();
 
         // Output
-        let output = derive_named_struct_impl(&name_input, &fields_input).to_string();
+        let output = derive_named_struct_impl(&name_input, &StructAttr::default(), &fields_input).to_string();
 
         // Test
         assert_eq!(expected_output, output)
     }
+
+    #[test]
+    fn named_struct_validated() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(validate = check)]
+            struct MyNamedStruct{
+                #[pod(range = 1..=3)]
+                my_field: u8
+            }
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+        let name_input = input.ident;
+        let attribute = StructAttr::from_attributes(&input.attrs).unwrap();
+        let fields_input = match input.data {
+            Data::Struct(ds) => {
+                match ds.fields {
+                    Fields::Named(f) => {
+                        f
+                    },
+                    _ => unreachable!()
+                }
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_from_le = quote! {
+            offset += <u8 as safe_pod::Pod>::SIZE;
+            if !(1..=3).contains(&my_field) {
+                return Err(safe_pod::PodError::Invalid {
+                    field: "my_field",
+                    message: format!("{:?} is not in range {:?}", my_field, 1..=3)
+                });
+            }
+
+            let value = Self{ my_field };
+            check(&value)?;
+
+            Ok(value)
+        }.to_string();
+
+        // Output
+        let output = derive_named_struct_impl(&name_input, &attribute, &fields_input).to_string();
+
+        // Test
+        assert!(output.contains(&expected_from_le))
+    }
+
+    #[test]
+    fn named_struct_calc() {
+        // Define input
+        let input_stream = quote! {
+            struct MyNamedStruct{
+                #[pod(calc = Self::SIZE as u8, check)]
+                size: u8,
+                my_field: u16
+            }
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+        let name_input = input.ident;
+        let fields_input = match input.data {
+            Data::Struct(ds) => {
+                match ds.fields {
+                    Fields::Named(f) => {
+                        f
+                    },
+                    _ => unreachable!()
+                }
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_check = quote! {
+            let value = Self{ size, my_field };
+            if value.size != value.__calc_size() {
+                return Err(safe_pod::PodError::Invalid {
+                    field: "size",
+                    message: format!("expected {:?}, found {:?}", value.__calc_size(), value.size)
+                });
+            }
+
+            Ok(value)
+        }.to_string();
+        let expected_encode = quote! {
+            offset += safe_pod::Pod::to_le_bytes(&self.__calc_size(), &mut buffer[offset..])?;
+        }.to_string();
+        let expected_method = quote! {
+            #[allow(dead_code)]
+            impl MyNamedStruct {
+                #[doc(hidden)]
+                #[inline]
+                fn __calc_size(&self) -> u8 {
+                    Self::SIZE as u8
+                }
+            }
+        }.to_string();
+
+        // Output
+        let output = derive_named_struct_impl(&name_input, &StructAttr::default(), &fields_input).to_string();
+
+        // Test
+        assert!(output.contains(&expected_check));
+        assert!(output.contains(&expected_encode));
+        assert!(output.contains(&expected_method))
+    }
 }
\ No newline at end of file
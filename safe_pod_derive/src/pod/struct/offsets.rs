@@ -0,0 +1,274 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{spanned::Spanned, FieldsNamed, Ident, Path, Visibility};
+
+use super::bits::{self, LayoutField, LayoutItem};
+use super::field;
+use super::super::attributes::{CTarget, StructAttr};
+
+/// Gets the name of the offset constant of a field
+pub fn offset_name(field_name: &Ident) -> Ident {
+    let upper = field_name.to_string().trim_start_matches("r#").to_uppercase();
+    format_ident!("OFFSET_{}", upper, span = field_name.span())
+}
+
+/// Gets the names of the read and write helpers of a field,
+/// where `order` is `le` or `be`
+pub fn helper_names(field_name: &Ident, order: &str) -> (Ident, Ident) {
+    (
+        format_ident!("read_{}_{}", field_name, order, span = field_name.span()),
+        format_ident!("write_{}_{}", field_name, order, span = field_name.span())
+    )
+}
+
+/// Implementation of field offset constants and
+/// single field helpers for named structs
+pub fn derive_offsets_impl(name: &Ident, visibility: &Visibility, attribute: &StructAttr, struct_data: &FieldsNamed) -> TokenStream {
+    // Define offset constants and helpers
+    let mut items: Vec<TokenStream> = Vec::new();
+
+    // Define checks of the offsets against the twin
+    let mut twin_checks: Vec<TokenStream> = Vec::new();
+
+    // Define sizes of the layout items read so far
+    let mut size_expressions: Vec<TokenStream> = Vec::new();
+
+    // Go over fields and group bit fields
+    let layout_fields: Vec<LayoutField> = struct_data.named.iter().map(|field| LayoutField {
+        field,
+        binding: field.ident.clone().unwrap(),
+        access: quote! { value },
+    }).collect();
+
+    let layout = match bits::layout(layout_fields, attribute) {
+        Ok(l) => l,
+        Err(e) => return e
+    };
+
+    // Go over layout items and generate items
+    for layout_item in &layout {
+        let offset = quote! { 0usize #(+ #size_expressions)* };
+
+        match layout_item {
+            LayoutItem::Field(f) => {
+                // Generate expressions
+                let expressions = match field::field_expressions(f.field, &f.binding, &f.access) {
+                    Ok(e) => e,
+                    Err(e) => return e
+                };
+                size_expressions.push(expressions.size);
+
+                if let Some(twin) = &attribute.twin {
+                    twin_checks.push(twin_check(name, twin, &f.binding));
+                }
+
+                items.push(field_items(visibility, f, &offset, [
+                    expressions.from_le,
+                    expressions.from_be,
+                    expressions.to_le,
+                    expressions.to_be
+                ]));
+            },
+            LayoutItem::Padding { .. } | LayoutItem::Gap { .. } | LayoutItem::Magic(_) => {
+                // Padding, gaps and magic numbers only move the fields after them
+                let expressions = match layout_item.expressions() {
+                    Ok(e) => e,
+                    Err(e) => return e
+                };
+                size_expressions.push(expressions.size);
+            },
+            LayoutItem::Skipped(..) => {},
+            LayoutItem::Run(r) => {
+                size_expressions.push(r.size());
+
+                // Bit fields are read and written with their whole run
+                let read_le = r.read(&format_ident!("from_le_bytes"));
+                let read_be = r.read(&format_ident!("from_be_bytes"));
+                let write_le = r.write(&format_ident!("to_le_bytes"));
+                let write_be = r.write(&format_ident!("to_be_bytes"));
+
+                let size = r.size();
+
+                for (i, run_field) in r.fields.iter().enumerate() {
+                    let extract = r.extract(i);
+                    let replace = r.replace(i, &run_field.field.access);
+
+                    items.push(field_items(visibility, &run_field.field, &offset, [
+                        quote! { #read_le offset += #size; #extract },
+                        quote! { #read_be offset += #size; #extract },
+                        quote! { #read_le #replace #write_le },
+                        quote! { #read_be #replace #write_be }
+                    ]));
+                }
+            }
+        }
+    }
+
+    // Compare the layout with the twin when compiling for its target
+    let twin_impl = match (&attribute.twin, attribute.c_layout) {
+        (Some(twin), Some(target)) => {
+            let arch = match target {
+                CTarget::X86_64 => "x86_64",
+                CTarget::I386 => "x86",
+                CTarget::Arm32 => "arm",
+            };
+            let message = format!("size of `{}` doesn't match `{}`", name, twin.to_token_stream());
+
+            quote! {
+                #[cfg(target_arch = #arch)]
+                const _: () = {
+                    #(#twin_checks)*
+                    assert!(<#name as safe_pod::Pod>::SIZE == ::core::mem::size_of::<#twin>(), #message);
+                };
+            }
+        },
+        _ => TokenStream::new()
+    };
+
+    // Generate implementation
+    quote! {
+        #[allow(dead_code)]
+        impl #name {
+            #(#items)*
+        }
+
+        #twin_impl
+    }
+}
+
+// Generates the check that a field is at the same offset in the
+// twin, a `#[repr(C)]` struct with the same field names
+fn twin_check(name: &Ident, twin: &Path, field_name: &Ident) -> TokenStream {
+    let offset_name = offset_name(field_name);
+    let display_name = field_name.to_string().trim_start_matches("r#").to_string();
+    let message = format!("offset of `{}` doesn't match `{}`", display_name, twin.to_token_stream());
+
+    quote_spanned! {field_name.span() =>
+        assert!(#name::#offset_name == ::core::mem::offset_of!(#twin, #field_name), #message);
+    }
+}
+
+// Generates the offset constant and helpers of a field at `offset`, from
+// expressions reading it little and big endian and writing it little
+// and big endian, relative to the start of the field. Constant fields
+// are written without the value, so it may be unused
+fn field_items(visibility: &Visibility, layout_field: &LayoutField, offset: &TokenStream, expressions: [TokenStream; 4]) -> TokenStream {
+    let field = layout_field.field;
+    let span = field.span();
+    let ty = field.ty.clone();
+
+    // Get field names
+    let field_name = layout_field.binding.clone();
+    let offset_name = offset_name(&field_name);
+    let (read_le, write_le) = helper_names(&field_name, "le");
+    let (read_be, write_be) = helper_names(&field_name, "be");
+
+    let [from_le, from_be, to_le, to_be] = expressions;
+
+    let display_name = field_name.to_string().trim_start_matches("r#").to_string();
+    let offset_doc = format!("Offset of the `{}` field in the encoded bytes", display_name);
+    let read_doc = format!("Decode only the `{}` field from the encoded bytes", display_name);
+    let write_doc = format!("Encode only the `{}` field into the encoded bytes", display_name);
+
+    quote_spanned! {span =>
+        #[doc = #offset_doc]
+        #visibility const #offset_name: usize = #offset;
+
+        #[doc = #read_doc]
+        #[inline]
+        #[allow(unused_assignments)]
+        #visibility fn #read_le(buffer: &[u8]) -> Result<#ty, safe_pod::PodError> {
+            let buffer = buffer.get(Self::#offset_name..).ok_or(safe_pod::PodError::OutOfSpace)?;
+            let mut offset = 0usize;
+            #from_le
+            Ok(#field_name)
+        }
+
+        #[doc = #read_doc]
+        #[inline]
+        #[allow(unused_assignments)]
+        #visibility fn #read_be(buffer: &[u8]) -> Result<#ty, safe_pod::PodError> {
+            let buffer = buffer.get(Self::#offset_name..).ok_or(safe_pod::PodError::OutOfSpace)?;
+            let mut offset = 0usize;
+            #from_be
+            Ok(#field_name)
+        }
+
+        #[doc = #write_doc]
+        #[inline]
+        #[allow(unused_variables)]
+        #visibility fn #write_le(buffer: &mut [u8], value: #ty) -> Result<usize, safe_pod::PodError> {
+            let buffer = buffer.get_mut(Self::#offset_name..).ok_or(safe_pod::PodError::OutOfSpace)?;
+            let mut offset = 0usize;
+            #to_le
+            Ok(offset)
+        }
+
+        #[doc = #write_doc]
+        #[inline]
+        #[allow(unused_variables)]
+        #visibility fn #write_be(buffer: &mut [u8], value: #ty) -> Result<usize, safe_pod::PodError> {
+            let buffer = buffer.get_mut(Self::#offset_name..).ok_or(safe_pod::PodError::OutOfSpace)?;
+            let mut offset = 0usize;
+            #to_be
+            Ok(offset)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse2, Data, DeriveInput, Fields};
+
+    use super::*;
+
+    #[test]
+    fn offsets_named_struct() {
+        // Define input
+        let input_stream = quote! {
+            pub struct Foo {
+                a: u8,
+                r#type: u16,
+            }
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+        let name_input = input.ident;
+        let visibility_input = input.vis;
+        let fields_input = match input.data {
+            Data::Struct(ds) => {
+                match ds.fields {
+                    Fields::Named(f) => {
+                        f
+                    },
+                    _ => unreachable!()
+                }
+            },
+            _ => unreachable!()
+        };
+
+        // Define expected output
+        let expected_offset = quote! {
+            #[doc = "Offset of the `type` field in the encoded bytes"]
+            pub const OFFSET_TYPE: usize = 0usize + <u8 as safe_pod::Pod>::SIZE;
+        }.to_string();
+        let expected_write_le = quote! {
+            #[doc = "Encode only the `type` field into the encoded bytes"]
+            #[inline]
+            #[allow(unused_variables)]
+            pub fn write_type_le(buffer: &mut [u8], value: u16) -> Result<usize, safe_pod::PodError> {
+                let buffer = buffer.get_mut(Self::OFFSET_TYPE..).ok_or(safe_pod::PodError::OutOfSpace)?;
+                let mut offset = 0usize;
+                offset += safe_pod::Pod::to_le_bytes(&value, &mut buffer[offset..])?;
+                Ok(offset)
+            }
+        }.to_string();
+
+        // Output
+        let output = derive_offsets_impl(&name_input, &visibility_input, &StructAttr::default(), &fields_input).to_string();
+
+        // Test
+        assert!(output.contains(&expected_offset));
+        assert!(output.contains(&expected_write_le));
+    }
+}
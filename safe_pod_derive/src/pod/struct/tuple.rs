@@ -1,9 +1,13 @@
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
+use quote::quote;
 use syn::{spanned::Spanned, FieldsUnnamed, Ident, Index};
 
+use super::bits::{self, LayoutField};
+use super::field;
+use super::super::attributes::StructAttr;
+
 /// Implementation of derive macro for tuple structs
-pub fn derive_tuple_struct_impl(name: &Ident, struct_data: &FieldsUnnamed) -> TokenStream {
+pub fn derive_tuple_struct_impl(name: &Ident, attribute: &StructAttr, struct_data: &FieldsUnnamed) -> TokenStream {
     // Define size expressions
     let mut size_expressions: Vec<TokenStream> = Vec::new();
 
@@ -22,56 +26,96 @@ pub fn derive_tuple_struct_impl(name: &Ident, struct_data: &FieldsUnnamed) -> To
     // Define to be expressions
     let mut to_be_expressions: Vec<TokenStream> = Vec::new();
 
-    // Go over fields and generate expressions
-    let mut n = 0usize;
-    for field in &struct_data.unnamed {
-        let span = field.span();
-        let ty = field.ty.clone();
-        
-        // Generate size expression
-        size_expressions.push(
-            quote_spanned! {span => <#ty as safe_pod::Pod>::SIZE}
-        );
+    // Define checks run once every field is decoded
+    let mut check_expressions: Vec<TokenStream> = Vec::new();
+
+    // Define methods of computed fields, and the checks
+    // of those marked check, run once the struct is decoded
+    let mut calc_methods: Vec<TokenStream> = Vec::new();
+    let mut calc_checks: Vec<TokenStream> = Vec::new();
 
+    // Go over fields and group bit fields
+    let mut layout_fields: Vec<LayoutField> = Vec::new();
+    for (n, field) in struct_data.unnamed.iter().enumerate() {
         // Generate temporary name
-        let temp_name = Ident::new(&format!("temp_{}", n), span);
+        let temp_name = Ident::new(&format!("temp_{}", n), field.span());
         temp_names.push(temp_name.clone());
 
-        // Generate from le expression
-        from_le_expressions.push(
-            quote_spanned! {span => 
-                let #temp_name = <#ty as safe_pod::Pod>::from_le_bytes(&buffer[offset..])?;
-                offset += <#ty as safe_pod::Pod>::SIZE;
-            }
-        );
-
-        // Generate from be expression
-        from_be_expressions.push(
-            quote_spanned! {span => 
-                let #temp_name = <#ty as safe_pod::Pod>::from_be_bytes(&buffer[offset..])?;
-                offset += <#ty as safe_pod::Pod>::SIZE;
-            }
-        );
+        match field::check_expressions(field, &temp_name, &n.to_string()) {
+            Ok(c) => check_expressions.push(c),
+            Err(e) => return e
+        }
 
-        // Generate to le expression
         let field_index = Index::from(n);
-        to_le_expressions.push(
-            quote_spanned! {span => 
-                offset += safe_pod::Pod::to_le_bytes(&self.#field_index, &mut buffer[offset..])?;
+        let mut access = quote! { self.#field_index };
+        match field::calc_expressions(field, &temp_name, &quote! { #field_index }, &n.to_string()) {
+            Ok(Some(c)) => {
+                calc_methods.push(c.method);
+                calc_checks.extend(c.check);
+                access = c.access;
+            },
+            Ok(None) => {},
+            Err(e) => return e
+        }
+
+        layout_fields.push(LayoutField {
+            field,
+            binding: temp_name,
+            access,
+        });
+    }
+
+    let items = match bits::layout(layout_fields, attribute) {
+        Ok(i) => i,
+        Err(e) => return e
+    };
+
+    // Go over layout items and generate expressions
+    for item in &items {
+        // Generate expressions
+        let expressions = match item.expressions() {
+            Ok(e) => e,
+            Err(e) => return e
+        };
+
+        size_expressions.push(expressions.size);
+        from_le_expressions.push(expressions.from_le);
+        from_be_expressions.push(expressions.from_be);
+        to_le_expressions.push(expressions.to_le);
+        to_be_expressions.push(expressions.to_be);
+    }
+
+    // Construct the decoded struct, checking computed fields
+    // and passing it to the validation hook if set
+    let construct = match (&attribute.validate, calc_checks.is_empty()) {
+        (None, true) => quote! {
+            Ok(Self(#(#temp_names),*))
+        },
+        (validate, _) => {
+            let validate = validate.iter();
+            quote! {
+                let value = Self(#(#temp_names),*);
+                #(#calc_checks)*
+                #(#validate(&value)?;)*
+
+                Ok(value)
             }
-        );
-        
-        // Generate to be expression
-        let field_index = Index::from(n);
-        to_be_expressions.push(
-            quote_spanned! {span => 
-                offset += safe_pod::Pod::to_be_bytes(&self.#field_index, &mut buffer[offset..])?;
+        }
+    };
+
+    // Computed fields are encoded from their methods
+    let calc_impl = match calc_methods.is_empty() {
+        true => TokenStream::new(),
+        false => quote! {
+            #[allow(dead_code)]
+            impl #name {
+                #(#calc_methods)*
             }
-        );
+        }
+    };
 
-        // Incement n
-        n += 1;
-    }
+    // Gaps check at compile time that the fields before them fit
+    let checks = items.iter().filter_map(|item| item.check());
 
     // Generate implementation
     quote! {
@@ -88,8 +132,9 @@ pub fn derive_tuple_struct_impl(name: &Ident, struct_data: &FieldsUnnamed) -> To
                 let mut offset = 0usize;
 
                 #(#from_le_expressions)*
+                #(#check_expressions)*
 
-                Ok(Self(#(#temp_names),*))
+                #construct
             }
 
             #[inline]
@@ -102,8 +147,9 @@ pub fn derive_tuple_struct_impl(name: &Ident, struct_data: &FieldsUnnamed) -> To
                 let mut offset = 0usize;
 
                 #(#from_be_expressions)*
+                #(#check_expressions)*
 
-                Ok(Self(#(#temp_names),*))
+                #construct
             }
 
             #[inline]
@@ -132,6 +178,10 @@ pub fn derive_tuple_struct_impl(name: &Ident, struct_data: &FieldsUnnamed) -> To
                 Ok(offset)
             }
         }
+
+        #calc_impl
+
+        #(#checks)*
     }
 }
 
@@ -226,7 +276,7 @@ mod tests {
         }.to_string();
 
         // Output
-        let output = derive_tuple_struct_impl(&name_input, &fields_input).to_string();
+        let output = derive_tuple_struct_impl(&name_input, &StructAttr::default(), &fields_input).to_string();
 
         // Test
         assert_eq!(expected_output, output)
@@ -333,7 +383,7 @@ mod tests {
         }.to_string();
 
         // Output
-        let output = derive_tuple_struct_impl(&name_input, &fields_input).to_string();
+        let output = derive_tuple_struct_impl(&name_input, &StructAttr::default(), &fields_input).to_string();
 
         // Test
         assert_eq!(expected_output, output)
@@ -0,0 +1,334 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{spanned::Spanned, Error, FieldsNamed, Ident, Visibility};
+
+use super::offsets;
+use super::super::attributes::FieldAttr;
+
+/// Implementation of view types for named structs
+pub fn derive_view_impl(name: &Ident, visibility: &Visibility, struct_data: &FieldsNamed) -> TokenStream {
+    let view_name = format_ident!("{}View", name);
+    let view_mut_name = format_ident!("{}ViewMut", name);
+
+    // Define view getters
+    let mut getters: Vec<TokenStream> = Vec::new();
+
+    // Define mutable view getters and setters
+    let mut mut_accessors: Vec<TokenStream> = Vec::new();
+
+    // Go over fields and generate accessors
+    for field in &struct_data.named {
+        let span = field.span();
+        let ty = field.ty.clone();
+
+        // Get field names
+        let field_name = field.ident.clone().unwrap();
+        let setter_name = format_ident!("set_{}", field_name);
+        let field_mut_name = format_ident!("{}_mut", field_name);
+        let offset_name = offsets::offset_name(&field_name);
+        let (read_le, write_le) = offsets::helper_names(&field_name, "le");
+        let (read_be, write_be) = offsets::helper_names(&field_name, "be");
+
+        // Get pod attribute
+        let attribute = match FieldAttr::from_attributes(&field.attrs) {
+            Ok(fa) => fa,
+            Err(e) => match e {
+                "not found" => FieldAttr::default(),
+                _ => {
+                    return Error::new(
+                        span,
+                        format!("Error while parsing #[pod(...)] attribute: {}", e)
+                    ).to_compile_error();
+                }
+            }
+        };
+
+        // Skipped fields aren't in the encoded bytes
+        if attribute.skip.is_some() {
+            continue;
+        }
+
+        let display_name = field_name.to_string().trim_start_matches("r#").to_string();
+        let getter_doc = format!("Decode the `{}` field", display_name);
+        let setter_doc = format!("Encode the `{}` field in place", display_name);
+
+        // Fields with views of their own return nested views
+        if attribute.view {
+            if attribute.scale.is_some() || attribute.offset.is_some() || attribute.with.is_some()
+                || attribute.map.is_some() || attribute.try_map.is_some() {
+                return Error::new(
+                    span,
+                    "view fields cannot be scaled or mapped"
+                ).to_compile_error();
+            }
+
+            getters.push(quote_spanned! {span =>
+                #[doc = #getter_doc]
+                #[inline]
+                pub fn #field_name(&self) -> Result<<#ty as safe_pod::HasView>::View<'a>, safe_pod::PodError> {
+                    let bytes: &'a [u8] = self.bytes;
+                    if self.big_endian {
+                        safe_pod::PodView::new_be(&bytes[#name::#offset_name..])
+                    } else {
+                        safe_pod::PodView::new_le(&bytes[#name::#offset_name..])
+                    }
+                }
+            });
+
+            let field_mut_doc = format!("Get a mutable view of the `{}` field", display_name);
+
+            mut_accessors.push(quote_spanned! {span =>
+                #[doc = #getter_doc]
+                #[inline]
+                pub fn #field_name(&self) -> Result<<#ty as safe_pod::HasView>::View<'_>, safe_pod::PodError> {
+                    if self.big_endian {
+                        safe_pod::PodView::new_be(&self.bytes[#name::#offset_name..])
+                    } else {
+                        safe_pod::PodView::new_le(&self.bytes[#name::#offset_name..])
+                    }
+                }
+
+                #[doc = #field_mut_doc]
+                #[inline]
+                pub fn #field_mut_name(&mut self) -> Result<<#ty as safe_pod::HasView>::ViewMut<'_>, safe_pod::PodError> {
+                    if self.big_endian {
+                        safe_pod::PodViewMut::new_be(&mut self.bytes[#name::#offset_name..])
+                    } else {
+                        safe_pod::PodViewMut::new_le(&mut self.bytes[#name::#offset_name..])
+                    }
+                }
+            });
+        } else {
+            let getter = quote_spanned! {span =>
+                #[doc = #getter_doc]
+                #[inline]
+                pub fn #field_name(&self) -> Result<#ty, safe_pod::PodError> {
+                    if self.big_endian {
+                        #name::#read_be(self.bytes)
+                    } else {
+                        #name::#read_le(self.bytes)
+                    }
+                }
+            };
+
+            getters.push(getter.clone());
+            mut_accessors.push(getter);
+        }
+
+        mut_accessors.push(quote_spanned! {span =>
+            #[doc = #setter_doc]
+            #[inline]
+            pub fn #setter_name(&mut self, value: #ty) -> Result<(), safe_pod::PodError> {
+                if self.big_endian {
+                    #name::#write_be(self.bytes, value)?;
+                } else {
+                    #name::#write_le(self.bytes, value)?;
+                }
+                Ok(())
+            }
+        });
+    }
+
+    let view_doc = format!("Read-only view over the encoded bytes of [`{}`]", name);
+    let view_mut_doc = format!("Mutable view over the encoded bytes of [`{}`]", name);
+
+    // Generate implementation
+    quote! {
+        #[doc = #view_doc]
+        #[derive(Clone, Copy, Debug)]
+        #visibility struct #view_name<'a> {
+            bytes: &'a [u8],
+            big_endian: bool,
+        }
+
+        impl<'a> safe_pod::PodView<'a> for #view_name<'a> {
+            type Target = #name;
+
+            #[inline]
+            fn new_le(bytes: &'a [u8]) -> Result<Self, safe_pod::PodError> {
+                if bytes.len() < <#name as safe_pod::Pod>::SIZE {
+                    return Err(safe_pod::PodError::OutOfSpace);
+                }
+
+                Ok(Self { bytes: &bytes[..<#name as safe_pod::Pod>::SIZE], big_endian: false })
+            }
+
+            #[inline]
+            fn new_be(bytes: &'a [u8]) -> Result<Self, safe_pod::PodError> {
+                if bytes.len() < <#name as safe_pod::Pod>::SIZE {
+                    return Err(safe_pod::PodError::OutOfSpace);
+                }
+
+                Ok(Self { bytes: &bytes[..<#name as safe_pod::Pod>::SIZE], big_endian: true })
+            }
+
+            #[inline]
+            fn as_bytes(&self) -> &'a [u8] {
+                self.bytes
+            }
+
+            #[inline]
+            fn get(&self) -> Result<#name, safe_pod::PodError> {
+                if self.big_endian {
+                    <#name as safe_pod::Pod>::from_be_bytes(self.bytes)
+                } else {
+                    <#name as safe_pod::Pod>::from_le_bytes(self.bytes)
+                }
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<'a> #view_name<'a> {
+            #(#getters)*
+        }
+
+        #[doc = #view_mut_doc]
+        #[derive(Debug)]
+        #visibility struct #view_mut_name<'a> {
+            bytes: &'a mut [u8],
+            big_endian: bool,
+        }
+
+        impl<'a> safe_pod::PodViewMut<'a> for #view_mut_name<'a> {
+            type Target = #name;
+
+            #[inline]
+            fn new_le(bytes: &'a mut [u8]) -> Result<Self, safe_pod::PodError> {
+                if bytes.len() < <#name as safe_pod::Pod>::SIZE {
+                    return Err(safe_pod::PodError::OutOfSpace);
+                }
+
+                Ok(Self { bytes: &mut bytes[..<#name as safe_pod::Pod>::SIZE], big_endian: false })
+            }
+
+            #[inline]
+            fn new_be(bytes: &'a mut [u8]) -> Result<Self, safe_pod::PodError> {
+                if bytes.len() < <#name as safe_pod::Pod>::SIZE {
+                    return Err(safe_pod::PodError::OutOfSpace);
+                }
+
+                Ok(Self { bytes: &mut bytes[..<#name as safe_pod::Pod>::SIZE], big_endian: true })
+            }
+
+            #[inline]
+            fn as_bytes(&self) -> &[u8] {
+                self.bytes
+            }
+
+            #[inline]
+            fn get(&self) -> Result<#name, safe_pod::PodError> {
+                if self.big_endian {
+                    <#name as safe_pod::Pod>::from_be_bytes(self.bytes)
+                } else {
+                    <#name as safe_pod::Pod>::from_le_bytes(self.bytes)
+                }
+            }
+
+            #[inline]
+            fn set(&mut self, value: &#name) -> Result<(), safe_pod::PodError> {
+                if self.big_endian {
+                    safe_pod::Pod::to_be_bytes(value, self.bytes)?;
+                } else {
+                    safe_pod::Pod::to_le_bytes(value, self.bytes)?;
+                }
+                Ok(())
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<'a> #view_mut_name<'a> {
+            #(#mut_accessors)*
+        }
+
+        impl safe_pod::HasView for #name {
+            type View<'a> = #view_name<'a>;
+            type ViewMut<'a> = #view_mut_name<'a>;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse2, Data, DeriveInput, Fields};
+
+    use super::*;
+
+    // Gets the name, visibility and fields of a named struct
+    fn named_struct(input_stream: TokenStream) -> (Ident, Visibility, FieldsNamed) {
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+        match input.data {
+            Data::Struct(ds) => match ds.fields {
+                Fields::Named(f) => (input.ident, input.vis, f),
+                _ => unreachable!()
+            },
+            _ => unreachable!()
+        }
+    }
+
+    #[test]
+    fn view_accessors() {
+        // Define input
+        let (name, visibility, fields) = named_struct(quote! {
+            pub struct Foo {
+                a: u8,
+                b: u16,
+            }
+        });
+
+        // Define expected output
+        let expected_getter = quote! {
+            #[doc = "Decode the `b` field"]
+            #[inline]
+            pub fn b(&self) -> Result<u16, safe_pod::PodError> {
+                if self.big_endian {
+                    Foo::read_b_be(self.bytes)
+                } else {
+                    Foo::read_b_le(self.bytes)
+                }
+            }
+        }.to_string();
+        let expected_setter = quote! {
+            #[doc = "Encode the `b` field in place"]
+            #[inline]
+            pub fn set_b(&mut self, value: u16) -> Result<(), safe_pod::PodError> {
+                if self.big_endian {
+                    Foo::write_b_be(self.bytes, value)?;
+                } else {
+                    Foo::write_b_le(self.bytes, value)?;
+                }
+                Ok(())
+            }
+        }.to_string();
+        let expected_view = quote! {
+            pub struct FooView<'a> {
+                bytes: &'a [u8],
+                big_endian: bool,
+            }
+        }.to_string();
+
+        // Output
+        let output = derive_view_impl(&name, &visibility, &fields).to_string();
+
+        // Test
+        assert!(output.contains(&expected_getter));
+        assert!(output.contains(&expected_setter));
+        assert!(output.contains(&expected_view));
+    }
+
+    #[test]
+    fn view_field_scaled() {
+        // Define input
+        let (name, visibility, fields) = named_struct(quote! {
+            struct Foo {
+                #[pod(view, repr(i16), scale = 0.5)]
+                a: Bar,
+            }
+        });
+
+        // Output
+        let output = derive_view_impl(&name, &visibility, &fields).to_string();
+
+        // Test
+        assert!(output.contains("view fields cannot be scaled"))
+    }
+}
@@ -0,0 +1,173 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, DataEnum, Error, Fields, Ident, Visibility};
+
+use super::{derive_flags_impl, flag_attribute, flag_mask, Flag, FlagsType};
+
+/// Implementation of derive macro for enums of single bit flags,
+/// which generates a `{Name}Flags` set type
+pub fn derive_enum_impl(name: &Ident, visibility: &Visibility, flags_type: &FlagsType, enum_data: &DataEnum) -> TokenStream {
+    let flags_name = format_ident!("{}Flags", name);
+
+    // Define flags and the match arms of their bits
+    let mut flags: Vec<Flag> = Vec::new();
+    let mut bit_arms: Vec<TokenStream> = Vec::new();
+
+    // Go over variants and assign bits
+    for variant in &enum_data.variants {
+        let span = variant.span();
+        let variant_name = variant.ident.clone();
+
+        if !matches!(variant.fields, Fields::Unit) {
+            return Error::new(
+                span,
+                "PodFlags can only be derived for enums with unit variants"
+            ).to_compile_error();
+        }
+
+        let attribute = match flag_attribute(&variant.attrs, span) {
+            Ok(a) => a,
+            Err(e) => return e
+        };
+        if attribute.unknown {
+            return Error::new(
+                span,
+                "unknown attribute is only supported on struct fields"
+            ).to_compile_error();
+        }
+
+        let mask = match flag_mask(flags_type, attribute.bit, flags.len(), &flags, span) {
+            Ok(m) => m,
+            Err(e) => return e
+        };
+
+        let mask_literal = Literal::u64_unsuffixed(mask);
+        bit_arms.push(quote! { #name::#variant_name => #mask_literal, });
+        flags.push(Flag {
+            name: variant_name.to_string(),
+            mask,
+            item: quote! { #name::#variant_name },
+        });
+    }
+
+    if flags.is_empty() {
+        return Error::new(
+            name.span(),
+            "PodFlags needs at least one flag"
+        ).to_compile_error();
+    }
+
+    let repr = &flags_type.repr;
+    let flags_doc = format!("Set of [`{}`] flags", name);
+
+    let flags_impl = derive_flags_impl(
+        &flags_name,
+        flags_type,
+        &flags,
+        &quote! { self.bits },
+        &quote! { Self { bits } },
+        &quote! { #name }
+    );
+
+    quote! {
+        #[doc = #flags_doc]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+        #visibility struct #flags_name {
+            bits: #repr,
+        }
+
+        impl safe_pod::Zeroable for #flags_name {
+            #[inline]
+            fn zeroed() -> Self {
+                Self { bits: 0 }
+            }
+        }
+
+        impl From<#name> for #flags_name {
+            #[inline]
+            fn from(flag: #name) -> Self {
+                let bits = match flag {
+                    #(#bit_arms)*
+                };
+
+                Self { bits }
+            }
+        }
+
+        impl<T: Into<#flags_name>> ::core::ops::BitOr<T> for #name {
+            type Output = #flags_name;
+
+            #[inline]
+            fn bitor(self, rhs: T) -> #flags_name {
+                #flags_name::from(self) | rhs
+            }
+        }
+
+        #flags_impl
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::{parse2, DeriveInput};
+
+    use super::super::derive_pod_flags_impl;
+
+    #[test]
+    fn flags_enum() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(repr(u16))]
+            enum Foo {
+                A,
+                #[pod(bit = 4)]
+                B,
+            }
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+
+        // Define expected output
+        let expected_from = quote! {
+            impl From<Foo> for FooFlags {
+                #[inline]
+                fn from(flag: Foo) -> Self {
+                    let bits = match flag {
+                        Foo::A => 1,
+                        Foo::B => 16,
+                    };
+
+                    Self { bits }
+                }
+            }
+        }.to_string();
+
+        // Output
+        let output = derive_pod_flags_impl(input).to_string();
+
+        // Test
+        assert!(output.contains(&expected_from))
+    }
+
+    #[test]
+    fn flags_enum_shared_bit() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(repr(u8))]
+            enum Foo {
+                #[pod(bit = 1)]
+                A,
+                B,
+            }
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+
+        // Output
+        let output = derive_pod_flags_impl(input).to_string();
+
+        // Test
+        assert!(output.contains("flags cannot share a bit"))
+    }
+}
@@ -0,0 +1,351 @@
+// Define modules
+mod r#struct;
+mod r#enum;
+
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Error, Ident, Type};
+
+use crate::pod::attributes::{FlagAttr, FlagsAttr, UnknownBits};
+
+/// Implementation of derive macro for PodFlags trait
+pub fn derive_pod_flags_impl(input: DeriveInput) -> TokenStream {
+    // If the type that derives PodFlags is a union
+    // return error
+    if let Data::Union(d) = &input.data {
+        return Error::new(
+            d.union_token.span,
+            "Union types cannot derive PodFlags"
+        ).to_compile_error();
+    }
+
+    // Get pod attribute
+    let pod_attribute = match FlagsAttr::from_attributes(&input.attrs) {
+        Ok(pa) => pa,
+        Err(e) => match e {
+            "not found" => FlagsAttr::default(),
+            _ => {
+                return Error::new(
+                    input.ident.span(),
+                    format!("Error while parsing #[pod(...)] attribute: {}", e)
+                ).to_compile_error();
+            }
+        }
+    };
+
+    // Flags are stored in an unsigned integer
+    let repr = match &pod_attribute.repr {
+        Some(r) => r.clone(),
+        None => {
+            return Error::new(
+                input.ident.span(),
+                "PodFlags needs #[pod(repr($type))] set to u8, u16, u32 or u64"
+            ).to_compile_error();
+        }
+    };
+    let width = match &repr {
+        Type::Path(t) if t.path.is_ident("u8") => 8,
+        Type::Path(t) if t.path.is_ident("u16") => 16,
+        Type::Path(t) if t.path.is_ident("u32") => 32,
+        Type::Path(t) if t.path.is_ident("u64") => 64,
+        _ => {
+            return Error::new(
+                input.ident.span(),
+                "PodFlags repr must be one of u8, u16, u32 or u64"
+            ).to_compile_error();
+        }
+    };
+
+    let flags_type = FlagsType {
+        repr,
+        width,
+        unknown_bits: pod_attribute.unknown_bits,
+    };
+
+    // Match type and delegate to appropriate impl functions
+    match &input.data {
+        Data::Struct(d) => return r#struct::derive_struct_impl(&input.ident, &flags_type, d),
+        Data::Enum(d) => return r#enum::derive_enum_impl(&input.ident, &input.vis, &flags_type, d),
+        Data::Union(_) => return TokenStream::new(),
+    }
+}
+
+/// Integer representation of a flags type
+pub struct FlagsType {
+    /// Integer the flags are stored in
+    pub repr: Type,
+    /// Number of bits of the integer
+    pub width: u32,
+    /// What decoding does with bits that aren't flags
+    pub unknown_bits: UnknownBits,
+}
+
+impl FlagsType {
+    // Mask of all the bits of the integer
+    fn all_bits(&self) -> u64 {
+        u64::MAX >> (64 - self.width)
+    }
+}
+
+/// A single flag of a flags type
+pub struct Flag {
+    /// Name of the flag used by `Debug`
+    pub name: String,
+    /// Mask of the flag's bit
+    pub mask: u64,
+    /// Expression of the flag yielded by `iter`
+    pub item: TokenStream,
+}
+
+/// Gets the `#[pod(...)]` attribute of a field or variant
+pub fn flag_attribute(attributes: &Vec<Attribute>, span: Span) -> Result<FlagAttr, TokenStream> {
+    match FlagAttr::from_attributes(attributes) {
+        Ok(fa) => return Ok(fa),
+        Err(e) => match e {
+            "not found" => return Ok(FlagAttr::default()),
+            _ => {
+                return Err(Error::new(
+                    span,
+                    format!("Error while parsing #[pod(...)] attribute: {}", e)
+                ).to_compile_error());
+            }
+        }
+    }
+}
+
+/// Gets the mask of the flag at `index`, from its `bit`
+/// attribute if set, checking it against the flags before it
+pub fn flag_mask(flags_type: &FlagsType, bit: Option<u32>, index: usize, flags: &[Flag], span: Span) -> Result<u64, TokenStream> {
+    let bit = bit.unwrap_or(index as u32);
+    if bit >= flags_type.width {
+        return Err(Error::new(
+            span,
+            "flag bit must be less than the number of bits of repr"
+        ).to_compile_error());
+    }
+
+    let mask = 1u64 << bit;
+    if flags.iter().any(|f| f.mask == mask) {
+        return Err(Error::new(
+            span,
+            "flags cannot share a bit"
+        ).to_compile_error());
+    }
+
+    return Ok(mask);
+}
+
+/// Generates the implementations shared by every flags type, where
+/// `bits` computes the raw bits of `self`, `construct` creates an
+/// instance from a local `bits` variable, keeping unknown bits if the
+/// type can store them, and `item` is the type yielded by `iter`
+pub fn derive_flags_impl(
+    name: &Ident,
+    flags_type: &FlagsType,
+    flags: &[Flag],
+    bits: &TokenStream,
+    construct: &TokenStream,
+    item: &TokenStream
+) -> TokenStream {
+    let repr = &flags_type.repr;
+
+    // Get masks
+    let known_bits = flags.iter().fold(0u64, |known, f| known | f.mask);
+    let unknown_bits = flags_type.all_bits() & !known_bits;
+    let known = Literal::u64_unsuffixed(known_bits);
+    let unknown = Literal::u64_unsuffixed(unknown_bits);
+
+    let masks: Vec<Literal> = flags.iter().map(|f| Literal::u64_unsuffixed(f.mask)).collect();
+    let names: Vec<&String> = flags.iter().map(|f| &f.name).collect();
+    let items: Vec<&TokenStream> = flags.iter().map(|f| &f.item).collect();
+    let name_str = name.to_string();
+
+    // Apply unknown bit policy
+    let from_bits = match flags_type.unknown_bits {
+        UnknownBits::Reject if unknown_bits != 0 => quote! {
+            if bits & #unknown != 0 {
+                return Err(safe_pod::PodError::OutOfRange);
+            }
+
+            Ok(#construct)
+        },
+        UnknownBits::Reject | UnknownBits::Preserve => quote! {
+            Ok(#construct)
+        },
+        UnknownBits::Drop => quote! {
+            let bits = bits & #known;
+
+            Ok(#construct)
+        }
+    };
+
+    quote! {
+        impl safe_pod::PodFlags for #name {
+            type Bits = #repr;
+
+            const KNOWN: #repr = #known;
+
+            #[inline]
+            fn bits(&self) -> #repr {
+                #bits
+            }
+
+            #[inline]
+            fn from_bits(bits: #repr) -> Result<Self, safe_pod::PodError> {
+                #from_bits
+            }
+
+            #[inline]
+            fn from_bits_truncate(bits: #repr) -> Self {
+                let bits = bits & #known;
+
+                #construct
+            }
+        }
+
+        impl safe_pod::Pod for #name {
+            const SIZE: usize = <#repr as safe_pod::Pod>::SIZE;
+
+            #[inline]
+            fn from_le_bytes(buffer: &[u8]) -> Result<Self, safe_pod::PodError> {
+                <Self as safe_pod::PodFlags>::from_bits(<#repr as safe_pod::Pod>::from_le_bytes(buffer)?)
+            }
+
+            #[inline]
+            fn from_be_bytes(buffer: &[u8]) -> Result<Self, safe_pod::PodError> {
+                <Self as safe_pod::PodFlags>::from_bits(<#repr as safe_pod::Pod>::from_be_bytes(buffer)?)
+            }
+
+            #[inline]
+            fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, safe_pod::PodError> {
+                safe_pod::Pod::to_le_bytes(&safe_pod::PodFlags::bits(self), buffer)
+            }
+
+            #[inline]
+            fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, safe_pod::PodError> {
+                safe_pod::Pod::to_be_bytes(&safe_pod::PodFlags::bits(self), buffer)
+            }
+        }
+
+        #[allow(dead_code)]
+        impl #name {
+            /// No flags set
+            #[inline]
+            pub fn empty() -> Self {
+                <Self as safe_pod::PodFlags>::from_bits_truncate(0)
+            }
+
+            /// All known flags set
+            #[inline]
+            pub fn all() -> Self {
+                <Self as safe_pod::PodFlags>::from_bits_truncate(#known)
+            }
+
+            /// Check if no known flag is set
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                safe_pod::PodFlags::bits(self) & #known == 0
+            }
+
+            /// Check if all the flags of `other` are set
+            #[inline]
+            pub fn contains(&self, other: impl Into<Self>) -> bool {
+                let other = safe_pod::PodFlags::bits(&other.into());
+
+                safe_pod::PodFlags::bits(self) & other == other
+            }
+
+            /// Set the flags of `other`
+            #[inline]
+            pub fn insert(&mut self, other: impl Into<Self>) {
+                let bits = safe_pod::PodFlags::bits(self) | safe_pod::PodFlags::bits(&other.into());
+
+                *self = #construct;
+            }
+
+            /// Clear the flags of `other`
+            #[inline]
+            pub fn remove(&mut self, other: impl Into<Self>) {
+                let bits = safe_pod::PodFlags::bits(self) & !safe_pod::PodFlags::bits(&other.into());
+
+                *self = #construct;
+            }
+
+            /// Iterate over the known flags that are set
+            #[inline]
+            pub fn iter(&self) -> impl Iterator<Item = #item> {
+                let bits = safe_pod::PodFlags::bits(self);
+
+                [#((#masks, #items)),*].into_iter()
+                    .filter(move |(mask, _)| bits & mask == *mask)
+                    .map(|(_, flag)| flag)
+            }
+        }
+
+        impl<T: Into<#name>> ::core::ops::BitOr<T> for #name {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(mut self, rhs: T) -> Self {
+                self.insert(rhs);
+                self
+            }
+        }
+
+        impl<T: Into<#name>> ::core::ops::BitOrAssign<T> for #name {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: T) {
+                self.insert(rhs);
+            }
+        }
+
+        impl<T: Into<#name>> ::core::ops::BitAnd<T> for #name {
+            type Output = Self;
+
+            #[inline]
+            fn bitand(self, rhs: T) -> Self {
+                let bits = safe_pod::PodFlags::bits(&self) & safe_pod::PodFlags::bits(&rhs.into());
+
+                #construct
+            }
+        }
+
+        impl<T: Into<#name>> ::core::ops::BitAndAssign<T> for #name {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: T) {
+                let bits = safe_pod::PodFlags::bits(self) & safe_pod::PodFlags::bits(&rhs.into());
+
+                *self = #construct;
+            }
+        }
+
+        impl ::core::fmt::Debug for #name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let bits = safe_pod::PodFlags::bits(self);
+
+                // List set flags by name, then any unknown bits
+                write!(f, "{}(", #name_str)?;
+                let mut first = true;
+                for (mask, flag) in [#((#masks, #names)),*] {
+                    if bits & mask == mask {
+                        if !first {
+                            write!(f, " | ")?;
+                        }
+                        write!(f, "{}", flag)?;
+                        first = false;
+                    }
+                }
+
+                let unknown = bits & #unknown;
+                if unknown != 0 || first {
+                    if !first {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{:#x}", unknown)?;
+                }
+
+                write!(f, ")")
+            }
+        }
+    }
+}
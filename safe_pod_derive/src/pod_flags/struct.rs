@@ -0,0 +1,170 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use syn::{spanned::Spanned, DataStruct, Error, Fields, Ident};
+
+use super::{derive_flags_impl, flag_attribute, flag_mask, Flag, FlagsType};
+use crate::pod::attributes::UnknownBits;
+
+/// Implementation of derive macro for structs of `bool` flags
+pub fn derive_struct_impl(name: &Ident, flags_type: &FlagsType, struct_data: &DataStruct) -> TokenStream {
+    let fields = match &struct_data.fields {
+        Fields::Named(f) => f,
+        _ => {
+            return Error::new(
+                name.span(),
+                "PodFlags can only be derived for structs with named fields"
+            ).to_compile_error();
+        }
+    };
+
+    // Define flags and the field keeping unknown bits, if any
+    let mut flags: Vec<Flag> = Vec::new();
+    let mut flag_fields: Vec<Ident> = Vec::new();
+    let mut unknown_field: Option<Ident> = None;
+
+    // Go over fields and assign bits
+    for field in &fields.named {
+        let span = field.span();
+        let field_name = field.ident.clone().unwrap();
+
+        let attribute = match flag_attribute(&field.attrs, span) {
+            Ok(a) => a,
+            Err(e) => return e
+        };
+
+        if attribute.unknown {
+            if flags_type.unknown_bits != UnknownBits::Preserve {
+                return Error::new(
+                    span,
+                    "unknown field requires unknown_bits = preserve on the struct"
+                ).to_compile_error();
+            }
+            if unknown_field.is_some() || attribute.bit.is_some() {
+                return Error::new(
+                    span,
+                    "only one field can be unknown, and it cannot have a bit"
+                ).to_compile_error();
+            }
+
+            unknown_field = Some(field_name);
+            continue;
+        }
+
+        let mask = match flag_mask(flags_type, attribute.bit, flags.len(), &flags, span) {
+            Ok(m) => m,
+            Err(e) => return e
+        };
+
+        let mask_literal = Literal::u64_unsuffixed(mask);
+        flags.push(Flag {
+            name: field_name.to_string().trim_start_matches("r#").to_string(),
+            mask,
+            item: quote! { <Self as safe_pod::PodFlags>::from_bits_truncate(#mask_literal) },
+        });
+        flag_fields.push(field_name);
+    }
+
+    if flags.is_empty() {
+        return Error::new(
+            name.span(),
+            "PodFlags needs at least one flag"
+        ).to_compile_error();
+    }
+    if flags_type.unknown_bits == UnknownBits::Preserve && unknown_field.is_none() {
+        return Error::new(
+            name.span(),
+            "unknown_bits = preserve needs a field marked #[pod(unknown)] to keep the bits in"
+        ).to_compile_error();
+    }
+
+    let repr = &flags_type.repr;
+    let masks: Vec<Literal> = flags.iter().map(|f| Literal::u64_unsuffixed(f.mask)).collect();
+    let known_bits = flags.iter().fold(0u64, |known, f| known | f.mask);
+    let unknown = Literal::u64_unsuffixed(flags_type.all_bits() & !known_bits);
+
+    // Unknown bits are kept in their own field
+    let (initial_bits, unknown_init) = match &unknown_field {
+        Some(f) => (quote! { self.#f & #unknown }, quote! { #f: bits & #unknown, }),
+        None => (quote! { 0 }, TokenStream::new())
+    };
+
+    let bits = quote! {
+        let mut bits: #repr = #initial_bits;
+        #(
+            if self.#flag_fields {
+                bits |= #masks;
+            }
+        )*
+        bits
+    };
+    let construct = quote! {
+        Self {
+            #(#flag_fields: (bits & #masks) != 0,)*
+            #unknown_init
+        }
+    };
+
+    return derive_flags_impl(name, flags_type, &flags, &bits, &construct, &quote! { Self });
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::{parse2, DeriveInput};
+
+    use super::super::derive_pod_flags_impl;
+
+    #[test]
+    fn flags_struct() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(repr(u8))]
+            struct Foo {
+                a: bool,
+                #[pod(bit = 7)]
+                b: bool,
+            }
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+
+        // Define expected output
+        let expected_from_bits = quote! {
+            fn from_bits(bits: u8) -> Result<Self, safe_pod::PodError> {
+                if bits & 126 != 0 {
+                    return Err(safe_pod::PodError::OutOfRange);
+                }
+
+                Ok(Self {
+                    a: (bits & 1) != 0,
+                    b: (bits & 128) != 0,
+                })
+            }
+        }.to_string();
+
+        // Output
+        let output = derive_pod_flags_impl(input).to_string();
+
+        // Test
+        assert!(output.contains(&expected_from_bits))
+    }
+
+    #[test]
+    fn flags_struct_preserve_without_field() {
+        // Define input
+        let input_stream = quote! {
+            #[pod(repr(u8), unknown_bits = preserve)]
+            struct Foo {
+                a: bool,
+            }
+        };
+
+        let input = parse2::<DeriveInput>(input_stream).unwrap();
+
+        // Output
+        let output = derive_pod_flags_impl(input).to_string();
+
+        // Test
+        assert!(output.contains("unknown_bits = preserve needs a field marked #[pod(unknown)] to keep the bits in"))
+    }
+}
@@ -0,0 +1,13 @@
+use proc_macro2::TokenStream;
+use syn::{parse2, Data, DeriveInput, Field, Fields};
+
+/// Gets the fields of a named struct
+pub fn named_fields(input_stream: TokenStream) -> Vec<Field> {
+    match parse2::<DeriveInput>(input_stream).unwrap().data {
+        Data::Struct(ds) => match ds.fields {
+            Fields::Named(f) => f.named.into_iter().collect(),
+            _ => unreachable!()
+        },
+        _ => unreachable!()
+    }
+}
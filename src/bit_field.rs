@@ -0,0 +1,123 @@
+use crate::pod::PodError;
+
+/// A type that can be stored in a `#[pod(bits = N)]` field
+///
+/// Implemented for the primitive integers and `bool`, and derived
+/// for enums with a primitive integer `repr`.
+pub trait BitField: Sized {
+    /// Create instance from the low `width` bits of `bits`,
+    /// failing with [`PodError::OutOfRange`] if the value doesn't fit
+    fn from_bits(bits: u64, width: u32) -> Result<Self, PodError>;
+
+    /// Get the instance as `width` bits, failing with
+    /// [`PodError::OutOfRange`] if it doesn't fit in them
+    fn to_bits(&self, width: u32) -> Result<u64, PodError>;
+}
+
+// Mask of the low `width` bits
+#[inline]
+fn mask(width: u32) -> u64 {
+    u64::MAX >> (64 - width.clamp(1, 64))
+}
+
+// Implement BitField for unsigned integers
+macro_rules! impl_bit_field_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl BitField for $t {
+                #[inline]
+                fn from_bits(bits: u64, width: u32) -> Result<Self, PodError> {
+                    <$t>::try_from(bits & mask(width)).map_err(|_| PodError::OutOfRange)
+                }
+
+                #[inline]
+                fn to_bits(&self, width: u32) -> Result<u64, PodError> {
+                    let bits = *self as u64;
+
+                    if bits & !mask(width) != 0 {
+                        return Err(PodError::OutOfRange);
+                    }
+
+                    Ok(bits)
+                }
+            }
+        )*
+    };
+}
+
+// Implement BitField for signed integers, stored in two's complement
+macro_rules! impl_bit_field_signed {
+    ($($t:ty),*) => {
+        $(
+            impl BitField for $t {
+                #[inline]
+                fn from_bits(bits: u64, width: u32) -> Result<Self, PodError> {
+                    let shift = 64 - width.clamp(1, 64);
+                    let value = ((bits << shift) as i64) >> shift;
+
+                    <$t>::try_from(value).map_err(|_| PodError::OutOfRange)
+                }
+
+                #[inline]
+                fn to_bits(&self, width: u32) -> Result<u64, PodError> {
+                    let value = *self as i64;
+                    let shift = 64 - width.clamp(1, 64);
+
+                    // The value must survive a sign extending roundtrip
+                    if ((value << shift) >> shift) != value {
+                        return Err(PodError::OutOfRange);
+                    }
+
+                    Ok(value as u64 & mask(width))
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_field_unsigned!(u8, u16, u32, u64);
+impl_bit_field_signed!(i8, i16, i32, i64);
+
+impl BitField for bool {
+    #[inline]
+    fn from_bits(bits: u64, width: u32) -> Result<Self, PodError> {
+        match bits & mask(width) {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(PodError::OutOfRange),
+        }
+    }
+
+    #[inline]
+    fn to_bits(&self, _width: u32) -> Result<u64, PodError> {
+        Ok(*self as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_field() {
+        // Unsigned
+        assert_eq!(u8::from_bits(0b1010, 4), Ok(10));
+        assert_eq!(u8::from_bits(0x1ff, 9), Err(PodError::OutOfRange));
+        assert_eq!(15u8.to_bits(4), Ok(15));
+        assert_eq!(16u8.to_bits(4), Err(PodError::OutOfRange));
+        assert_eq!(u64::MAX.to_bits(64), Ok(u64::MAX));
+
+        // Signed
+        assert_eq!(i8::from_bits(0b1111, 4), Ok(-1));
+        assert_eq!(i8::from_bits(0b0111, 4), Ok(7));
+        assert_eq!((-8i8).to_bits(4), Ok(0b1000));
+        assert_eq!((-9i8).to_bits(4), Err(PodError::OutOfRange));
+        assert_eq!(8i8.to_bits(4), Err(PodError::OutOfRange));
+        assert_eq!(i64::MIN.to_bits(64), Ok(1 << 63));
+
+        // Bool
+        assert_eq!(bool::from_bits(1, 1), Ok(true));
+        assert_eq!(bool::from_bits(2, 2), Err(PodError::OutOfRange));
+        assert_eq!(true.to_bits(1), Ok(1));
+    }
+}
@@ -0,0 +1,481 @@
+use crate::bit_field::BitField;
+use crate::pod::{Pod, PodError};
+
+/// Order in which the bits of each byte of a bit stream are used
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+    /// From the most significant bit down, as in H.264 and FLAC,
+    /// where the first bit read is the most significant bit of the value
+    #[default]
+    Msb,
+    /// From the least significant bit up, as in DEFLATE,
+    /// where the first bit read is the least significant bit of the value
+    Lsb,
+}
+
+// Mask of the low `width` bits, for widths of up to 8
+#[inline]
+fn byte_mask(width: u32) -> u8 {
+    (0xffu16 >> (8 - width)) as u8
+}
+
+/// Reads values bit by bit from a byte slice
+///
+/// Reads fail with [`PodError::OutOfSpace`] if the buffer ends before the
+/// value does and with [`PodError::OutOfRange`] if the value doesn't fit its
+/// type. A failed read leaves the position unchanged.
+#[derive(Clone, Copy, Debug)]
+pub struct BitReader<'a> {
+    buffer: &'a [u8],
+    position: usize,
+    order: BitOrder,
+}
+
+impl<'a> BitReader<'a> {
+    /// Create a reader at the start of `buffer`
+    pub fn new(buffer: &'a [u8], order: BitOrder) -> Self {
+        Self { buffer, position: 0, order }
+    }
+
+    /// Get the bit order of the reader
+    pub fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    /// Get the number of bits read so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Get the number of bits left in the buffer
+    pub fn remaining(&self) -> usize {
+        self.buffer.len() * 8 - self.position
+    }
+
+    /// Check if the position is at a byte boundary
+    pub fn is_aligned(&self) -> bool {
+        self.position.is_multiple_of(8)
+    }
+
+    /// Skip to the next byte boundary, if not at one already
+    pub fn align(&mut self) {
+        self.position = self.position.next_multiple_of(8);
+    }
+
+    /// Skip `count` bits
+    pub fn skip(&mut self, count: usize) -> Result<(), PodError> {
+        if count > self.remaining() {
+            return Err(PodError::OutOfSpace);
+        }
+
+        self.position += count;
+
+        Ok(())
+    }
+
+    /// Read a single bit
+    pub fn read_bit(&mut self) -> Result<bool, PodError> {
+        Ok(self.read_bits(1)? == 1)
+    }
+
+    /// Read `count` bits, at most 64, as an unsigned value
+    pub fn read_bits(&mut self, count: u32) -> Result<u64, PodError> {
+        if count > 64 {
+            return Err(PodError::OutOfRange);
+        }
+        if count as usize > self.remaining() {
+            return Err(PodError::OutOfSpace);
+        }
+
+        let mut value = 0u64;
+        let mut read = 0u32;
+        while read < count {
+            let byte = self.buffer[self.position / 8];
+            let offset = (self.position % 8) as u32;
+            let width = (8 - offset).min(count - read);
+
+            match self.order {
+                BitOrder::Msb => {
+                    let chunk = (byte >> (8 - offset - width)) & byte_mask(width);
+                    value = (value << width) | chunk as u64;
+                },
+                BitOrder::Lsb => {
+                    let chunk = (byte >> offset) & byte_mask(width);
+                    value |= (chunk as u64) << read;
+                }
+            }
+
+            read += width;
+            self.position += width as usize;
+        }
+
+        Ok(value)
+    }
+
+    /// Read `count` bits, at most 64, as a two's complement signed value
+    pub fn read_signed(&mut self, count: u32) -> Result<i64, PodError> {
+        if count == 0 || count > 64 {
+            return Err(PodError::OutOfRange);
+        }
+
+        let bits = self.read_bits(count)?;
+
+        i64::from_bits(bits, count)
+    }
+
+    /// Read an unsigned Exp-Golomb code, as used by H.264 `ue(v)`
+    pub fn read_ue(&mut self) -> Result<u64, PodError> {
+        let start = self.position;
+        let result = self.read_ue_inner();
+        if result.is_err() {
+            self.position = start;
+        }
+
+        result
+    }
+
+    // Read an unsigned Exp-Golomb code without restoring the position
+    fn read_ue_inner(&mut self) -> Result<u64, PodError> {
+        let mut leading_zeros = 0u32;
+        while !self.read_bit()? {
+            leading_zeros += 1;
+            if leading_zeros == 64 {
+                return Err(PodError::OutOfRange);
+            }
+        }
+
+        // The suffix is read most significant bit first in either order
+        let mut suffix = 0u64;
+        for _ in 0..leading_zeros {
+            suffix = (suffix << 1) | self.read_bit()? as u64;
+        }
+
+        Ok(((1u64 << leading_zeros) - 1) + suffix)
+    }
+
+    /// Read a signed Exp-Golomb code, as used by H.264 `se(v)`
+    pub fn read_se(&mut self) -> Result<i64, PodError> {
+        let code = self.read_ue()?;
+
+        // Odd codes are positive, even codes are negative,
+        // and codes are at most u64::MAX - 1 so the magnitude fits
+        let magnitude = (code / 2 + code % 2) as i64;
+
+        match code % 2 {
+            1 => Ok(magnitude),
+            _ => Ok(-magnitude),
+        }
+    }
+
+    /// Read a [`Pod`] type from little endian bytes at a byte boundary,
+    /// failing with [`PodError::OutOfRange`] if not at one
+    pub fn read_pod_le<T: Pod>(&mut self) -> Result<T, PodError> {
+        let value = T::from_le_bytes(self.aligned_bytes()?)?;
+        self.position += T::SIZE * 8;
+
+        Ok(value)
+    }
+
+    /// Read a [`Pod`] type from big endian bytes at a byte boundary,
+    /// failing with [`PodError::OutOfRange`] if not at one
+    pub fn read_pod_be<T: Pod>(&mut self) -> Result<T, PodError> {
+        let value = T::from_be_bytes(self.aligned_bytes()?)?;
+        self.position += T::SIZE * 8;
+
+        Ok(value)
+    }
+
+    // Get the bytes from the position on, which must be at a byte boundary
+    fn aligned_bytes(&self) -> Result<&'a [u8], PodError> {
+        if !self.is_aligned() {
+            return Err(PodError::OutOfRange);
+        }
+
+        Ok(&self.buffer[self.position / 8..])
+    }
+}
+
+/// Writes values bit by bit to a byte slice
+///
+/// Writes fail with [`PodError::OutOfSpace`] if the buffer ends before the
+/// value does and with [`PodError::OutOfRange`] if the value doesn't fit in
+/// the requested bits. A failed write leaves the position unchanged.
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    buffer: &'a mut [u8],
+    position: usize,
+    order: BitOrder,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Create a writer at the start of `buffer`
+    pub fn new(buffer: &'a mut [u8], order: BitOrder) -> Self {
+        Self { buffer, position: 0, order }
+    }
+
+    /// Get the bit order of the writer
+    pub fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    /// Get the number of bits written so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Get the number of bits left in the buffer
+    pub fn remaining(&self) -> usize {
+        self.buffer.len() * 8 - self.position
+    }
+
+    /// Get the number of bytes written to so far,
+    /// counting a partially written last byte
+    pub fn bytes_written(&self) -> usize {
+        self.position.div_ceil(8)
+    }
+
+    /// Check if the position is at a byte boundary
+    pub fn is_aligned(&self) -> bool {
+        self.position.is_multiple_of(8)
+    }
+
+    /// Pad with zero bits to the next byte boundary, if not at one already
+    pub fn align(&mut self) {
+        let padding = self.position.next_multiple_of(8) - self.position;
+
+        // A partially written byte is always in the buffer
+        let _ = self.write_bits(0, padding as u32);
+    }
+
+    /// Write a single bit
+    pub fn write_bit(&mut self, bit: bool) -> Result<(), PodError> {
+        self.write_bits(bit as u64, 1)
+    }
+
+    /// Write the low `count` bits, at most 64, of an unsigned value
+    pub fn write_bits(&mut self, value: u64, count: u32) -> Result<(), PodError> {
+        if count > 64 || (count < 64 && value >> count != 0) {
+            return Err(PodError::OutOfRange);
+        }
+        if count as usize > self.remaining() {
+            return Err(PodError::OutOfSpace);
+        }
+
+        let mut written = 0u32;
+        while written < count {
+            let offset = (self.position % 8) as u32;
+            let width = (8 - offset).min(count - written);
+            let byte = &mut self.buffer[self.position / 8];
+
+            let (chunk, shift) = match self.order {
+                BitOrder::Msb => ((value >> (count - written - width)) as u8 & byte_mask(width), 8 - offset - width),
+                BitOrder::Lsb => ((value >> written) as u8 & byte_mask(width), offset),
+            };
+            *byte = (*byte & !(byte_mask(width) << shift)) | (chunk << shift);
+
+            written += width;
+            self.position += width as usize;
+        }
+
+        Ok(())
+    }
+
+    /// Write a value as `count` bits, at most 64, of two's complement
+    pub fn write_signed(&mut self, value: i64, count: u32) -> Result<(), PodError> {
+        if count == 0 || count > 64 {
+            return Err(PodError::OutOfRange);
+        }
+
+        self.write_bits(value.to_bits(count)?, count)
+    }
+
+    /// Write an unsigned Exp-Golomb code, as used by H.264 `ue(v)`
+    pub fn write_ue(&mut self, value: u64) -> Result<(), PodError> {
+        let code = value.checked_add(1).ok_or(PodError::OutOfRange)?;
+        let width = 64 - code.leading_zeros();
+
+        // Check for space first so the prefix isn't written alone
+        if (2 * width - 1) as usize > self.remaining() {
+            return Err(PodError::OutOfSpace);
+        }
+
+        self.write_bits(0, width - 1)?;
+
+        // The code is written most significant bit first in either order,
+        // like the reader reads it
+        for bit in (0..width).rev() {
+            self.write_bit((code >> bit) & 1 == 1)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write a signed Exp-Golomb code, as used by H.264 `se(v)`
+    pub fn write_se(&mut self, value: i64) -> Result<(), PodError> {
+        // Positive values get odd codes, others get even codes
+        let code = match value > 0 {
+            true => value.unsigned_abs() * 2 - 1,
+            false => value.unsigned_abs().checked_mul(2).ok_or(PodError::OutOfRange)?,
+        };
+
+        self.write_ue(code)
+    }
+
+    /// Write a [`Pod`] type as little endian bytes at a byte boundary,
+    /// failing with [`PodError::OutOfRange`] if not at one
+    pub fn write_pod_le<T: Pod>(&mut self, value: &T) -> Result<(), PodError> {
+        let written = value.to_le_bytes(self.aligned_bytes()?)?;
+        self.position += written * 8;
+
+        Ok(())
+    }
+
+    /// Write a [`Pod`] type as big endian bytes at a byte boundary,
+    /// failing with [`PodError::OutOfRange`] if not at one
+    pub fn write_pod_be<T: Pod>(&mut self, value: &T) -> Result<(), PodError> {
+        let written = value.to_be_bytes(self.aligned_bytes()?)?;
+        self.position += written * 8;
+
+        Ok(())
+    }
+
+    // Get the bytes from the position on, which must be at a byte boundary
+    fn aligned_bytes(&mut self) -> Result<&mut [u8], PodError> {
+        if !self.is_aligned() {
+            return Err(PodError::OutOfRange);
+        }
+
+        Ok(&mut self.buffer[self.position / 8..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_reader() {
+        // Msb first
+        let buffer = [0b1011_0011, 0b0110_1111];
+        let mut reader = BitReader::new(&buffer, BitOrder::Msb);
+
+        assert_eq!(reader.read_bit(), Ok(true));
+        assert_eq!(reader.read_bits(3), Ok(0b011));
+        assert_eq!(reader.read_bits(6), Ok(0b00_1101));
+        assert_eq!(reader.read_signed(3), Ok(-3));
+        assert_eq!(reader.read_bits(4), Err(PodError::OutOfSpace));
+        assert_eq!(reader.position(), 13);
+        assert_eq!(reader.read_bits(3), Ok(0b111));
+        assert_eq!(reader.remaining(), 0);
+
+        // Lsb first
+        let mut reader = BitReader::new(&buffer, BitOrder::Lsb);
+
+        assert_eq!(reader.read_bits(4), Ok(0b0011));
+        assert_eq!(reader.read_bits(8), Ok(0b1111_1011));
+        assert!(!reader.is_aligned());
+        reader.align();
+        assert_eq!(reader.position(), 16);
+
+        // Pod at byte boundaries
+        let buffer = [0xf0, 0x12, 0x34];
+        let mut reader = BitReader::new(&buffer, BitOrder::Msb);
+
+        assert_eq!(reader.read_bits(4), Ok(0xf));
+        assert_eq!(reader.read_pod_be::<u16>(), Err(PodError::OutOfRange));
+        reader.align();
+        assert_eq!(reader.read_pod_be::<u16>(), Ok(0x1234));
+    }
+
+    #[test]
+    fn bit_writer() {
+        // Msb first
+        let mut buffer = [0xffu8; 2];
+        let mut writer = BitWriter::new(&mut buffer, BitOrder::Msb);
+
+        assert_eq!(writer.write_bit(true), Ok(()));
+        assert_eq!(writer.write_bits(0b011, 3), Ok(()));
+        assert_eq!(writer.write_bits(0b00_1101, 6), Ok(()));
+        assert_eq!(writer.write_signed(-3, 3), Ok(()));
+        assert_eq!(writer.write_bits(8, 3), Err(PodError::OutOfRange));
+        assert_eq!(writer.write_signed(4, 3), Err(PodError::OutOfRange));
+        assert_eq!(writer.bytes_written(), 2);
+        writer.align();
+        assert_eq!(writer.write_bit(true), Err(PodError::OutOfSpace));
+        assert_eq!(buffer, [0b1011_0011, 0b0110_1000]);
+
+        // Lsb first
+        let mut buffer = [0u8; 3];
+        let mut writer = BitWriter::new(&mut buffer, BitOrder::Lsb);
+
+        assert_eq!(writer.write_bits(0b0011, 4), Ok(()));
+        assert_eq!(writer.write_bits(0b1111_1011, 8), Ok(()));
+        assert_eq!(writer.write_pod_le(&1u8), Err(PodError::OutOfRange));
+        writer.align();
+        assert_eq!(writer.write_pod_le(&0x12u8), Ok(()));
+        assert_eq!(buffer, [0b1011_0011, 0b0000_1111, 0x12]);
+    }
+
+    #[test]
+    fn exp_golomb() {
+        // Roundtrip
+        let mut buffer = [0u8; 64];
+        let mut writer = BitWriter::new(&mut buffer, BitOrder::Msb);
+
+        for value in [0, 1, 2, 3, 7, 255, u64::MAX - 1] {
+            assert_eq!(writer.write_ue(value), Ok(()));
+        }
+        for value in [0, 1, -1, 2, -2, i64::MAX, -i64::MAX] {
+            assert_eq!(writer.write_se(value), Ok(()));
+        }
+        assert_eq!(writer.write_ue(u64::MAX), Err(PodError::OutOfRange));
+        assert_eq!(writer.write_se(i64::MIN), Err(PodError::OutOfRange));
+
+        let mut reader = BitReader::new(&buffer, BitOrder::Msb);
+
+        for value in [0, 1, 2, 3, 7, 255, u64::MAX - 1] {
+            assert_eq!(reader.read_ue(), Ok(value));
+        }
+        for value in [0, 1, -1, 2, -2, i64::MAX, -i64::MAX] {
+            assert_eq!(reader.read_se(), Ok(value));
+        }
+
+        // Roundtrip least significant bit first, with multi-bit codes
+        let mut buffer = [0u8; 64];
+        let mut writer = BitWriter::new(&mut buffer, BitOrder::Lsb);
+
+        for value in [0, 1, 2, 3, 4, 5, 6, 7, 100, 12345] {
+            assert_eq!(writer.write_ue(value), Ok(()));
+        }
+        for value in [-3, 17, -12345] {
+            assert_eq!(writer.write_se(value), Ok(()));
+        }
+        assert_eq!(writer.write_signed(-5, 6), Ok(()));
+
+        let mut reader = BitReader::new(&buffer, BitOrder::Lsb);
+
+        for value in [0, 1, 2, 3, 4, 5, 6, 7, 100, 12345] {
+            assert_eq!(reader.read_ue(), Ok(value));
+        }
+        for value in [-3, 17, -12345] {
+            assert_eq!(reader.read_se(), Ok(value));
+        }
+        assert_eq!(reader.read_signed(6), Ok(-5));
+
+        // Known codes
+        let buffer = [0b1010_0110, 0b0100_0000];
+        let mut reader = BitReader::new(&buffer, BitOrder::Msb);
+
+        assert_eq!(reader.read_ue(), Ok(0));
+        assert_eq!(reader.read_ue(), Ok(1));
+        assert_eq!(reader.read_ue(), Ok(2));
+        assert_eq!(reader.read_se(), Ok(2));
+
+        // Truncated codes don't move the reader
+        let buffer = [0b0000_0001];
+        let mut reader = BitReader::new(&buffer, BitOrder::Msb);
+
+        assert_eq!(reader.read_ue(), Err(PodError::OutOfSpace));
+        assert_eq!(reader.position(), 0);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::dyn_pod::DynPod;
+use crate::pod::PodError;
+
+/// A type that can be created from a byte array, possibly
+/// borrowing parts of it instead of copying them
+///
+/// Every [`DynPod`] type is also a `BorrowPod` that doesn't borrow,
+/// and `&'a [u8; N]` borrows `N` bytes. Derived structs can also hold
+/// `&'a [u8]` and `&'a str` fields whose length is given by a `count`,
+/// `len_prefix` or `until_eof` attribute.
+pub trait BorrowPod<'a>: Sized {
+    /// Create instance from little endian bytes at the start of
+    /// `buffer`, returning it and the number of bytes consumed
+    fn decode_borrowed_le(buffer: &'a [u8]) -> Result<(Self, usize), PodError>;
+
+    /// Create instance from big endian bytes at the start of
+    /// `buffer`, returning it and the number of bytes consumed
+    fn decode_borrowed_be(buffer: &'a [u8]) -> Result<(Self, usize), PodError>;
+}
+
+impl<'a, T: DynPod> BorrowPod<'a> for T {
+    #[inline]
+    fn decode_borrowed_le(buffer: &'a [u8]) -> Result<(Self, usize), PodError> {
+        T::decode_le(buffer)
+    }
+
+    #[inline]
+    fn decode_borrowed_be(buffer: &'a [u8]) -> Result<(Self, usize), PodError> {
+        T::decode_be(buffer)
+    }
+}
+
+impl<'a, const N: usize> BorrowPod<'a> for &'a [u8; N] {
+    #[inline]
+    fn decode_borrowed_le(buffer: &'a [u8]) -> Result<(Self, usize), PodError> {
+        match buffer.first_chunk::<N>() {
+            Some(bytes) => Ok((bytes, N)),
+            None => Err(PodError::OutOfSpace),
+        }
+    }
+
+    #[inline]
+    fn decode_borrowed_be(buffer: &'a [u8]) -> Result<(Self, usize), PodError> {
+        Self::decode_borrowed_le(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrow_pod() {
+        let buffer_read = [0x01u8, 0x02u8, 0x03u8];
+
+        assert_eq!(<u16 as BorrowPod>::decode_borrowed_be(&buffer_read), Ok((0x0102, 2)));
+        assert_eq!(<&[u8; 2] as BorrowPod>::decode_borrowed_le(&buffer_read[1..]), Ok((&[0x02, 0x03], 2)));
+        assert_eq!(<&[u8; 4] as BorrowPod>::decode_borrowed_le(&buffer_read), Err(PodError::OutOfSpace));
+
+        // The borrowed bytes point into the buffer
+        let (bytes, _) = <&[u8; 3] as BorrowPod>::decode_borrowed_be(&buffer_read).unwrap();
+        assert!(std::ptr::eq(bytes.as_ptr(), buffer_read.as_ptr()));
+    }
+}
@@ -0,0 +1,404 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use crate::encoded_int::NulPadded;
+use crate::fixed_str::{text_len, StrPadding};
+use crate::pod::{Pod, PodError};
+use crate::zeroable::Zeroable;
+
+/// A single byte character set
+pub trait CodePage {
+    /// Map a byte to a character, if it's defined
+    fn decode(byte: u8) -> Option<char>;
+
+    /// Map a character to a byte, if it can be represented
+    fn encode(c: char) -> Option<u8>;
+}
+
+/// IBM code page 437, the character set of the original IBM PC
+/// and of DOS and FAT file names. Bytes below `0x80` are ASCII.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Cp437;
+
+/// ISO 8859-1, where every byte is the Unicode code point of the same value
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Latin1;
+
+/// Windows code page 1252, Latin-1 with printable characters
+/// in place of most of the C1 controls
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Windows1252;
+
+// Characters of bytes 0x80 to 0xff in code page 437
+const CP437_HIGH: [Option<char>; 128] = [
+    Some('\u{00c7}'), Some('\u{00fc}'), Some('\u{00e9}'), Some('\u{00e2}'), Some('\u{00e4}'), Some('\u{00e0}'), Some('\u{00e5}'), Some('\u{00e7}'),
+    Some('\u{00ea}'), Some('\u{00eb}'), Some('\u{00e8}'), Some('\u{00ef}'), Some('\u{00ee}'), Some('\u{00ec}'), Some('\u{00c4}'), Some('\u{00c5}'),
+    Some('\u{00c9}'), Some('\u{00e6}'), Some('\u{00c6}'), Some('\u{00f4}'), Some('\u{00f6}'), Some('\u{00f2}'), Some('\u{00fb}'), Some('\u{00f9}'),
+    Some('\u{00ff}'), Some('\u{00d6}'), Some('\u{00dc}'), Some('\u{00a2}'), Some('\u{00a3}'), Some('\u{00a5}'), Some('\u{20a7}'), Some('\u{0192}'),
+    Some('\u{00e1}'), Some('\u{00ed}'), Some('\u{00f3}'), Some('\u{00fa}'), Some('\u{00f1}'), Some('\u{00d1}'), Some('\u{00aa}'), Some('\u{00ba}'),
+    Some('\u{00bf}'), Some('\u{2310}'), Some('\u{00ac}'), Some('\u{00bd}'), Some('\u{00bc}'), Some('\u{00a1}'), Some('\u{00ab}'), Some('\u{00bb}'),
+    Some('\u{2591}'), Some('\u{2592}'), Some('\u{2593}'), Some('\u{2502}'), Some('\u{2524}'), Some('\u{2561}'), Some('\u{2562}'), Some('\u{2556}'),
+    Some('\u{2555}'), Some('\u{2563}'), Some('\u{2551}'), Some('\u{2557}'), Some('\u{255d}'), Some('\u{255c}'), Some('\u{255b}'), Some('\u{2510}'),
+    Some('\u{2514}'), Some('\u{2534}'), Some('\u{252c}'), Some('\u{251c}'), Some('\u{2500}'), Some('\u{253c}'), Some('\u{255e}'), Some('\u{255f}'),
+    Some('\u{255a}'), Some('\u{2554}'), Some('\u{2569}'), Some('\u{2566}'), Some('\u{2560}'), Some('\u{2550}'), Some('\u{256c}'), Some('\u{2567}'),
+    Some('\u{2568}'), Some('\u{2564}'), Some('\u{2565}'), Some('\u{2559}'), Some('\u{2558}'), Some('\u{2552}'), Some('\u{2553}'), Some('\u{256b}'),
+    Some('\u{256a}'), Some('\u{2518}'), Some('\u{250c}'), Some('\u{2588}'), Some('\u{2584}'), Some('\u{258c}'), Some('\u{2590}'), Some('\u{2580}'),
+    Some('\u{03b1}'), Some('\u{00df}'), Some('\u{0393}'), Some('\u{03c0}'), Some('\u{03a3}'), Some('\u{03c3}'), Some('\u{00b5}'), Some('\u{03c4}'),
+    Some('\u{03a6}'), Some('\u{0398}'), Some('\u{03a9}'), Some('\u{03b4}'), Some('\u{221e}'), Some('\u{03c6}'), Some('\u{03b5}'), Some('\u{2229}'),
+    Some('\u{2261}'), Some('\u{00b1}'), Some('\u{2265}'), Some('\u{2264}'), Some('\u{2320}'), Some('\u{2321}'), Some('\u{00f7}'), Some('\u{2248}'),
+    Some('\u{00b0}'), Some('\u{2219}'), Some('\u{00b7}'), Some('\u{221a}'), Some('\u{207f}'), Some('\u{00b2}'), Some('\u{25a0}'), Some('\u{00a0}'),
+];
+
+// Characters of bytes 0x80 to 0x9f in Windows code page 1252,
+// the other bytes are the same as Latin-1
+const WINDOWS_1252_C1: [Option<char>; 32] = [
+    Some('\u{20ac}'), None, Some('\u{201a}'), Some('\u{0192}'), Some('\u{201e}'), Some('\u{2026}'), Some('\u{2020}'), Some('\u{2021}'),
+    Some('\u{02c6}'), Some('\u{2030}'), Some('\u{0160}'), Some('\u{2039}'), Some('\u{0152}'), None, Some('\u{017d}'), None,
+    None, Some('\u{2018}'), Some('\u{2019}'), Some('\u{201c}'), Some('\u{201d}'), Some('\u{2022}'), Some('\u{2013}'), Some('\u{2014}'),
+    Some('\u{02dc}'), Some('\u{2122}'), Some('\u{0161}'), Some('\u{203a}'), Some('\u{0153}'), None, Some('\u{017e}'), Some('\u{0178}'),
+];
+
+// Map a character to a byte using a table of bytes from 0x80
+fn encode_table(c: char, table: &[Option<char>]) -> Option<u8> {
+    table.iter().position(|t| *t == Some(c)).map(|p| p as u8 + 0x80)
+}
+
+impl CodePage for Cp437 {
+    #[inline]
+    fn decode(byte: u8) -> Option<char> {
+        match byte {
+            0x00..=0x7f => Some(byte as char),
+            _ => CP437_HIGH[(byte - 0x80) as usize],
+        }
+    }
+
+    #[inline]
+    fn encode(c: char) -> Option<u8> {
+        if c.is_ascii() {
+            return Some(c as u8);
+        }
+
+        encode_table(c, &CP437_HIGH)
+    }
+}
+
+impl CodePage for Latin1 {
+    #[inline]
+    fn decode(byte: u8) -> Option<char> {
+        Some(byte as char)
+    }
+
+    #[inline]
+    fn encode(c: char) -> Option<u8> {
+        u8::try_from(c).ok()
+    }
+}
+
+impl CodePage for Windows1252 {
+    #[inline]
+    fn decode(byte: u8) -> Option<char> {
+        match byte {
+            0x80..=0x9f => WINDOWS_1252_C1[(byte - 0x80) as usize],
+            _ => Some(byte as char),
+        }
+    }
+
+    #[inline]
+    fn encode(c: char) -> Option<u8> {
+        match u8::try_from(c) {
+            Ok(byte) if !(0x80..=0x9f).contains(&byte) => Some(byte),
+            _ => encode_table(c, &WINDOWS_1252_C1),
+        }
+    }
+}
+
+/// A string stored in exactly `N` bytes of the code page `CP`,
+/// padded according to `P`
+///
+/// Decoding fails with [`PodError::OutOfRange`] on bytes the code page
+/// doesn't define, and encoding on characters it can't represent.
+pub struct CodePageStr<const N: usize, CP: CodePage, P: StrPadding = NulPadded> {
+    bytes: [u8; N],
+    len: usize,
+    marker: PhantomData<(CP, P)>,
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> CodePageStr<N, CP, P> {
+    /// Longest text in bytes that fits
+    pub const CAPACITY: usize = if P::TERMINATED { N.saturating_sub(1) } else { N };
+
+    /// Create from text, failing with [`PodError::OutOfRange`] if it's
+    /// longer than [`CAPACITY`](Self::CAPACITY) characters, has
+    /// characters the code page can't represent, or wouldn't decode to
+    /// the same text, because it contains a NUL byte when NUL padded or
+    /// ends with a space when space padded
+    pub fn new(text: &str) -> Result<Self, PodError> {
+        let mut bytes = [P::BYTE; N];
+
+        let mut len = 0;
+        for c in text.chars() {
+            if len == Self::CAPACITY {
+                return Err(PodError::OutOfRange);
+            }
+
+            bytes[len] = CP::encode(c).ok_or(PodError::OutOfRange)?;
+            len += 1;
+        }
+
+        let ambiguous = match P::BYTE {
+            0 => bytes[..len].contains(&0),
+            _ => bytes[..len].last() == Some(&P::BYTE),
+        };
+        if ambiguous {
+            return Err(PodError::OutOfRange);
+        }
+
+        Ok(Self { bytes, len, marker: PhantomData })
+    }
+
+    /// Get the encoded bytes of the text, without padding
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Iterate over the characters of the text
+    #[inline]
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        // Contents are validated on construction
+        self.as_bytes().iter().map(|b| CP::decode(*b).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Length of the text in bytes
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the text is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> Clone for CodePageStr<N, CP, P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> Copy for CodePageStr<N, CP, P> { }
+
+impl<const N: usize, CP: CodePage, P: StrPadding> PartialEq for CodePageStr<N, CP, P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> Eq for CodePageStr<N, CP, P> { }
+
+impl<const N: usize, CP: CodePage, P: StrPadding> PartialEq<str> for CodePageStr<N, CP, P> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> PartialEq<&str> for CodePageStr<N, CP, P> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> Hash for CodePageStr<N, CP, P> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state)
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> Default for CodePageStr<N, CP, P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> fmt::Debug for CodePageStr<N, CP, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&String::from(*self), f)
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> fmt::Display for CodePageStr<N, CP, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.chars() {
+            fmt::Write::write_char(f, c)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> TryFrom<&str> for CodePageStr<N, CP, P> {
+    type Error = PodError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> TryFrom<String> for CodePageStr<N, CP, P> {
+    type Error = PodError;
+
+    #[inline]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> From<CodePageStr<N, CP, P>> for String {
+    #[inline]
+    fn from(value: CodePageStr<N, CP, P>) -> Self {
+        value.chars().collect()
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> Zeroable for CodePageStr<N, CP, P> {
+    #[inline]
+    fn zeroed() -> Self {
+        Self { bytes: [P::BYTE; N], len: 0, marker: PhantomData }
+    }
+}
+
+impl<const N: usize, CP: CodePage, P: StrPadding> Pod for CodePageStr<N, CP, P> {
+    const SIZE: usize = N;
+
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&buffer[..N]);
+
+        let len = text_len::<P, u8>(&bytes, P::BYTE)?;
+        if bytes[..len].iter().any(|b| CP::decode(*b).is_none()) {
+            return Err(PodError::OutOfRange);
+        }
+
+        Ok(Self { bytes, len, marker: PhantomData })
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Self::from_le_bytes(buffer)
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        buffer[..N].copy_from_slice(&self.bytes);
+
+        Ok(N)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        self.to_le_bytes(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoded_int::SpacePadded;
+    use crate::fixed_str::NulTerminated;
+
+    use super::*;
+
+    #[test]
+    fn code_pages() {
+        assert_eq!(Cp437::decode(b'A'), Some('A'));
+        assert_eq!(Cp437::decode(0x81), Some('\u{fc}'));
+        assert_eq!(Cp437::decode(0xc9), Some('\u{2554}'));
+        assert_eq!(Cp437::encode('\u{2554}'), Some(0xc9));
+        assert_eq!(Cp437::encode('\u{20ac}'), None);
+        assert_eq!(Latin1::decode(0xe9), Some('\u{e9}'));
+        assert_eq!(Latin1::encode('\u{20ac}'), None);
+        assert_eq!(Windows1252::decode(0x80), Some('\u{20ac}'));
+        assert_eq!(Windows1252::decode(0x81), None);
+        assert_eq!(Windows1252::encode('\u{20ac}'), Some(0x80));
+        assert_eq!(Windows1252::encode('\u{e9}'), Some(0xe9));
+
+        // Every defined byte maps back to itself
+        for byte in 0..=255u8 {
+            if let Some(c) = Cp437::decode(byte) {
+                assert_eq!(Cp437::encode(c), Some(byte));
+            }
+            if let Some(c) = Windows1252::decode(byte) {
+                assert_eq!(Windows1252::encode(c), Some(byte));
+            }
+        }
+    }
+
+    #[test]
+    fn pod_code_page_str() {
+        // Read success
+        let buffer_read = [b'C', b'A', b'F', 0x90, b' ', b' '];
+
+        assert_eq!(<CodePageStr<6, Cp437, SpacePadded> as Pod>::from_le_bytes(&buffer_read).unwrap(), "CAF\u{c9}");
+        assert_eq!(<CodePageStr<6, Latin1> as Pod>::from_be_bytes(&buffer_read).unwrap(), "CAF\u{90}  ");
+
+        // Read fail
+        assert_eq!(<CodePageStr<2, Windows1252> as Pod>::from_le_bytes(&[b'a', 0x8d]), Err(PodError::OutOfRange));
+        assert_eq!(<CodePageStr<2, Latin1> as Pod>::from_le_bytes(b"a"), Err(PodError::OutOfSpace));
+
+        // Write success
+        let mut buffer_write = [0xffu8; 4];
+
+        assert_eq!(CodePageStr::<4, Windows1252>::new("\u{20ac}5").unwrap().to_le_bytes(&mut buffer_write), Ok(4));
+        assert_eq!(buffer_write, [0x80, b'5', 0, 0]);
+
+        // Write fail
+        assert_eq!(CodePageStr::<4, Latin1>::new("\u{20ac}"), Err(PodError::OutOfRange));
+        assert_eq!(CodePageStr::<2, Latin1>::new("abc"), Err(PodError::OutOfRange));
+        assert_eq!(
+            CodePageStr::<4, Latin1>::new("a").unwrap().to_le_bytes(&mut buffer_write[1..]),
+            Err(PodError::OutOfSpace)
+        );
+    }
+
+    #[test]
+    fn code_page_str_conversions() {
+        let text = CodePageStr::<8, Cp437>::try_from(String::from("\u{2591}ok")).unwrap();
+
+        assert_eq!(text.len(), 3);
+        assert_eq!(text.as_bytes(), &[0xb0, b'o', b'k']);
+        assert_eq!(String::from(text), "\u{2591}ok");
+        assert_eq!(format!("{:?}", text), "\"\u{2591}ok\"");
+    }
+
+    #[test]
+    fn code_page_str_ambiguous_text() {
+        // Text that would decode differently
+        assert_eq!(CodePageStr::<4, Latin1>::new("a\0b"), Err(PodError::OutOfRange));
+        assert_eq!(CodePageStr::<4, Latin1, NulTerminated>::new("a\0"), Err(PodError::OutOfRange));
+        assert_eq!(CodePageStr::<4, Latin1, SpacePadded>::new("ab "), Err(PodError::OutOfRange));
+
+        // Spaces before the end and NUL in space padded text roundtrip
+        let mut buffer = [0u8; 4];
+        for text in [" a b", "a\0b"] {
+            CodePageStr::<4, Latin1, SpacePadded>::new(text).unwrap().to_le_bytes(&mut buffer).unwrap();
+            assert_eq!(<CodePageStr<4, Latin1, SpacePadded> as Pod>::from_le_bytes(&buffer).unwrap(), text);
+        }
+    }
+}
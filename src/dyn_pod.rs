@@ -0,0 +1,170 @@
+use crate::endian::{Be, Le};
+use crate::pod::{Pod, PodError};
+use crate::varint::{Uleb128, Vlq};
+
+/// A type that can be created from and turned to a byte array
+/// whose size depends on the value, such as a length prefixed
+/// array or a variable length integer
+///
+/// Every [`Pod`] type is also a `DynPod` with a constant
+/// encoded length of [`Pod::SIZE`].
+pub trait DynPod: Sized {
+    /// Size of the byte representation of `self`
+    fn encoded_len(&self) -> usize;
+
+    /// Create instance from little endian bytes at the start of
+    /// `buffer`, returning it and the number of bytes consumed
+    fn decode_le(buffer: &[u8]) -> Result<(Self, usize), PodError>;
+
+    /// Create instance from big endian bytes at the start of
+    /// `buffer`, returning it and the number of bytes consumed
+    fn decode_be(buffer: &[u8]) -> Result<(Self, usize), PodError>;
+
+    /// Write little endian bytes of instance to the start of
+    /// `buffer`, returning the number of bytes written
+    fn encode_le(&self, buffer: &mut [u8]) -> Result<usize, PodError>;
+
+    /// Write big endian bytes of instance to the start of
+    /// `buffer`, returning the number of bytes written
+    fn encode_be(&self, buffer: &mut [u8]) -> Result<usize, PodError>;
+}
+
+impl<T: Pod> DynPod for T {
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        T::SIZE
+    }
+
+    #[inline]
+    fn decode_le(buffer: &[u8]) -> Result<(Self, usize), PodError> {
+        Ok((T::from_le_bytes(buffer)?, T::SIZE))
+    }
+
+    #[inline]
+    fn decode_be(buffer: &[u8]) -> Result<(Self, usize), PodError> {
+        Ok((T::from_be_bytes(buffer)?, T::SIZE))
+    }
+
+    #[inline]
+    fn encode_le(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        self.to_le_bytes(buffer)
+    }
+
+    #[inline]
+    fn encode_be(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        self.to_be_bytes(buffer)
+    }
+}
+
+/// An integer that holds the number of elements of a collection,
+/// as used by `count` and `len_prefix` fields of a derived [`DynPod`]
+pub trait LengthInt: Copy {
+    /// Convert to a length, failing with [`PodError::OutOfRange`]
+    /// if it doesn't fit in a `usize`
+    fn to_length(self) -> Result<usize, PodError>;
+
+    /// Convert from a length, failing with [`PodError::OutOfRange`]
+    /// if it doesn't fit in the type
+    fn from_length(length: usize) -> Result<Self, PodError>;
+}
+
+// Implements LengthInt for the unsigned native integer types
+macro_rules! impl_length_int {
+    ($($ty:ty),*) => {
+        $(
+            impl LengthInt for $ty {
+                #[inline]
+                fn to_length(self) -> Result<usize, PodError> {
+                    usize::try_from(self).map_err(|_| PodError::OutOfRange)
+                }
+
+                #[inline]
+                fn from_length(length: usize) -> Result<Self, PodError> {
+                    <$ty>::try_from(length).map_err(|_| PodError::OutOfRange)
+                }
+            }
+        )*
+    };
+}
+
+impl_length_int!(u8, u16, u32, u64);
+
+impl<T: LengthInt> LengthInt for Be<T> {
+    #[inline]
+    fn to_length(self) -> Result<usize, PodError> {
+        self.get().to_length()
+    }
+
+    #[inline]
+    fn from_length(length: usize) -> Result<Self, PodError> {
+        Ok(Be::new(T::from_length(length)?))
+    }
+}
+
+impl<T: LengthInt> LengthInt for Le<T> {
+    #[inline]
+    fn to_length(self) -> Result<usize, PodError> {
+        self.get().to_length()
+    }
+
+    #[inline]
+    fn from_length(length: usize) -> Result<Self, PodError> {
+        Ok(Le::new(T::from_length(length)?))
+    }
+}
+
+impl LengthInt for Uleb128 {
+    #[inline]
+    fn to_length(self) -> Result<usize, PodError> {
+        self.get().to_length()
+    }
+
+    #[inline]
+    fn from_length(length: usize) -> Result<Self, PodError> {
+        Ok(Uleb128::new(u64::from_length(length)?))
+    }
+}
+
+impl LengthInt for Vlq {
+    #[inline]
+    fn to_length(self) -> Result<usize, PodError> {
+        self.get().to_length()
+    }
+
+    #[inline]
+    fn from_length(length: usize) -> Result<Self, PodError> {
+        Vlq::new(u32::from_length(length)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dyn_pod_blanket() {
+        // Read
+        let buffer_read = [0x01u8, 0x02u8, 0x03u8];
+
+        assert_eq!(<u16 as DynPod>::decode_le(&buffer_read), Ok((0x0201, 2)));
+        assert_eq!(<u16 as DynPod>::decode_be(&buffer_read), Ok((0x0102, 2)));
+        assert_eq!(<u32 as DynPod>::decode_le(&buffer_read), Err(PodError::OutOfSpace));
+
+        // Write
+        let mut buffer_write = [0u8; 2];
+
+        assert_eq!(DynPod::encoded_len(&0x0102u16), 2);
+        assert_eq!(DynPod::encode_be(&0x0102u16, &mut buffer_write), Ok(2));
+        assert_eq!(buffer_write, [0x01, 0x02]);
+        assert_eq!(DynPod::encode_le(&0u32, &mut buffer_write), Err(PodError::OutOfSpace));
+    }
+
+    #[test]
+    fn length_int() {
+        assert_eq!(300u16.to_length(), Ok(300));
+        assert_eq!(u8::from_length(256), Err(PodError::OutOfRange));
+        assert_eq!(Be::<u32>::from_length(7).map(Be::get), Ok(7));
+        assert_eq!(Uleb128::from_length(1 << 20).map(Uleb128::get), Ok(1 << 20));
+        assert_eq!(Vlq::from_length(1 << 28), Err(PodError::OutOfRange));
+    }
+}
@@ -0,0 +1,486 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::pod::{Pod, PodError};
+use crate::zeroable::Zeroable;
+
+/// How ASCII encoded numbers are padded to their fixed width
+pub trait Padding {
+    /// Byte used for padding
+    const BYTE: u8;
+
+    /// Whether padding goes before the digits
+    const LEADING: bool;
+}
+
+/// Digits right aligned with leading `'0'` bytes, e.g. `"0000644"`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ZeroPadded;
+
+/// Digits left aligned with trailing spaces, e.g. `"644    "`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SpacePadded;
+
+/// Digits left aligned with trailing NUL bytes, e.g. `"644\0\0\0\0"`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NulPadded;
+
+impl Padding for ZeroPadded {
+    const BYTE: u8 = b'0';
+    const LEADING: bool = true;
+}
+
+impl Padding for SpacePadded {
+    const BYTE: u8 = b' ';
+    const LEADING: bool = false;
+}
+
+impl Padding for NulPadded {
+    const BYTE: u8 = 0;
+    const LEADING: bool = false;
+}
+
+// Largest value that fits in `digits` digits of `radix`
+fn max_value(radix: u64, digits: usize) -> u64 {
+    let mut max = 1u64;
+    for _ in 0..digits {
+        max = match max.checked_mul(radix) {
+            Some(m) => m,
+            None => return u64::MAX,
+        };
+    }
+
+    max - 1
+}
+
+// Parse ASCII digits, ignoring leading spaces and NUL bytes
+// and trailing spaces and NUL bytes
+fn parse_ascii(bytes: &[u8], radix: u32) -> Result<u64, PodError> {
+    let is_padding = |b: &u8| *b == b' ' || *b == 0;
+
+    let start = bytes.iter().position(|b| !is_padding(b)).unwrap_or(bytes.len());
+    let end = bytes.iter().rposition(|b| !is_padding(b)).map_or(start, |e| e + 1);
+
+    let mut value = 0u64;
+    for byte in &bytes[start..end] {
+        let digit = match (*byte as char).to_digit(radix) {
+            Some(d) => d as u64,
+            None => return Err(PodError::OutOfRange),
+        };
+
+        value = match value.checked_mul(radix as u64).and_then(|v| v.checked_add(digit)) {
+            Some(v) => v,
+            None => return Err(PodError::OutOfRange),
+        };
+    }
+
+    Ok(value)
+}
+
+// Write ASCII digits padded to exactly `buffer.len()` bytes
+fn write_ascii<P: Padding>(value: u64, radix: u32, buffer: &mut [u8]) -> Result<usize, PodError> {
+    // Digits in reverse order
+    let mut digits: Vec<u8> = Vec::new();
+    let mut rest = value;
+    loop {
+        let digit = (rest % radix as u64) as u32;
+        digits.push(char::from_digit(digit, radix).unwrap_or('0').to_ascii_uppercase() as u8);
+        rest /= radix as u64;
+
+        if rest == 0 {
+            break;
+        }
+    }
+
+    if digits.len() > buffer.len() {
+        return Err(PodError::OutOfRange);
+    }
+
+    let padding = buffer.len() - digits.len();
+    let (digit_range, padding_range) = if P::LEADING {
+        (padding..buffer.len(), 0..padding)
+    } else {
+        (0..digits.len(), digits.len()..buffer.len())
+    };
+
+    for (byte, digit) in buffer[digit_range].iter_mut().zip(digits.iter().rev()) {
+        *byte = *digit;
+    }
+    for byte in &mut buffer[padding_range] {
+        *byte = P::BYTE;
+    }
+
+    Ok(buffer.len())
+}
+
+// Implements a fixed width ASCII encoded number type
+macro_rules! impl_ascii {
+    ($(#[$meta:meta])* $ty:ident, $radix:expr) => {
+        $(#[$meta])*
+        pub struct $ty<const N: usize, P: Padding = ZeroPadded> {
+            value: u64,
+            padding: PhantomData<P>,
+        }
+
+        impl<const N: usize, P: Padding> $ty<N, P> {
+            /// Create from a value, failing with [`PodError::OutOfRange`]
+            /// if it has more than `N` digits
+            #[inline]
+            pub fn new(value: u64) -> Result<Self, PodError> {
+                if value > Self::max() {
+                    return Err(PodError::OutOfRange);
+                }
+
+                Ok(Self { value, padding: PhantomData })
+            }
+
+            /// Get the value
+            #[inline]
+            pub const fn get(self) -> u64 {
+                self.value
+            }
+
+            /// Largest value that fits in `N` digits
+            #[inline]
+            pub fn max() -> u64 {
+                max_value($radix, N)
+            }
+        }
+
+        impl<const N: usize, P: Padding> Clone for $ty<N, P> {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<const N: usize, P: Padding> Copy for $ty<N, P> { }
+
+        impl<const N: usize, P: Padding> PartialEq for $ty<N, P> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.value == other.value
+            }
+        }
+
+        impl<const N: usize, P: Padding> Eq for $ty<N, P> { }
+
+        impl<const N: usize, P: Padding> fmt::Debug for $ty<N, P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.value, f)
+            }
+        }
+
+        impl<const N: usize, P: Padding> fmt::Display for $ty<N, P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.value, f)
+            }
+        }
+
+        impl<const N: usize, P: Padding> Zeroable for $ty<N, P> {
+            #[inline]
+            fn zeroed() -> Self {
+                Self { value: 0, padding: PhantomData }
+            }
+        }
+
+        impl<const N: usize, P: Padding> Pod for $ty<N, P> {
+            const SIZE: usize = N;
+
+            #[inline]
+            fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+                if buffer.len() < Self::SIZE {
+                    return Err(PodError::OutOfSpace);
+                }
+
+                Ok(Self { value: parse_ascii(&buffer[..N], $radix)?, padding: PhantomData })
+            }
+
+            #[inline]
+            fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+                Self::from_le_bytes(buffer)
+            }
+
+            #[inline]
+            fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+                if buffer.len() < Self::SIZE {
+                    return Err(PodError::OutOfSpace);
+                }
+
+                write_ascii::<P>(self.value, $radix, &mut buffer[..N])
+            }
+
+            #[inline]
+            fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+                self.to_le_bytes(buffer)
+            }
+        }
+
+        impl<const N: usize, P: Padding> TryFrom<u64> for $ty<N, P> {
+            type Error = PodError;
+
+            #[inline]
+            fn try_from(value: u64) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl<const N: usize, P: Padding> From<$ty<N, P>> for u64 {
+            #[inline]
+            fn from(value: $ty<N, P>) -> Self {
+                value.value
+            }
+        }
+    };
+}
+
+impl_ascii!(
+    /// An `N` byte ASCII octal number, as used in TAR headers
+    ///
+    /// Decoding ignores leading and trailing spaces and NUL bytes, so
+    /// both `"0000644\0"` and `"644 "` are accepted. Encoding always
+    /// writes exactly `N` bytes padded according to `P`.
+    AsciiOctal, 8
+);
+
+impl_ascii!(
+    /// An `N` byte ASCII decimal number, as used in `ar` headers
+    ///
+    /// Decoding ignores leading and trailing spaces and NUL bytes.
+    /// Encoding always writes exactly `N` bytes padded according to `P`.
+    AsciiDecimal, 10
+);
+
+impl_ascii!(
+    /// An `N` byte ASCII hexadecimal number, as used in `cpio` headers
+    ///
+    /// Decoding accepts either case and ignores leading and trailing
+    /// spaces and NUL bytes. Encoding writes upper case digits, always
+    /// exactly `N` bytes padded according to `P`.
+    AsciiHex, 16
+);
+
+/// An `N` byte packed binary coded decimal number
+///
+/// Each byte holds two decimal digits, the high nibble first.
+/// With `from_be_bytes`/`to_be_bytes` the most significant byte
+/// comes first, with `from_le_bytes`/`to_le_bytes` it comes last.
+/// Nibbles above 9 fail to decode with [`PodError::OutOfRange`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Bcd<const N: usize>(u64);
+
+impl<const N: usize> Bcd<N> {
+    /// Create from a value, failing with [`PodError::OutOfRange`]
+    /// if it has more than `2 * N` digits
+    #[inline]
+    pub fn new(value: u64) -> Result<Self, PodError> {
+        if value > Self::max() {
+            return Err(PodError::OutOfRange);
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Get the value
+    #[inline]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Largest value that fits in `2 * N` digits
+    #[inline]
+    pub fn max() -> u64 {
+        max_value(10, N * 2)
+    }
+
+    // Decode bytes ordered from most to least significant
+    fn decode<'a>(bytes: impl Iterator<Item = &'a u8>) -> Result<Self, PodError> {
+        let mut value = 0u64;
+        for byte in bytes {
+            for digit in [byte >> 4, byte & 0x0f] {
+                if digit > 9 {
+                    return Err(PodError::OutOfRange);
+                }
+
+                value = match value.checked_mul(10).and_then(|v| v.checked_add(digit as u64)) {
+                    Some(v) => v,
+                    None => return Err(PodError::OutOfRange),
+                };
+            }
+        }
+
+        Ok(Self(value))
+    }
+
+    // Encode bytes ordered from least to most significant
+    fn encode<'a>(&self, bytes: impl Iterator<Item = &'a mut u8>) -> Result<usize, PodError> {
+        if self.0 > Self::max() {
+            return Err(PodError::OutOfRange);
+        }
+
+        let mut rest = self.0;
+        for byte in bytes {
+            let low = (rest % 10) as u8;
+            let high = ((rest / 10) % 10) as u8;
+            *byte = (high << 4) | low;
+            rest /= 100;
+        }
+
+        Ok(N)
+    }
+}
+
+impl<const N: usize> fmt::Debug for Bcd<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize> fmt::Display for Bcd<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize> Zeroable for Bcd<N> {
+    #[inline]
+    fn zeroed() -> Self {
+        Self(0)
+    }
+}
+
+impl<const N: usize> Pod for Bcd<N> {
+    const SIZE: usize = N;
+
+    #[inline]
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        Self::decode(buffer[..N].iter().rev())
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        Self::decode(buffer[..N].iter())
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        self.encode(buffer[..N].iter_mut())
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        self.encode(buffer[..N].iter_mut().rev())
+    }
+}
+
+impl<const N: usize> TryFrom<u64> for Bcd<N> {
+    type Error = PodError;
+
+    #[inline]
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const N: usize> From<Bcd<N>> for u64 {
+    #[inline]
+    fn from(value: Bcd<N>) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pod_ascii_octal() {
+        // Read success
+        assert_eq!(<AsciiOctal<8> as Pod>::from_le_bytes(b"0000644\0").map(u64::from), Ok(0o644));
+        assert_eq!(<AsciiOctal<8> as Pod>::from_be_bytes(b"   644 \0").map(u64::from), Ok(0o644));
+        assert_eq!(<AsciiOctal<8> as Pod>::from_le_bytes(&[0u8; 8]).map(u64::from), Ok(0));
+
+        // Read fail
+        assert_eq!(<AsciiOctal<8> as Pod>::from_le_bytes(b"0000648\0"), Err(PodError::OutOfRange));
+        assert_eq!(<AsciiOctal<8> as Pod>::from_le_bytes(b"00 644\0\0"), Err(PodError::OutOfRange));
+        assert_eq!(<AsciiOctal<8> as Pod>::from_le_bytes(b"0000644"), Err(PodError::OutOfSpace));
+
+        // Write success
+        let mut buffer_write = [0xffu8; 8];
+
+        assert_eq!(AsciiOctal::<8>::new(0o644).unwrap().to_le_bytes(&mut buffer_write), Ok(8));
+        assert_eq!(&buffer_write, b"00000644");
+        assert_eq!(AsciiOctal::<8, NulPadded>::new(0o644).unwrap().to_be_bytes(&mut buffer_write), Ok(8));
+        assert_eq!(&buffer_write, b"644\0\0\0\0\0");
+
+        // Write fail
+        assert_eq!(AsciiOctal::<2>::new(0o100), Err(PodError::OutOfRange));
+        assert_eq!(
+            AsciiOctal::<8>::new(1).unwrap().to_le_bytes(&mut buffer_write[1..]),
+            Err(PodError::OutOfSpace)
+        );
+    }
+
+    #[test]
+    fn pod_ascii_decimal_and_hex() {
+        let mut buffer_write = [0u8; 10];
+
+        assert_eq!(AsciiDecimal::<10, SpacePadded>::new(1234).unwrap().to_le_bytes(&mut buffer_write), Ok(10));
+        assert_eq!(&buffer_write, b"1234      ");
+        assert_eq!(<AsciiDecimal<10> as Pod>::from_le_bytes(&buffer_write).map(u64::from), Ok(1234));
+        assert_eq!(<AsciiDecimal<3> as Pod>::from_le_bytes(b"1a3"), Err(PodError::OutOfRange));
+        assert_eq!(AsciiDecimal::<3>::max(), 999);
+        assert_eq!(AsciiDecimal::<30>::max(), u64::MAX);
+        assert_eq!(<AsciiDecimal<21> as Pod>::from_le_bytes(b"999999999999999999999"), Err(PodError::OutOfRange));
+
+        let mut buffer_write = [0u8; 8];
+
+        assert_eq!(AsciiHex::<8>::try_from(0xbeef).unwrap().to_le_bytes(&mut buffer_write), Ok(8));
+        assert_eq!(&buffer_write, b"0000BEEF");
+        assert_eq!(<AsciiHex<8> as Pod>::from_le_bytes(b"0000beef").map(u64::from), Ok(0xbeef));
+        assert_eq!(AsciiHex::<2>::try_from(0x100), Err(PodError::OutOfRange));
+    }
+
+    #[test]
+    fn pod_bcd() {
+        // Read success
+        let buffer_read = [0x12u8, 0x34u8];
+
+        assert_eq!(<Bcd<2> as Pod>::from_be_bytes(&buffer_read).map(u64::from), Ok(1234));
+        assert_eq!(<Bcd<2> as Pod>::from_le_bytes(&buffer_read).map(u64::from), Ok(3412));
+        assert_eq!(<Bcd<1> as Pod>::from_le_bytes(&[0x59]).map(u64::from), Ok(59));
+
+        // Read fail
+        assert_eq!(<Bcd<1> as Pod>::from_le_bytes(&[0x5a]), Err(PodError::OutOfRange));
+        assert_eq!(<Bcd<1> as Pod>::from_le_bytes(&[0xa5]), Err(PodError::OutOfRange));
+        assert_eq!(<Bcd<2> as Pod>::from_le_bytes(&[0x12]), Err(PodError::OutOfSpace));
+
+        // Write success
+        let mut buffer_write = [0u8; 3];
+
+        assert_eq!(Bcd::<3>::new(12345).unwrap().to_be_bytes(&mut buffer_write), Ok(3));
+        assert_eq!(buffer_write, [0x01, 0x23, 0x45]);
+        assert_eq!(Bcd::<3>::new(12345).unwrap().to_le_bytes(&mut buffer_write), Ok(3));
+        assert_eq!(buffer_write, [0x45, 0x23, 0x01]);
+
+        // Write fail
+        assert_eq!(Bcd::<1>::new(100), Err(PodError::OutOfRange));
+        assert_eq!(Bcd::<3>::new(1).unwrap().to_le_bytes(&mut buffer_write[1..]), Err(PodError::OutOfSpace));
+    }
+}
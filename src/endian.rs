@@ -0,0 +1,393 @@
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign,
+    Div, DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr,
+    ShrAssign, Sub, SubAssign,
+};
+
+use crate::pod::{Pod, PodError};
+use crate::zeroable::Zeroable;
+
+/// A value that is always serialized in big endian byte order,
+/// regardless of whether `from_le_bytes` or `from_be_bytes` is called
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Be<T>(T);
+
+/// A value that is always serialized in little endian byte order,
+/// regardless of whether `from_le_bytes` or `from_be_bytes` is called
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Le<T>(T);
+
+/// Big endian `u16`
+pub type U16Be = Be<u16>;
+/// Big endian `u32`
+pub type U32Be = Be<u32>;
+/// Big endian `u64`
+pub type U64Be = Be<u64>;
+/// Big endian `u128`
+pub type U128Be = Be<u128>;
+/// Big endian `i16`
+pub type I16Be = Be<i16>;
+/// Big endian `i32`
+pub type I32Be = Be<i32>;
+/// Big endian `i64`
+pub type I64Be = Be<i64>;
+/// Big endian `i128`
+pub type I128Be = Be<i128>;
+/// Big endian `f32`
+pub type F32Be = Be<f32>;
+/// Big endian `f64`
+pub type F64Be = Be<f64>;
+
+/// Little endian `u16`
+pub type U16Le = Le<u16>;
+/// Little endian `u32`
+pub type U32Le = Le<u32>;
+/// Little endian `u64`
+pub type U64Le = Le<u64>;
+/// Little endian `u128`
+pub type U128Le = Le<u128>;
+/// Little endian `i16`
+pub type I16Le = Le<i16>;
+/// Little endian `i32`
+pub type I32Le = Le<i32>;
+/// Little endian `i64`
+pub type I64Le = Le<i64>;
+/// Little endian `i128`
+pub type I128Le = Le<i128>;
+/// Little endian `f32`
+pub type F32Le = Le<f32>;
+/// Little endian `f64`
+pub type F64Le = Le<f64>;
+
+impl<T: Copy> Be<T> {
+    /// Wrap a native value
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Get the native value
+    #[inline]
+    pub const fn get(self) -> T {
+        self.0
+    }
+
+    /// Set the native value
+    #[inline]
+    pub fn set(&mut self, value: T) {
+        self.0 = value;
+    }
+}
+
+impl<T: Copy> Le<T> {
+    /// Wrap a native value
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Get the native value
+    #[inline]
+    pub const fn get(self) -> T {
+        self.0
+    }
+
+    /// Set the native value
+    #[inline]
+    pub fn set(&mut self, value: T) {
+        self.0 = value;
+    }
+}
+
+impl<T: Zeroable> Zeroable for Be<T> {
+    #[inline]
+    fn zeroed() -> Self {
+        Self(T::zeroed())
+    }
+}
+
+impl<T: Zeroable> Zeroable for Le<T> {
+    #[inline]
+    fn zeroed() -> Self {
+        Self(T::zeroed())
+    }
+}
+
+impl<T: Pod> Pod for Be<T> {
+    const SIZE: usize = T::SIZE;
+
+    #[inline]
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(T::from_be_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(T::from_be_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        self.0.to_be_bytes(buffer)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        self.0.to_be_bytes(buffer)
+    }
+}
+
+impl<T: Pod> Pod for Le<T> {
+    const SIZE: usize = T::SIZE;
+
+    #[inline]
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(T::from_le_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(T::from_le_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        self.0.to_le_bytes(buffer)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        self.0.to_le_bytes(buffer)
+    }
+}
+
+// Implements formatting, conversions and arithmetic
+// by forwarding to the wrapped value
+macro_rules! impl_forwarding {
+    ($wrapper:ident) => {
+        impl<T: fmt::Debug> fmt::Debug for $wrapper<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl<T: fmt::Display> fmt::Display for $wrapper<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl<T> From<T> for $wrapper<T> {
+            #[inline]
+            fn from(value: T) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<T: PartialEq> PartialEq<T> for $wrapper<T> {
+            #[inline]
+            fn eq(&self, other: &T) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl<T: PartialOrd> PartialOrd<T> for $wrapper<T> {
+            #[inline]
+            fn partial_cmp(&self, other: &T) -> Option<std::cmp::Ordering> {
+                self.0.partial_cmp(other)
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $wrapper<T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl<T: Not<Output = T>> Not for $wrapper<T> {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                Self(!self.0)
+            }
+        }
+
+        impl_forwarding!($wrapper, Add, add, AddAssign, add_assign);
+        impl_forwarding!($wrapper, Sub, sub, SubAssign, sub_assign);
+        impl_forwarding!($wrapper, Mul, mul, MulAssign, mul_assign);
+        impl_forwarding!($wrapper, Div, div, DivAssign, div_assign);
+        impl_forwarding!($wrapper, Rem, rem, RemAssign, rem_assign);
+        impl_forwarding!($wrapper, BitAnd, bitand, BitAndAssign, bitand_assign);
+        impl_forwarding!($wrapper, BitOr, bitor, BitOrAssign, bitor_assign);
+        impl_forwarding!($wrapper, BitXor, bitxor, BitXorAssign, bitxor_assign);
+        impl_forwarding!($wrapper, Shl, shl, ShlAssign, shl_assign);
+        impl_forwarding!($wrapper, Shr, shr, ShrAssign, shr_assign);
+    };
+    ($wrapper:ident, $op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident) => {
+        impl<T: $op<Output = T>> $op for $wrapper<T> {
+            type Output = Self;
+
+            #[inline]
+            fn $op_fn(self, rhs: Self) -> Self {
+                Self($op::$op_fn(self.0, rhs.0))
+            }
+        }
+
+        impl<T: $op<Output = T>> $op<T> for $wrapper<T> {
+            type Output = Self;
+
+            #[inline]
+            fn $op_fn(self, rhs: T) -> Self {
+                Self($op::$op_fn(self.0, rhs))
+            }
+        }
+
+        impl<T: $op_assign> $op_assign for $wrapper<T> {
+            #[inline]
+            fn $op_assign_fn(&mut self, rhs: Self) {
+                $op_assign::$op_assign_fn(&mut self.0, rhs.0)
+            }
+        }
+
+        impl<T: $op_assign> $op_assign<T> for $wrapper<T> {
+            #[inline]
+            fn $op_assign_fn(&mut self, rhs: T) {
+                $op_assign::$op_assign_fn(&mut self.0, rhs)
+            }
+        }
+    };
+}
+
+impl_forwarding!(Be);
+impl_forwarding!(Le);
+
+// Implements conversions back to the native types
+macro_rules! impl_into_native {
+    ($($ty:ty),*) => {
+        $(
+            impl From<Be<$ty>> for $ty {
+                #[inline]
+                fn from(value: Be<$ty>) -> Self {
+                    value.0
+                }
+            }
+
+            impl From<Le<$ty>> for $ty {
+                #[inline]
+                fn from(value: Le<$ty>) -> Self {
+                    value.0
+                }
+            }
+        )*
+    };
+}
+
+impl_into_native!(bool, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pod_be() {
+        // Read ignores requested byte order
+        let buffer_read = [0u8, 0u8, 0u8, 1u8];
+
+        assert_eq!(<U32Be as Pod>::from_le_bytes(&buffer_read), Ok(U32Be::new(1)));
+        assert_eq!(<U32Be as Pod>::from_be_bytes(&buffer_read), Ok(U32Be::new(1)));
+
+        // Read fail
+        let buffer_read_fail = [0u8; <U32Be as Pod>::SIZE - 1];
+
+        assert_eq!(<U32Be as Pod>::from_le_bytes(&buffer_read_fail), Err(PodError::OutOfSpace));
+        assert_eq!(<U32Be as Pod>::from_be_bytes(&buffer_read_fail), Err(PodError::OutOfSpace));
+
+        // Write ignores requested byte order
+        let mut buffer_write1 = [0u8; <U32Be as Pod>::SIZE];
+        let mut buffer_write2 = [0u8; <U32Be as Pod>::SIZE];
+
+        assert_eq!(U32Be::new(1).to_le_bytes(&mut buffer_write1), Ok(<U32Be as Pod>::SIZE));
+        assert_eq!(U32Be::new(1).to_be_bytes(&mut buffer_write2), Ok(<U32Be as Pod>::SIZE));
+
+        assert_eq!(buffer_write1, buffer_read);
+        assert_eq!(buffer_write2, buffer_read);
+
+        // Write fail
+        let mut buffer_write_fail = [0u8; <U32Be as Pod>::SIZE - 1];
+
+        assert_eq!(U32Be::new(1).to_le_bytes(&mut buffer_write_fail), Err(PodError::OutOfSpace));
+        assert_eq!(U32Be::new(1).to_be_bytes(&mut buffer_write_fail), Err(PodError::OutOfSpace));
+    }
+
+    #[test]
+    fn pod_le() {
+        // Read ignores requested byte order
+        let buffer_read = [1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0xf0u8, 0x3fu8];
+
+        assert_eq!(<F64Le as Pod>::from_le_bytes(&buffer_read[6..]), Err(PodError::OutOfSpace));
+        assert_eq!(<I16Le as Pod>::from_le_bytes(&buffer_read), Ok(I16Le::new(1)));
+        assert_eq!(<I16Le as Pod>::from_be_bytes(&buffer_read), Ok(I16Le::new(1)));
+
+        let buffer_read = [0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0xf0u8, 0x3fu8];
+
+        assert_eq!(<F64Le as Pod>::from_le_bytes(&buffer_read), Ok(F64Le::new(1.0)));
+        assert_eq!(<F64Le as Pod>::from_be_bytes(&buffer_read), Ok(F64Le::new(1.0)));
+
+        // Write ignores requested byte order
+        let mut buffer_write = [0u8; <F64Le as Pod>::SIZE];
+
+        assert_eq!(F64Le::new(1.0).to_be_bytes(&mut buffer_write), Ok(<F64Le as Pod>::SIZE));
+        assert_eq!(buffer_write, buffer_read);
+    }
+
+    #[test]
+    fn mixed_endian_array() {
+        let buffer_read = [0u8, 1u8, 2u8, 0u8];
+
+        assert_eq!(
+            <[U16Be; 2] as Pod>::from_le_bytes(&buffer_read),
+            Ok([U16Be::new(1), U16Be::new(512)])
+        );
+        assert_eq!(
+            <[U16Le; 2] as Pod>::from_be_bytes(&buffer_read),
+            Ok([U16Le::new(256), U16Le::new(2)])
+        );
+    }
+
+    #[test]
+    fn forwarding() {
+        let mut value = U32Le::from(5);
+
+        assert_eq!(value.get(), 5);
+        value.set(7);
+        assert_eq!(u32::from(value), 7);
+
+        assert_eq!(value + U32Le::new(1), 8u32);
+        assert_eq!(value - 2, 5u32);
+        assert_eq!(value * 2, U32Le::new(14));
+        assert_eq!(value / 7, 1u32);
+        assert_eq!(value % 4, 3u32);
+        assert_eq!(value & 3, 3u32);
+        assert_eq!(value | 8, 15u32);
+        assert_eq!(value ^ 1, 6u32);
+        assert_eq!(value << 1, 14u32);
+        assert_eq!(value >> 1, 3u32);
+        assert_eq!(!U32Be::new(0), u32::MAX);
+        assert_eq!(-I32Be::new(3), -3i32);
+
+        value += 1;
+        value -= U32Le::new(2);
+        assert_eq!(value, 6u32);
+
+        assert!(U32Be::new(1) < U32Be::new(2));
+        assert!(U32Be::new(3) > 2);
+        assert_eq!(format!("{:?}", U32Be::new(3)), "3");
+        assert_eq!(format!("{}", F32Le::new(1.5)), "1.5");
+        assert_eq!(U16Be::zeroed(), 0u16);
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt;
+
+use crate::endian::{Be, Le};
+use crate::pod::{Pod, PodError};
+use crate::zeroable::Zeroable;
+
+/// An integer type that can back fixed point and scaled values
+pub trait FixedInt: Pod + Copy {
+    /// Convert to an `f64`
+    fn to_f64(self) -> f64;
+
+    /// Convert from an `f64`, rounding to nearest and saturating
+    /// at the bounds of the type. NaN converts to zero.
+    fn from_f64_saturating(value: f64) -> Self;
+}
+
+// Implements FixedInt for the native integer types
+macro_rules! impl_fixed_int {
+    ($($ty:ty),*) => {
+        $(
+            impl FixedInt for $ty {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                fn from_f64_saturating(value: f64) -> Self {
+                    value.round() as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_int!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl<T: FixedInt> FixedInt for Be<T> {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.get().to_f64()
+    }
+
+    #[inline]
+    fn from_f64_saturating(value: f64) -> Self {
+        Be::new(T::from_f64_saturating(value))
+    }
+}
+
+impl<T: FixedInt> FixedInt for Le<T> {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.get().to_f64()
+    }
+
+    #[inline]
+    fn from_f64_saturating(value: f64) -> Self {
+        Le::new(T::from_f64_saturating(value))
+    }
+}
+
+/// A binary fixed point number stored as the integer `I`
+/// with `FRAC` fractional bits
+///
+/// The value is `bits / 2^FRAC`, so a `Fixed<i16, 15>` is a Q15
+/// number in `[-1, 1)` and a `Fixed<i32, 16>` is a Q16.16 number.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed<I, const FRAC: u32>(I);
+
+/// Q7, a signed 8 bit number in `[-1, 1)`
+pub type Q7 = Fixed<i8, 7>;
+/// Q15, a signed 16 bit number in `[-1, 1)`
+pub type Q15 = Fixed<i16, 15>;
+/// Q31, a signed 32 bit number in `[-1, 1)`
+pub type Q31 = Fixed<i32, 31>;
+/// Signed Q8.8
+pub type I8F8 = Fixed<i16, 8>;
+/// Signed Q16.16
+pub type I16F16 = Fixed<i32, 16>;
+/// Unsigned Q16.16
+pub type U16F16 = Fixed<u32, 16>;
+
+impl<I: FixedInt, const FRAC: u32> Fixed<I, FRAC> {
+    /// Create from the raw integer
+    #[inline]
+    pub const fn from_bits(bits: I) -> Self {
+        Self(bits)
+    }
+
+    /// Get the raw integer
+    #[inline]
+    pub const fn to_bits(self) -> I {
+        self.0
+    }
+
+    /// Convert to an `f64`
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.0.to_f64() / Self::scale()
+    }
+
+    /// Convert from an `f64`, rounding to nearest and saturating
+    /// at the bounds of the type. NaN converts to zero.
+    #[inline]
+    pub fn from_f64(value: f64) -> Self {
+        Self(I::from_f64_saturating(value * Self::scale()))
+    }
+
+    // 2^FRAC
+    #[inline]
+    fn scale() -> f64 {
+        2f64.powi(FRAC as i32)
+    }
+}
+
+impl<I: Zeroable, const FRAC: u32> Zeroable for Fixed<I, FRAC> {
+    #[inline]
+    fn zeroed() -> Self {
+        Self(I::zeroed())
+    }
+}
+
+impl<I: Pod, const FRAC: u32> Pod for Fixed<I, FRAC> {
+    const SIZE: usize = I::SIZE;
+
+    #[inline]
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(I::from_le_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(I::from_be_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        self.0.to_le_bytes(buffer)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        self.0.to_be_bytes(buffer)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> From<Fixed<I, FRAC>> for f64 {
+    #[inline]
+    fn from(value: Fixed<I, FRAC>) -> Self {
+        value.to_f64()
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> fmt::Debug for Fixed<I, FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f64(), f)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> fmt::Display for Fixed<I, FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pod_fixed() {
+        // Read success
+        let buffer_read = [0x00u8, 0x40u8];
+
+        assert_eq!(<Q15 as Pod>::from_le_bytes(&buffer_read).map(Q15::to_f64), Ok(0.5));
+        assert_eq!(<Q15 as Pod>::from_be_bytes(&buffer_read).map(Q15::to_bits), Ok(0x0040));
+
+        // Read fail
+        let buffer_read_fail = [0u8; <Q15 as Pod>::SIZE - 1];
+
+        assert_eq!(<Q15 as Pod>::from_le_bytes(&buffer_read_fail), Err(PodError::OutOfSpace));
+        assert_eq!(<Q15 as Pod>::from_be_bytes(&buffer_read_fail), Err(PodError::OutOfSpace));
+
+        // Write success
+        let mut buffer_write = [0u8; <Q15 as Pod>::SIZE * 2];
+
+        assert_eq!(Q15::from_f64(0.5).to_le_bytes(&mut buffer_write), Ok(<Q15 as Pod>::SIZE));
+        assert_eq!(
+            Q15::from_f64(0.5).to_be_bytes(&mut buffer_write[<Q15 as Pod>::SIZE..]),
+            Ok(<Q15 as Pod>::SIZE)
+        );
+        assert_eq!(buffer_write, [0x00, 0x40, 0x40, 0x00]);
+
+        // Write fail
+        let mut buffer_write_fail = [0u8; <Q15 as Pod>::SIZE - 1];
+
+        assert_eq!(Q15::from_f64(0.5).to_le_bytes(&mut buffer_write_fail), Err(PodError::OutOfSpace));
+        assert_eq!(Q15::from_f64(0.5).to_be_bytes(&mut buffer_write_fail), Err(PodError::OutOfSpace));
+    }
+
+    #[test]
+    fn fixed_conversions() {
+        assert_eq!(Q15::from_f64(-1.0).to_bits(), i16::MIN);
+        assert_eq!(Q15::from_f64(-0.25).to_f64(), -0.25);
+        assert_eq!(I16F16::from_f64(1.5).to_bits(), 0x0001_8000);
+        assert_eq!(I16F16::from_f64(-1.5).to_f64(), -1.5);
+        assert_eq!(U16F16::from_bits(0x0002_4000).to_f64(), 2.25);
+        assert_eq!(I8F8::from_f64(1.0 / 512.0).to_bits(), 1);
+        assert_eq!(Fixed::<u8, 4>::from_f64(0.03).to_bits(), 0);
+
+        // Saturation
+        assert_eq!(Q15::from_f64(1.0).to_bits(), i16::MAX);
+        assert_eq!(Q15::from_f64(-2.0).to_bits(), i16::MIN);
+        assert_eq!(U16F16::from_f64(-1.0).to_bits(), 0);
+        assert_eq!(Q7::from_f64(f64::INFINITY).to_bits(), i8::MAX);
+        assert_eq!(Q31::from_f64(f64::NAN).to_bits(), 0);
+
+        assert!(Q15::from_f64(0.25) < Q15::from_f64(0.5));
+        assert_eq!(format!("{}", Q15::from_f64(0.5)), "0.5");
+        assert_eq!(f64::from(Fixed::<Be<u16>, 8>::from_f64(2.5)), 2.5);
+    }
+
+    #[test]
+    fn fixed_int() {
+        assert_eq!(u8::from_f64_saturating(255.6), 255);
+        assert_eq!(i16::from_f64_saturating(-1.5), -2);
+        assert_eq!(u32::from_f64_saturating(-3.0), 0);
+        assert_eq!(Be::<u16>::from_f64_saturating(7.0).get(), 7);
+        assert_eq!(Le::<i8>::new(-3).to_f64(), -3.0);
+    }
+}
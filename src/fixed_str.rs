@@ -0,0 +1,332 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use crate::encoded_int::{NulPadded, SpacePadded};
+use crate::pod::{Pod, PodError};
+use crate::zeroable::Zeroable;
+
+/// How fixed width strings are padded to their width
+pub trait StrPadding {
+    /// Byte used for padding
+    const BYTE: u8;
+
+    /// Whether at least one padding byte must follow the text
+    const TERMINATED: bool;
+}
+
+/// Text followed by at least one NUL byte, like a C string
+/// in a `char name[N]` buffer
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NulTerminated;
+
+impl StrPadding for NulPadded {
+    const BYTE: u8 = 0;
+    const TERMINATED: bool = false;
+}
+
+impl StrPadding for NulTerminated {
+    const BYTE: u8 = 0;
+    const TERMINATED: bool = true;
+}
+
+impl StrPadding for SpacePadded {
+    const BYTE: u8 = b' ';
+    const TERMINATED: bool = false;
+}
+
+// Find the length of padded text made of `units`, where `padding`
+// is the padding byte as a unit
+pub(crate) fn text_len<P: StrPadding, T: Copy + PartialEq>(units: &[T], padding: T) -> Result<usize, PodError> {
+    // NUL padded text ends at the first NUL
+    if P::BYTE == 0 {
+        return match units.iter().position(|u| *u == padding) {
+            Some(l) => Ok(l),
+            None if P::TERMINATED => Err(PodError::OutOfRange),
+            None => Ok(units.len()),
+        };
+    }
+
+    Ok(units.iter().rposition(|u| *u != padding).map_or(0, |l| l + 1))
+}
+
+/// Which bytes are allowed in a fixed width string
+pub trait StrEncoding {
+    /// Whether `bytes` is valid text
+    fn validate(bytes: &[u8]) -> bool;
+}
+
+/// Any valid UTF-8
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Utf8;
+
+/// ASCII only
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Ascii;
+
+impl StrEncoding for Utf8 {
+    #[inline]
+    fn validate(bytes: &[u8]) -> bool {
+        std::str::from_utf8(bytes).is_ok()
+    }
+}
+
+impl StrEncoding for Ascii {
+    #[inline]
+    fn validate(bytes: &[u8]) -> bool {
+        bytes.is_ascii()
+    }
+}
+
+/// A string stored in exactly `N` bytes, padded according to `P`
+/// and validated according to `E`
+///
+/// Decoding strips the padding and fails with [`PodError::OutOfRange`]
+/// if the text isn't valid, or if `P` is [`NulTerminated`] and
+/// there is no NUL byte. NUL padded text ends at the first NUL byte
+/// and space padded text has its trailing spaces removed.
+pub struct FixedStr<const N: usize, P: StrPadding = NulPadded, E: StrEncoding = Utf8> {
+    bytes: [u8; N],
+    len: usize,
+    marker: PhantomData<(P, E)>,
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> FixedStr<N, P, E> {
+    /// Longest text in bytes that fits
+    pub const CAPACITY: usize = if P::TERMINATED { N.saturating_sub(1) } else { N };
+
+    /// Create from text, failing with [`PodError::OutOfRange`] if it's
+    /// longer than [`CAPACITY`](Self::CAPACITY) bytes, not valid, or
+    /// wouldn't decode to the same text, because it contains a NUL
+    /// byte when NUL padded or ends with a space when space padded
+    pub fn new(text: &str) -> Result<Self, PodError> {
+        Self::from_text_bytes(text.as_bytes())
+    }
+
+    /// Get the text
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // Contents are validated on construction
+        std::str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+
+    /// Get the bytes of the text, without padding
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Length of the text in bytes
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the text is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Create from text bytes, checking length, encoding and
+    // that the padding wouldn't cut the text short on decode
+    fn from_text_bytes(text: &[u8]) -> Result<Self, PodError> {
+        if text.len() > Self::CAPACITY || !E::validate(text) || std::str::from_utf8(text).is_err() {
+            return Err(PodError::OutOfRange);
+        }
+
+        let ambiguous = match P::BYTE {
+            0 => text.contains(&0),
+            _ => text.last() == Some(&P::BYTE),
+        };
+        if ambiguous {
+            return Err(PodError::OutOfRange);
+        }
+
+        let mut bytes = [P::BYTE; N];
+        bytes[..text.len()].copy_from_slice(text);
+
+        Ok(Self { bytes, len: text.len(), marker: PhantomData })
+    }
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> Clone for FixedStr<N, P, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> Copy for FixedStr<N, P, E> { }
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> PartialEq for FixedStr<N, P, E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> Eq for FixedStr<N, P, E> { }
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> PartialEq<str> for FixedStr<N, P, E> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> PartialEq<&str> for FixedStr<N, P, E> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> Hash for FixedStr<N, P, E> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state)
+    }
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> Default for FixedStr<N, P, E> {
+    #[inline]
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> fmt::Debug for FixedStr<N, P, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> fmt::Display for FixedStr<N, P, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> AsRef<str> for FixedStr<N, P, E> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> TryFrom<&str> for FixedStr<N, P, E> {
+    type Error = PodError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> Zeroable for FixedStr<N, P, E> {
+    #[inline]
+    fn zeroed() -> Self {
+        Self { bytes: [P::BYTE; N], len: 0, marker: PhantomData }
+    }
+}
+
+impl<const N: usize, P: StrPadding, E: StrEncoding> Pod for FixedStr<N, P, E> {
+    const SIZE: usize = N;
+
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        let bytes = &buffer[..N];
+        let len = text_len::<P, u8>(bytes, P::BYTE)?;
+
+        Self::from_text_bytes(&bytes[..len])
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Self::from_le_bytes(buffer)
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        buffer[..N].copy_from_slice(&self.bytes);
+
+        Ok(N)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        self.to_le_bytes(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pod_fixed_str() {
+        // Read success
+        assert_eq!(<FixedStr<8> as Pod>::from_le_bytes(b"name\0xyz").unwrap(), "name");
+        assert_eq!(<FixedStr<4> as Pod>::from_le_bytes(b"full").unwrap(), "full");
+        assert_eq!(<FixedStr<8, SpacePadded> as Pod>::from_be_bytes(b"A B     ").unwrap(), "A B");
+        assert_eq!(<FixedStr<4, NulTerminated> as Pod>::from_le_bytes(b"abc\0").unwrap(), "abc");
+        assert_eq!(<FixedStr<4> as Pod>::from_le_bytes("é\0\0".as_bytes()).unwrap(), "é");
+
+        // Read fail
+        assert_eq!(<FixedStr<4, NulTerminated> as Pod>::from_le_bytes(b"abcd"), Err(PodError::OutOfRange));
+        assert_eq!(<FixedStr<4> as Pod>::from_le_bytes(&[0xff, 0, 0, 0]), Err(PodError::OutOfRange));
+        assert_eq!(
+            <FixedStr<4, NulPadded, Ascii> as Pod>::from_le_bytes("é\0\0".as_bytes()),
+            Err(PodError::OutOfRange)
+        );
+        assert_eq!(<FixedStr<4> as Pod>::from_le_bytes(b"abc"), Err(PodError::OutOfSpace));
+
+        // Write success
+        let mut buffer_write = [0xffu8; 6];
+
+        assert_eq!(FixedStr::<6>::new("abc").unwrap().to_le_bytes(&mut buffer_write), Ok(6));
+        assert_eq!(&buffer_write, b"abc\0\0\0");
+        assert_eq!(FixedStr::<6, SpacePadded>::new("abc").unwrap().to_be_bytes(&mut buffer_write), Ok(6));
+        assert_eq!(&buffer_write, b"abc   ");
+
+        // Write fail
+        assert_eq!(FixedStr::<6>::new("abc").unwrap().to_le_bytes(&mut buffer_write[1..]), Err(PodError::OutOfSpace));
+    }
+
+    #[test]
+    fn fixed_str_conversions() {
+        assert_eq!(FixedStr::<4>::try_from("abcd").map(|s| s.len()), Ok(4));
+        assert_eq!(FixedStr::<4>::try_from("abcde"), Err(PodError::OutOfRange));
+        assert_eq!(FixedStr::<4, NulTerminated>::try_from("abcd"), Err(PodError::OutOfRange));
+        assert_eq!(FixedStr::<4, NulTerminated>::CAPACITY, 3);
+        assert_eq!(FixedStr::<4, NulPadded, Ascii>::try_from("é"), Err(PodError::OutOfRange));
+        assert!(FixedStr::<4>::zeroed().is_empty());
+        assert_eq!(FixedStr::<4, SpacePadded>::zeroed().as_str(), "");
+        assert_eq!(format!("{}", FixedStr::<8>::new("hello").unwrap()), "hello");
+    }
+
+    #[test]
+    fn fixed_str_ambiguous_text() {
+        // Text that would decode differently
+        assert_eq!(FixedStr::<8>::new("ab\0cd"), Err(PodError::OutOfRange));
+        assert_eq!(FixedStr::<8, NulTerminated>::new("ab\0"), Err(PodError::OutOfRange));
+        assert_eq!(FixedStr::<8, SpacePadded>::new("abc "), Err(PodError::OutOfRange));
+        assert_eq!(FixedStr::<8, SpacePadded>::try_from(" "), Err(PodError::OutOfRange));
+
+        // Spaces before the end and NUL bytes in space padded text roundtrip
+        let mut buffer = [0u8; 8];
+        for text in [" a b", "a\0b"] {
+            let value = FixedStr::<8, SpacePadded>::new(text).unwrap();
+            value.to_le_bytes(&mut buffer).unwrap();
+            assert_eq!(<FixedStr<8, SpacePadded> as Pod>::from_le_bytes(&buffer).unwrap(), text);
+        }
+    }
+}
@@ -0,0 +1,433 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::pod::{Pod, PodError};
+use crate::zeroable::Zeroable;
+
+/// An IEEE 754 binary16 half precision float
+///
+/// Converting to `f32` is lossless, converting from
+/// `f32` rounds to the nearest value, ties to even.
+#[derive(Clone, Copy, Default)]
+pub struct F16(u16);
+
+/// A bfloat16 float, the upper half of an `f32`
+///
+/// Converting to `f32` is lossless, converting from
+/// `f32` rounds to the nearest value, ties to even.
+#[derive(Clone, Copy, Default)]
+pub struct BF16(u16);
+
+impl F16 {
+    /// Positive zero
+    pub const ZERO: Self = Self(0x0000);
+    /// One
+    pub const ONE: Self = Self(0x3c00);
+    /// Largest finite value (65504)
+    pub const MAX: Self = Self(0x7bff);
+    /// Smallest finite value (-65504)
+    pub const MIN: Self = Self(0xfbff);
+    /// Smallest positive normal value
+    pub const MIN_POSITIVE: Self = Self(0x0400);
+    /// Positive infinity
+    pub const INFINITY: Self = Self(0x7c00);
+    /// Negative infinity
+    pub const NEG_INFINITY: Self = Self(0xfc00);
+    /// Quiet NaN
+    pub const NAN: Self = Self(0x7e00);
+
+    /// Create from raw bits
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// Get raw bits
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Whether the value is NaN
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7c00 == 0x7c00 && self.0 & 0x03ff != 0
+    }
+
+    /// Whether the value is positive or negative infinity
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        self.0 & 0x7fff == 0x7c00
+    }
+
+    /// Create from an `f32`, rounding to nearest, ties to even
+    pub const fn from_f32(value: f32) -> Self {
+        let x = value.to_bits();
+        let sign = ((x >> 16) & 0x8000) as u16;
+        let exp = ((x >> 23) & 0xff) as i32;
+        let man = x & 0x007f_ffff;
+
+        // Infinity and NaN, keeping NaN quiet
+        if exp == 0xff {
+            if man == 0 {
+                return Self(sign | 0x7c00);
+            }
+            return Self(sign | 0x7e00 | (man >> 13) as u16);
+        }
+
+        // Rebias exponent
+        let half_exp = exp - 127 + 15;
+
+        // Overflow to infinity
+        if half_exp >= 0x1f {
+            return Self(sign | 0x7c00);
+        }
+
+        // Subnormal or zero
+        if half_exp <= 0 {
+            // Less than half of the smallest subnormal
+            if half_exp < -10 {
+                return Self(sign);
+            }
+
+            let man = man | 0x0080_0000;
+            let shift = (14 - half_exp) as u32;
+            let mut half_man = man >> shift;
+            let round = (man >> (shift - 1)) & 1 != 0;
+            let sticky = man & ((1 << (shift - 1)) - 1) != 0;
+            if round && (sticky || half_man & 1 != 0) {
+                half_man += 1;
+            }
+
+            return Self(sign | half_man as u16);
+        }
+
+        // Normal, a carry out of the mantissa correctly
+        // increments the exponent or overflows to infinity
+        let mut bits = ((half_exp as u32) << 10) | (man >> 13);
+        let round = man & 0x1000 != 0;
+        let sticky = man & 0x0fff != 0;
+        if round && (sticky || bits & 1 != 0) {
+            bits += 1;
+        }
+
+        Self(sign | bits as u16)
+    }
+
+    /// Convert to an `f32` losslessly
+    pub fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let exp = ((self.0 >> 10) & 0x1f) as u32;
+        let man = (self.0 & 0x03ff) as u32;
+
+        // Infinity and NaN
+        if exp == 0x1f {
+            return f32::from_bits(sign | 0x7f80_0000 | (man << 13));
+        }
+
+        // Zero and subnormal, scaled by 2^-24
+        if exp == 0 {
+            let magnitude = man as f32 * f32::from_bits(0x3380_0000);
+            return f32::from_bits(sign | magnitude.to_bits());
+        }
+
+        // Normal
+        f32::from_bits(sign | ((exp + 112) << 23) | (man << 13))
+    }
+
+    /// Convert to an `f64` losslessly
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+}
+
+impl BF16 {
+    /// Positive zero
+    pub const ZERO: Self = Self(0x0000);
+    /// One
+    pub const ONE: Self = Self(0x3f80);
+    /// Largest finite value
+    pub const MAX: Self = Self(0x7f7f);
+    /// Smallest finite value
+    pub const MIN: Self = Self(0xff7f);
+    /// Smallest positive normal value
+    pub const MIN_POSITIVE: Self = Self(0x0080);
+    /// Positive infinity
+    pub const INFINITY: Self = Self(0x7f80);
+    /// Negative infinity
+    pub const NEG_INFINITY: Self = Self(0xff80);
+    /// Quiet NaN
+    pub const NAN: Self = Self(0x7fc0);
+
+    /// Create from raw bits
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// Get raw bits
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Whether the value is NaN
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7f80 == 0x7f80 && self.0 & 0x007f != 0
+    }
+
+    /// Whether the value is positive or negative infinity
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        self.0 & 0x7fff == 0x7f80
+    }
+
+    /// Create from an `f32`, rounding to nearest, ties to even
+    pub const fn from_f32(value: f32) -> Self {
+        let x = value.to_bits();
+
+        // NaN, keeping it quiet so truncation can't make it infinite
+        if x & 0x7f80_0000 == 0x7f80_0000 && x & 0x007f_ffff != 0 {
+            return Self(((x >> 16) as u16) | 0x0040);
+        }
+
+        // A carry out of the mantissa correctly
+        // increments the exponent or overflows to infinity
+        let rounding = 0x7fff + ((x >> 16) & 1);
+        Self((x.wrapping_add(rounding) >> 16) as u16)
+    }
+
+    /// Convert to an `f32` losslessly
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        f32::from_bits((self.0 as u32) << 16)
+    }
+
+    /// Convert to an `f64` losslessly
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+}
+
+// Implements traits shared by both half precision types
+macro_rules! impl_half {
+    ($ty:ident) => {
+        impl Zeroable for $ty {
+            #[inline]
+            fn zeroed() -> Self {
+                Self(0)
+            }
+        }
+
+        impl Pod for $ty {
+            const SIZE: usize = 2;
+
+            #[inline]
+            fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+                Ok(Self(<u16 as Pod>::from_le_bytes(buffer)?))
+            }
+
+            #[inline]
+            fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+                Ok(Self(<u16 as Pod>::from_be_bytes(buffer)?))
+            }
+
+            #[inline]
+            fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+                <u16 as Pod>::to_le_bytes(&self.0, buffer)
+            }
+
+            #[inline]
+            fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+                <u16 as Pod>::to_be_bytes(&self.0, buffer)
+            }
+        }
+
+        impl PartialEq for $ty {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.to_f32() == other.to_f32()
+            }
+        }
+
+        impl PartialOrd for $ty {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.to_f32().partial_cmp(&other.to_f32())
+            }
+        }
+
+        impl fmt::Debug for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl From<$ty> for f32 {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                value.to_f32()
+            }
+        }
+
+        impl From<$ty> for f64 {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                value.to_f64()
+            }
+        }
+
+        impl From<f32> for $ty {
+            #[inline]
+            fn from(value: f32) -> Self {
+                Self::from_f32(value)
+            }
+        }
+    };
+}
+
+impl_half!(F16);
+impl_half!(BF16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pod_f16() {
+        // Read success
+        let buffer_read = [0x00u8, 0x3cu8];
+
+        assert_eq!(<F16 as Pod>::from_le_bytes(&buffer_read), Ok(F16::ONE));
+        assert_eq!(<F16 as Pod>::from_be_bytes(&buffer_read).map(F16::to_bits), Ok(0x003c));
+
+        // Read fail
+        let buffer_read_fail = [0u8; <F16 as Pod>::SIZE - 1];
+
+        assert_eq!(<F16 as Pod>::from_le_bytes(&buffer_read_fail), Err(PodError::OutOfSpace));
+        assert_eq!(<F16 as Pod>::from_be_bytes(&buffer_read_fail), Err(PodError::OutOfSpace));
+
+        // Write success
+        let mut buffer_write = [0u8; <F16 as Pod>::SIZE * 2];
+
+        assert_eq!(F16::ONE.to_le_bytes(&mut buffer_write), Ok(<F16 as Pod>::SIZE));
+        assert_eq!(
+            F16::ONE.to_be_bytes(&mut buffer_write[<F16 as Pod>::SIZE..]),
+            Ok(<F16 as Pod>::SIZE)
+        );
+        assert_eq!(buffer_write, [0x00, 0x3c, 0x3c, 0x00]);
+
+        // Write fail
+        let mut buffer_write_fail = [0u8; <F16 as Pod>::SIZE - 1];
+
+        assert_eq!(F16::ONE.to_le_bytes(&mut buffer_write_fail), Err(PodError::OutOfSpace));
+        assert_eq!(F16::ONE.to_be_bytes(&mut buffer_write_fail), Err(PodError::OutOfSpace));
+    }
+
+    #[test]
+    fn f16_to_f32() {
+        assert_eq!(F16::ONE.to_f32(), 1.0);
+        assert_eq!(F16::MAX.to_f32(), 65504.0);
+        assert_eq!(F16::MIN.to_f32(), -65504.0);
+        assert_eq!(F16::from_bits(0xc000).to_f32(), -2.0);
+        assert_eq!(F16::from_bits(0x0001).to_f32(), 2f32.powi(-24));
+        assert_eq!(F16::from_bits(0x03ff).to_f32(), 1023.0 * 2f32.powi(-24));
+        assert_eq!(F16::MIN_POSITIVE.to_f32(), 2f32.powi(-14));
+        assert_eq!(F16::from_bits(0x8000).to_f32().to_bits(), 0x8000_0000);
+        assert_eq!(F16::INFINITY.to_f32(), f32::INFINITY);
+        assert_eq!(F16::NEG_INFINITY.to_f32(), f32::NEG_INFINITY);
+        assert!(F16::NAN.to_f32().is_nan());
+    }
+
+    #[test]
+    fn f16_from_f32() {
+        assert_eq!(F16::from_f32(1.0).to_bits(), 0x3c00);
+        assert_eq!(F16::from_f32(-2.0).to_bits(), 0xc000);
+        assert_eq!(F16::from_f32(65504.0).to_bits(), 0x7bff);
+        assert_eq!(F16::from_f32(-0.0).to_bits(), 0x8000);
+
+        // Overflow and rounding up into infinity
+        assert_eq!(F16::from_f32(65520.0).to_bits(), 0x7c00);
+        assert_eq!(F16::from_f32(65519.0).to_bits(), 0x7bff);
+        assert_eq!(F16::from_f32(1e10).to_bits(), 0x7c00);
+        assert_eq!(F16::from_f32(-1e10).to_bits(), 0xfc00);
+
+        // Ties to even
+        assert_eq!(F16::from_f32(1.0 + 2f32.powi(-11)).to_bits(), 0x3c00);
+        assert_eq!(F16::from_f32(1.0 + 3.0 * 2f32.powi(-11)).to_bits(), 0x3c02);
+        assert_eq!(F16::from_f32(1.0 + 2f32.powi(-11) + 2f32.powi(-20)).to_bits(), 0x3c01);
+
+        // Subnormals
+        assert_eq!(F16::from_f32(2f32.powi(-24)).to_bits(), 0x0001);
+        assert_eq!(F16::from_f32(2f32.powi(-25)).to_bits(), 0x0000);
+        assert_eq!(F16::from_f32(1.5 * 2f32.powi(-25)).to_bits(), 0x0001);
+        assert_eq!(F16::from_f32(3.0 * 2f32.powi(-25)).to_bits(), 0x0002);
+        assert_eq!(F16::from_f32(2f32.powi(-30)).to_bits(), 0x0000);
+        assert_eq!(F16::from_f32(-2f32.powi(-30)).to_bits(), 0x8000);
+        assert_eq!(F16::from_f32(1023.5 * 2f32.powi(-24)).to_bits(), 0x0400);
+
+        // Infinity and NaN
+        assert_eq!(F16::from_f32(f32::INFINITY).to_bits(), 0x7c00);
+        assert_eq!(F16::from_f32(f32::NEG_INFINITY).to_bits(), 0xfc00);
+        assert!(F16::from_f32(f32::NAN).is_nan());
+        assert!(F16::from_f32(f32::from_bits(0x7f80_0001)).is_nan());
+
+        // Round trip every value
+        for bits in 0..=u16::MAX {
+            let value = F16::from_bits(bits);
+            if !value.is_nan() {
+                assert_eq!(F16::from_f32(value.to_f32()).to_bits(), bits);
+            }
+        }
+    }
+
+    #[test]
+    fn bf16_conversions() {
+        assert_eq!(BF16::from_f32(1.0).to_bits(), 0x3f80);
+        assert_eq!(BF16::ONE.to_f32(), 1.0);
+        assert_eq!(BF16::from_f32(-2.0), BF16::from_bits(0xc000));
+        assert_eq!(BF16::from_f32(f32::MAX).to_bits(), 0x7f80);
+        assert_eq!(BF16::MAX.to_f32(), f32::from_bits(0x7f7f_0000));
+
+        // Ties to even
+        assert_eq!(BF16::from_f32(f32::from_bits(0x3f80_8000)).to_bits(), 0x3f80);
+        assert_eq!(BF16::from_f32(f32::from_bits(0x3f81_8000)).to_bits(), 0x3f82);
+        assert_eq!(BF16::from_f32(f32::from_bits(0x3f80_8001)).to_bits(), 0x3f81);
+
+        // Subnormals
+        assert_eq!(BF16::from_f32(f32::from_bits(0x0000_8001)).to_bits(), 0x0001);
+        assert_eq!(BF16::from_bits(0x0001).to_f32(), f32::from_bits(0x0001_0000));
+
+        // Infinity and NaN
+        assert_eq!(BF16::from_f32(f32::INFINITY), BF16::INFINITY);
+        assert!(BF16::from_f32(f32::from_bits(0x7f80_0001)).is_nan());
+        assert!(BF16::NAN.to_f32().is_nan());
+        assert!(BF16::NEG_INFINITY.is_infinite());
+
+        let mut buffer = [0u8; <BF16 as Pod>::SIZE];
+        BF16::ONE.to_be_bytes(&mut buffer).unwrap();
+
+        assert_eq!(buffer, [0x3f, 0x80]);
+        assert_eq!(<BF16 as Pod>::from_be_bytes(&buffer), Ok(BF16::ONE));
+    }
+
+    #[test]
+    fn comparisons() {
+        assert_eq!(F16::from_bits(0x8000), F16::ZERO);
+        assert_ne!(F16::NAN, F16::NAN);
+        assert!(F16::ONE > F16::ZERO);
+        assert!(BF16::MIN < BF16::MAX);
+        assert_eq!(format!("{}", F16::from_f32(0.5)), "0.5");
+        assert_eq!(f64::from(BF16::ONE), 1.0);
+        assert_eq!(F16::zeroed(), F16::ZERO);
+    }
+}
@@ -0,0 +1,721 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::pod::{Pod, PodError};
+use crate::zeroable::Zeroable;
+
+/// An unsigned integer that is `BYTES` bytes wide
+///
+/// The value is stored as a little endian byte array,
+/// so any width is supported, including widths wider than `u128`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UInt<const BYTES: usize>([u8; BYTES]);
+
+/// A signed two's complement integer that is `BYTES` bytes wide
+///
+/// The value is stored as a little endian byte array,
+/// so any width is supported, including widths wider than `i128`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Int<const BYTES: usize>([u8; BYTES]);
+
+/// 24 bit unsigned integer
+pub type U24 = UInt<3>;
+/// 40 bit unsigned integer
+pub type U40 = UInt<5>;
+/// 48 bit unsigned integer
+pub type U48 = UInt<6>;
+/// 56 bit unsigned integer
+pub type U56 = UInt<7>;
+/// 256 bit unsigned integer
+pub type U256 = UInt<32>;
+
+/// 24 bit signed integer
+pub type I24 = Int<3>;
+/// 40 bit signed integer
+pub type I40 = Int<5>;
+/// 48 bit signed integer
+pub type I48 = Int<6>;
+/// 56 bit signed integer
+pub type I56 = Int<7>;
+/// 256 bit signed integer
+pub type I256 = Int<32>;
+
+impl<const BYTES: usize> UInt<BYTES> {
+    /// Smallest value
+    pub const MIN: Self = Self([0u8; BYTES]);
+
+    /// Largest value
+    pub const MAX: Self = Self([0xffu8; BYTES]);
+
+    /// Create from little endian bytes
+    #[inline]
+    pub const fn from_le_array(bytes: [u8; BYTES]) -> Self {
+        Self(bytes)
+    }
+
+    /// Get little endian bytes
+    #[inline]
+    pub const fn to_le_array(self) -> [u8; BYTES] {
+        self.0
+    }
+
+    /// Create from a `u128`, failing with
+    /// [`PodError::OutOfRange`] if the value does not fit
+    pub fn try_from_u128(value: u128) -> Result<Self, PodError> {
+        let bytes = value.to_le_bytes();
+        let mut array = [0u8; BYTES];
+
+        for (i, byte) in bytes.iter().enumerate() {
+            if i < BYTES {
+                array[i] = *byte;
+            } else if *byte != 0 {
+                return Err(PodError::OutOfRange);
+            }
+        }
+
+        Ok(Self(array))
+    }
+
+    /// Convert to a `u128`, failing with
+    /// [`PodError::OutOfRange`] if the value does not fit
+    pub fn try_to_u128(self) -> Result<u128, PodError> {
+        let mut bytes = [0u8; 16];
+
+        for (i, byte) in self.0.iter().enumerate() {
+            if i < 16 {
+                bytes[i] = *byte;
+            } else if *byte != 0 {
+                return Err(PodError::OutOfRange);
+            }
+        }
+
+        Ok(u128::from_le_bytes(bytes))
+    }
+}
+
+impl<const BYTES: usize> Int<BYTES> {
+    /// Smallest value
+    pub const MIN: Self = {
+        let mut array = [0u8; BYTES];
+        if BYTES > 0 {
+            array[BYTES - 1] = 0x80;
+        }
+        Self(array)
+    };
+
+    /// Largest value
+    pub const MAX: Self = {
+        let mut array = [0xffu8; BYTES];
+        if BYTES > 0 {
+            array[BYTES - 1] = 0x7f;
+        }
+        Self(array)
+    };
+
+    /// Create from little endian two's complement bytes
+    #[inline]
+    pub const fn from_le_array(bytes: [u8; BYTES]) -> Self {
+        Self(bytes)
+    }
+
+    /// Get little endian two's complement bytes
+    #[inline]
+    pub const fn to_le_array(self) -> [u8; BYTES] {
+        self.0
+    }
+
+    /// Whether the value is negative
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        BYTES > 0 && self.0[BYTES - 1] & 0x80 != 0
+    }
+
+    /// Create from an `i128`, failing with
+    /// [`PodError::OutOfRange`] if the value does not fit
+    pub fn try_from_i128(value: i128) -> Result<Self, PodError> {
+        let bytes = value.to_le_bytes();
+        let fill = if value < 0 { 0xffu8 } else { 0u8 };
+        let mut array = [fill; BYTES];
+
+        for (i, byte) in bytes.iter().enumerate() {
+            if i < BYTES {
+                array[i] = *byte;
+            } else if *byte != fill {
+                return Err(PodError::OutOfRange);
+            }
+        }
+
+        // Truncation must not flip the sign
+        let result = Self(array);
+        if result.is_negative() != (value < 0) {
+            return Err(PodError::OutOfRange);
+        }
+
+        Ok(result)
+    }
+
+    /// Create from a `u128`, failing with
+    /// [`PodError::OutOfRange`] if the value does not fit
+    pub fn try_from_u128(value: u128) -> Result<Self, PodError> {
+        let result = Self(UInt::<BYTES>::try_from_u128(value)?.0);
+        if result.is_negative() {
+            return Err(PodError::OutOfRange);
+        }
+
+        Ok(result)
+    }
+
+    /// Convert to an `i128` with sign extension, failing
+    /// with [`PodError::OutOfRange`] if the value does not fit
+    pub fn try_to_i128(self) -> Result<i128, PodError> {
+        let fill = if self.is_negative() { 0xffu8 } else { 0u8 };
+        let mut bytes = [fill; 16];
+
+        for (i, byte) in self.0.iter().enumerate() {
+            if i < 16 {
+                bytes[i] = *byte;
+            } else if *byte != fill {
+                return Err(PodError::OutOfRange);
+            }
+        }
+
+        // Truncation must not flip the sign
+        let value = i128::from_le_bytes(bytes);
+        if (value < 0) != self.is_negative() {
+            return Err(PodError::OutOfRange);
+        }
+
+        Ok(value)
+    }
+
+    /// Convert to a `u128`, failing with
+    /// [`PodError::OutOfRange`] if the value does not fit
+    pub fn try_to_u128(self) -> Result<u128, PodError> {
+        if self.is_negative() {
+            return Err(PodError::OutOfRange);
+        }
+
+        UInt(self.0).try_to_u128()
+    }
+}
+
+impl<const BYTES: usize> Default for UInt<BYTES> {
+    #[inline]
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
+impl<const BYTES: usize> Default for Int<BYTES> {
+    #[inline]
+    fn default() -> Self {
+        Self([0u8; BYTES])
+    }
+}
+
+impl<const BYTES: usize> Zeroable for UInt<BYTES> {
+    #[inline]
+    fn zeroed() -> Self {
+        Self([0u8; BYTES])
+    }
+}
+
+impl<const BYTES: usize> Zeroable for Int<BYTES> {
+    #[inline]
+    fn zeroed() -> Self {
+        Self([0u8; BYTES])
+    }
+}
+
+impl<const BYTES: usize> Pod for UInt<BYTES> {
+    const SIZE: usize = BYTES;
+
+    #[inline]
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(read_le(buffer)?))
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(read_be(buffer)?))
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        write_le(&self.0, buffer)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        write_be(&self.0, buffer)
+    }
+}
+
+impl<const BYTES: usize> Pod for Int<BYTES> {
+    const SIZE: usize = BYTES;
+
+    #[inline]
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(read_le(buffer)?))
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(read_be(buffer)?))
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        write_le(&self.0, buffer)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        write_be(&self.0, buffer)
+    }
+}
+
+// Read little endian bytes into a little endian array
+fn read_le<const BYTES: usize>(buffer: &[u8]) -> Result<[u8; BYTES], PodError> {
+    if buffer.len() < BYTES {
+        return Err(PodError::OutOfSpace);
+    }
+
+    let mut array = [0u8; BYTES];
+    array.copy_from_slice(&buffer[..BYTES]);
+
+    Ok(array)
+}
+
+// Read big endian bytes into a little endian array
+fn read_be<const BYTES: usize>(buffer: &[u8]) -> Result<[u8; BYTES], PodError> {
+    let mut array = read_le::<BYTES>(buffer)?;
+    array.reverse();
+
+    Ok(array)
+}
+
+// Write a little endian array as little endian bytes
+fn write_le(array: &[u8], buffer: &mut [u8]) -> Result<usize, PodError> {
+    if buffer.len() < array.len() {
+        return Err(PodError::OutOfSpace);
+    }
+
+    buffer[..array.len()].copy_from_slice(array);
+
+    Ok(array.len())
+}
+
+// Write a little endian array as big endian bytes
+fn write_be(array: &[u8], buffer: &mut [u8]) -> Result<usize, PodError> {
+    if buffer.len() < array.len() {
+        return Err(PodError::OutOfSpace);
+    }
+
+    for (i, byte) in array.iter().rev().enumerate() {
+        buffer[i] = *byte;
+    }
+
+    Ok(array.len())
+}
+
+impl<const BYTES: usize> Ord for UInt<BYTES> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl<const BYTES: usize> PartialOrd for UInt<BYTES> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const BYTES: usize> Ord for Int<BYTES> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Negative values are smaller, otherwise two's complement
+        // values with the same sign compare like unsigned values
+        match (self.is_negative(), other.is_negative()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            _ => UInt(self.0).cmp(&UInt(other.0)),
+        }
+    }
+}
+
+impl<const BYTES: usize> PartialOrd for Int<BYTES> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Format a little endian magnitude as decimal digits
+fn fmt_magnitude(magnitude: &[u8], negative: bool, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut magnitude = magnitude.to_vec();
+    let mut digits: Vec<u8> = Vec::new();
+
+    // Repeatedly divide by 10 from the most significant byte
+    loop {
+        let mut remainder = 0u16;
+        for byte in magnitude.iter_mut().rev() {
+            let current = (remainder << 8) | *byte as u16;
+            *byte = (current / 10) as u8;
+            remainder = current % 10;
+        }
+        digits.push(b'0' + remainder as u8);
+
+        if magnitude.iter().all(|b| *b == 0) {
+            break;
+        }
+    }
+
+    digits.reverse();
+    let digits = String::from_utf8(digits).map_err(|_| fmt::Error)?;
+
+    f.pad_integral(!negative, "", &digits)
+}
+
+impl<const BYTES: usize> fmt::Display for UInt<BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_magnitude(&self.0, false, f)
+    }
+}
+
+impl<const BYTES: usize> fmt::Debug for UInt<BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const BYTES: usize> fmt::Display for Int<BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_negative() {
+            return fmt_magnitude(&self.0, false, f);
+        }
+
+        // Two's complement negation
+        let mut magnitude = self.0;
+        let mut carry = true;
+        for byte in magnitude.iter_mut() {
+            *byte = !*byte;
+            if carry {
+                let (sum, overflow) = byte.overflowing_add(1);
+                *byte = sum;
+                carry = overflow;
+            }
+        }
+
+        fmt_magnitude(&magnitude, true, f)
+    }
+}
+
+impl<const BYTES: usize> fmt::Debug for Int<BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+// Implements conversions between a fixed width
+// integer and the native integer types
+macro_rules! impl_conversions {
+    (
+        $ty:ty,
+        from: [$($from:ty),*],
+        try_from: [$($try_from:ty),*],
+        into: [$($into:ty),*],
+        try_into: [$($try_into:ty),*]
+    ) => {
+        $(
+            impl From<$from> for $ty {
+                #[inline]
+                fn from(value: $from) -> Self {
+                    match <$ty>::try_from(value as i128) {
+                        Ok(v) => v,
+                        Err(_) => unreachable!(),
+                    }
+                }
+            }
+        )*
+
+        $(
+            impl TryFrom<$try_from> for $ty {
+                type Error = PodError;
+
+                #[inline]
+                #[allow(unused_comparisons)]
+                fn try_from(value: $try_from) -> Result<Self, Self::Error> {
+                    if value < 0 as $try_from {
+                        <$ty>::try_from(value as i128)
+                    } else {
+                        <$ty>::try_from(value as u128)
+                    }
+                }
+            }
+        )*
+
+        $(
+            impl From<$ty> for $into {
+                #[inline]
+                fn from(value: $ty) -> Self {
+                    match value.to_native::<$into>() {
+                        Ok(v) => v,
+                        Err(_) => unreachable!(),
+                    }
+                }
+            }
+        )*
+
+        $(
+            impl TryFrom<$ty> for $try_into {
+                type Error = PodError;
+
+                #[inline]
+                fn try_from(value: $ty) -> Result<Self, Self::Error> {
+                    value.to_native::<$try_into>()
+                }
+            }
+        )*
+    };
+}
+
+// Shared native conversion used by the `From`/`TryFrom` impls
+trait ToNative {
+    fn to_native<N: TryFrom<u128> + TryFrom<i128>>(self) -> Result<N, PodError>;
+}
+
+impl<const BYTES: usize> ToNative for UInt<BYTES> {
+    #[inline]
+    fn to_native<N: TryFrom<u128> + TryFrom<i128>>(self) -> Result<N, PodError> {
+        N::try_from(self.try_to_u128()?).map_err(|_| PodError::OutOfRange)
+    }
+}
+
+impl<const BYTES: usize> ToNative for Int<BYTES> {
+    #[inline]
+    fn to_native<N: TryFrom<u128> + TryFrom<i128>>(self) -> Result<N, PodError> {
+        if self.is_negative() {
+            N::try_from(self.try_to_i128()?).map_err(|_| PodError::OutOfRange)
+        } else {
+            N::try_from(self.try_to_u128()?).map_err(|_| PodError::OutOfRange)
+        }
+    }
+}
+
+impl<const BYTES: usize> TryFrom<u128> for UInt<BYTES> {
+    type Error = PodError;
+
+    #[inline]
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        Self::try_from_u128(value)
+    }
+}
+
+impl<const BYTES: usize> TryFrom<i128> for UInt<BYTES> {
+    type Error = PodError;
+
+    #[inline]
+    fn try_from(value: i128) -> Result<Self, Self::Error> {
+        if value < 0 {
+            return Err(PodError::OutOfRange);
+        }
+
+        Self::try_from_u128(value as u128)
+    }
+}
+
+impl<const BYTES: usize> TryFrom<u128> for Int<BYTES> {
+    type Error = PodError;
+
+    #[inline]
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        Self::try_from_u128(value)
+    }
+}
+
+impl<const BYTES: usize> TryFrom<i128> for Int<BYTES> {
+    type Error = PodError;
+
+    #[inline]
+    fn try_from(value: i128) -> Result<Self, Self::Error> {
+        Self::try_from_i128(value)
+    }
+}
+
+impl_conversions!(
+    U24,
+    from: [u8, u16],
+    try_from: [u32, u64, i8, i16, i32, i64],
+    into: [u32, u64, u128, i32, i64, i128],
+    try_into: [u8, u16, i8, i16]
+);
+impl_conversions!(
+    I24,
+    from: [u8, u16, i8, i16],
+    try_from: [u32, u64, i32, i64],
+    into: [i32, i64, i128],
+    try_into: [u8, u16, u32, u64, u128, i8, i16]
+);
+
+impl_conversions!(
+    U40,
+    from: [u8, u16, u32],
+    try_from: [u64, i8, i16, i32, i64],
+    into: [u64, u128, i64, i128],
+    try_into: [u8, u16, u32, i8, i16, i32]
+);
+impl_conversions!(
+    I40,
+    from: [u8, u16, u32, i8, i16, i32],
+    try_from: [u64, i64],
+    into: [i64, i128],
+    try_into: [u8, u16, u32, u64, u128, i8, i16, i32]
+);
+
+impl_conversions!(
+    U48,
+    from: [u8, u16, u32],
+    try_from: [u64, i8, i16, i32, i64],
+    into: [u64, u128, i64, i128],
+    try_into: [u8, u16, u32, i8, i16, i32]
+);
+impl_conversions!(
+    I48,
+    from: [u8, u16, u32, i8, i16, i32],
+    try_from: [u64, i64],
+    into: [i64, i128],
+    try_into: [u8, u16, u32, u64, u128, i8, i16, i32]
+);
+
+impl_conversions!(
+    U56,
+    from: [u8, u16, u32],
+    try_from: [u64, i8, i16, i32, i64],
+    into: [u64, u128, i64, i128],
+    try_into: [u8, u16, u32, i8, i16, i32]
+);
+impl_conversions!(
+    I56,
+    from: [u8, u16, u32, i8, i16, i32],
+    try_from: [u64, i64],
+    into: [i64, i128],
+    try_into: [u8, u16, u32, u64, u128, i8, i16, i32]
+);
+
+impl_conversions!(
+    U256,
+    from: [u8, u16, u32, u64],
+    try_from: [i8, i16, i32, i64],
+    into: [],
+    try_into: [u8, u16, u32, u64, u128, i8, i16, i32, i64, i128]
+);
+impl_conversions!(
+    I256,
+    from: [u8, u16, u32, u64, i8, i16, i32, i64],
+    try_from: [],
+    into: [],
+    try_into: [u8, u16, u32, u64, u128, i8, i16, i32, i64, i128]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pod_u24() {
+        // Read success
+        let buffer_read = [1u8, 2u8, 3u8];
+
+        assert_eq!(<U24 as Pod>::from_le_bytes(&buffer_read).map(u32::from), Ok(0x030201));
+        assert_eq!(<U24 as Pod>::from_be_bytes(&buffer_read).map(u32::from), Ok(0x010203));
+
+        // Read fail
+        let buffer_read_fail = [0u8; <U24 as Pod>::SIZE - 1];
+
+        assert_eq!(<U24 as Pod>::from_le_bytes(&buffer_read_fail), Err(PodError::OutOfSpace));
+        assert_eq!(<U24 as Pod>::from_be_bytes(&buffer_read_fail), Err(PodError::OutOfSpace));
+
+        // Write success
+        let mut buffer_write = [0u8; <U24 as Pod>::SIZE * 2];
+        let value = U24::try_from(0x030201u32).unwrap();
+
+        assert_eq!(value.to_le_bytes(&mut buffer_write), Ok(<U24 as Pod>::SIZE));
+        assert_eq!(
+            value.to_be_bytes(&mut buffer_write[<U24 as Pod>::SIZE..]),
+            Ok(<U24 as Pod>::SIZE)
+        );
+        assert_eq!(buffer_write, [1, 2, 3, 3, 2, 1]);
+
+        // Write fail
+        let mut buffer_write_fail = [0u8; <U24 as Pod>::SIZE - 1];
+
+        assert_eq!(value.to_le_bytes(&mut buffer_write_fail), Err(PodError::OutOfSpace));
+        assert_eq!(value.to_be_bytes(&mut buffer_write_fail), Err(PodError::OutOfSpace));
+    }
+
+    #[test]
+    fn pod_i24_sign_extension() {
+        let value = <I24 as Pod>::from_le_bytes(&[0xfe, 0xff, 0xff]).unwrap();
+
+        assert!(value.is_negative());
+        assert_eq!(i32::from(value), -2);
+        assert_eq!(i64::from(value), -2);
+        assert_eq!(i8::try_from(value), Ok(-2));
+        assert_eq!(u32::try_from(value), Err(PodError::OutOfRange));
+
+        let value = <I24 as Pod>::from_be_bytes(&[0x7f, 0xff, 0xff]).unwrap();
+
+        assert_eq!(value, I24::MAX);
+        assert_eq!(i32::from(value), 0x7fffff);
+        assert_eq!(i32::from(I24::MIN), -0x800000);
+    }
+
+    #[test]
+    fn overflow_checks() {
+        assert_eq!(U24::try_from(0xffffffu32).map(u32::from), Ok(0xffffff));
+        assert_eq!(U24::try_from(0x1000000u32), Err(PodError::OutOfRange));
+        assert_eq!(U24::try_from(-1i32), Err(PodError::OutOfRange));
+        assert_eq!(I24::try_from(0x7fffffi32).map(i32::from), Ok(0x7fffff));
+        assert_eq!(I24::try_from(0x800000i32), Err(PodError::OutOfRange));
+        assert_eq!(I24::try_from(-0x800000i32).map(i32::from), Ok(-0x800000));
+        assert_eq!(I24::try_from(-0x800001i32), Err(PodError::OutOfRange));
+        assert_eq!(U48::try_from(u64::MAX), Err(PodError::OutOfRange));
+        assert_eq!(U48::try_from(0xffff_ffff_ffffu64).map(u64::from), Ok(0xffff_ffff_ffff));
+        assert_eq!(u16::try_from(U24::from(0xffffu16)), Ok(0xffff));
+        assert_eq!(u8::try_from(U24::from(0x100u16)), Err(PodError::OutOfRange));
+        assert_eq!(I56::try_from(i64::MIN), Err(PodError::OutOfRange));
+        assert_eq!(i64::from(I56::from(i32::MIN)), i32::MIN as i64);
+    }
+
+    #[test]
+    fn wide_integers() {
+        assert_eq!(U256::try_from(u128::MAX).and_then(u128::try_from), Ok(u128::MAX));
+        assert_eq!(I256::try_from(i128::MIN).and_then(i128::try_from), Ok(i128::MIN));
+        assert_eq!(I256::try_from(u128::MAX).and_then(u128::try_from), Ok(u128::MAX));
+        assert_eq!(u128::try_from(U256::MAX), Err(PodError::OutOfRange));
+        assert_eq!(i128::try_from(I256::MIN), Err(PodError::OutOfRange));
+        assert_eq!(i128::try_from(I256::from(-1i8)), Ok(-1));
+
+        let mut buffer = [0u8; <U256 as Pod>::SIZE];
+        U256::from(1u8).to_be_bytes(&mut buffer).unwrap();
+
+        assert_eq!(buffer[31], 1);
+        assert_eq!(<U256 as Pod>::from_be_bytes(&buffer), Ok(U256::from(1u8)));
+    }
+
+    #[test]
+    fn ordering_and_formatting() {
+        assert!(U24::from(0x100u16) > U24::from(0xffu16));
+        assert!(I24::from(-1i8) < I24::from(0i8));
+        assert!(I24::MIN < I24::from(-1i8));
+        assert!(I24::from(2i8) > I24::from(1i8));
+
+        assert_eq!(format!("{}", U24::MAX), "16777215");
+        assert_eq!(format!("{}", I24::MIN), "-8388608");
+        assert_eq!(format!("{:?}", I24::from(0i8)), "0");
+        assert_eq!(
+            format!("{}", U256::MAX),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(U24::zeroed(), U24::MIN);
+    }
+}
@@ -0,0 +1,882 @@
+use std::fmt;
+
+use crate::pod::{Pod, PodError};
+use crate::zeroable::Zeroable;
+
+/// IBM System/360 single precision hexadecimal float
+///
+/// A sign bit, a 7 bit base 16 exponent biased by 64 and a
+/// 24 bit fraction. Converting to `f64` is exact. Converting
+/// from `f64` rounds to nearest, ties to even, flushes values
+/// below the smallest normalized value to zero and fails with
+/// [`PodError::OutOfRange`] for values that are too large,
+/// infinite or NaN.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IbmF32(u32);
+
+/// IBM System/360 double precision hexadecimal float
+///
+/// A sign bit, a 7 bit base 16 exponent biased by 64 and a
+/// 56 bit fraction. Converting to `f64` rounds to nearest, ties
+/// to even. Converting from `f64` is exact except that values
+/// below the smallest normalized value are flushed to zero, and
+/// fails with [`PodError::OutOfRange`] for values that are too
+/// large, infinite or NaN.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IbmF64(u64);
+
+/// VAX F_floating single precision float
+///
+/// A sign bit, an 8 bit exponent biased by 128 and a 23 bit
+/// fraction with a hidden bit. Converting to `f64` is exact,
+/// except for reserved operands which fail with
+/// [`PodError::OutOfRange`]. Converting from `f64` rounds to
+/// nearest, ties to even, flushes values too small to represent
+/// to zero and fails with [`PodError::OutOfRange`] for values
+/// that are too large, infinite or NaN.
+///
+/// `from_le_bytes`/`to_le_bytes` use VAX memory order, 16 bit
+/// little endian words with the most significant word first,
+/// while `from_be_bytes`/`to_be_bytes` use plain big endian order.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct VaxF(u32);
+
+/// VAX D_floating double precision float
+///
+/// A sign bit, an 8 bit exponent biased by 128 and a 55 bit
+/// fraction with a hidden bit. Converting to `f64` rounds to
+/// nearest, ties to even, and fails with [`PodError::OutOfRange`]
+/// for reserved operands. Converting from `f64` is exact except
+/// that values outside of the exponent range either flush to
+/// zero or fail with [`PodError::OutOfRange`].
+///
+/// Byte order is handled like [`VaxF`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct VaxD(u64);
+
+/// VAX G_floating double precision float
+///
+/// A sign bit, an 11 bit exponent biased by 1024 and a 52 bit
+/// fraction with a hidden bit. Converting to `f64` is exact
+/// except for values in the `f64` subnormal range, which round
+/// to nearest, ties to even, and fails with [`PodError::OutOfRange`]
+/// for reserved operands. Converting from `f64` rounds to nearest,
+/// ties to even, and fails with [`PodError::OutOfRange`] for values
+/// that are too large, infinite or NaN. Values too small to
+/// represent are flushed to zero.
+///
+/// Byte order is handled like [`VaxF`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct VaxG(u64);
+
+/// x87 80 bit extended precision float
+///
+/// A sign bit, a 15 bit exponent biased by 16383 and a 64 bit
+/// significand with an explicit integer bit. Converting from
+/// `f64` is exact. Converting to `f64` rounds to nearest, ties to
+/// even, and fails with [`PodError::OutOfRange`] for finite values
+/// that are too large. Unnormals, which x87 hardware rejects,
+/// convert to NaN, while pseudo denormals are read with the
+/// smallest exponent, as x87 hardware reads them.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct F80 {
+    sign_exponent: u16,
+    significand: u64,
+}
+
+// Split a finite non zero f64 into sign and a significand with
+// bit 52 set, so that the magnitude is significand * 2^exponent
+fn decompose(value: f64) -> (bool, u64, i32) {
+    let bits = value.to_bits();
+    let sign = bits >> 63 != 0;
+    let exp = ((bits >> 52) & 0x7ff) as i32;
+    let frac = bits & 0x000f_ffff_ffff_ffff;
+
+    if exp == 0 {
+        // Normalize subnormal
+        let shift = frac.leading_zeros() - 11;
+        return (sign, frac << shift, -1074 - shift as i32);
+    }
+
+    (sign, frac | (1 << 52), exp - 1075)
+}
+
+// Shift right rounding to nearest, ties to even
+fn shift_round(value: u64, shift: u32) -> u64 {
+    if shift == 0 {
+        return value;
+    }
+    if shift > 64 {
+        return 0;
+    }
+
+    let value = value as u128;
+    let quotient = value >> shift;
+    let remainder = value & ((1u128 << shift) - 1);
+    let half = 1u128 << (shift - 1);
+
+    if remainder > half || (remainder == half && quotient & 1 != 0) {
+        (quotient + 1) as u64
+    } else {
+        quotient as u64
+    }
+}
+
+// Build the f64 nearest to significand * 2^exponent, ties to even,
+// failing on overflow
+fn compose(sign: bool, significand: u64, exponent: i32) -> Result<f64, PodError> {
+    let sign_bit = (sign as u64) << 63;
+
+    if significand == 0 {
+        return Ok(f64::from_bits(sign_bit));
+    }
+
+    // Normalize so bit 63 is set
+    let shift = significand.leading_zeros();
+    let significand = significand << shift;
+    let mut unbiased = exponent - shift as i32 + 63;
+
+    if unbiased >= -1022 {
+        let mut rounded = shift_round(significand, 11);
+        if rounded == 1 << 53 {
+            rounded >>= 1;
+            unbiased += 1;
+        }
+        if unbiased > 1023 {
+            return Err(PodError::OutOfRange);
+        }
+
+        let biased = (unbiased + 1023) as u64;
+        return Ok(f64::from_bits(sign_bit | (biased << 52) | (rounded & 0x000f_ffff_ffff_ffff)));
+    }
+
+    // Subnormal, a carry into bit 52 yields the smallest normal
+    let rounded = shift_round(significand, (11 - 1022 - unbiased) as u32);
+    Ok(f64::from_bits(sign_bit | rounded))
+}
+
+// Encode as an IBM hexadecimal float with `frac_bits` fraction bits
+fn ibm_encode(value: f64, frac_bits: u32) -> Result<u64, PodError> {
+    if !value.is_finite() {
+        return Err(PodError::OutOfRange);
+    }
+    if value == 0.0 {
+        return Ok(0);
+    }
+
+    let (sign, significand, exponent) = decompose(value);
+
+    // The magnitude is below 2^bits and at least 2^(bits - 1),
+    // pick the hex exponent so the fraction is at least 1/16
+    let bits = exponent + 53;
+    let mut hex_exponent = bits.div_euclid(4) + (bits.rem_euclid(4) != 0) as i32;
+
+    let shift = exponent - 4 * hex_exponent + frac_bits as i32;
+    let mut fraction = if shift >= 0 {
+        significand << shift
+    } else {
+        shift_round(significand, (-shift) as u32)
+    };
+    if fraction == 1 << frac_bits {
+        fraction >>= 4;
+        hex_exponent += 1;
+    }
+
+    let biased = hex_exponent + 64;
+    if biased > 127 {
+        return Err(PodError::OutOfRange);
+    }
+    if biased < 0 {
+        return Ok(0);
+    }
+
+    Ok(((sign as u64) << (frac_bits + 7)) | ((biased as u64) << frac_bits) | fraction)
+}
+
+// Decode an IBM hexadecimal float with `frac_bits` fraction bits
+fn ibm_decode(bits: u64, frac_bits: u32) -> Result<f64, PodError> {
+    let sign = (bits >> (frac_bits + 7)) & 1 != 0;
+    let biased = ((bits >> frac_bits) & 0x7f) as i32;
+    let fraction = bits & ((1 << frac_bits) - 1);
+
+    compose(sign, fraction, 4 * (biased - 64) - frac_bits as i32)
+}
+
+// Encode as a VAX float with `man_bits` mantissa bits,
+// including the hidden bit, and an exponent biased by `bias`
+fn vax_encode(value: f64, man_bits: u32, bias: i32, max_biased: i32) -> Result<u64, PodError> {
+    if !value.is_finite() {
+        return Err(PodError::OutOfRange);
+    }
+    if value == 0.0 {
+        return Ok(0);
+    }
+
+    let (sign, significand, exponent) = decompose(value);
+
+    // The mantissa is in [0.5, 1)
+    let mut binary_exponent = exponent + 53;
+
+    let shift = man_bits as i32 - 53;
+    let mut mantissa = if shift >= 0 {
+        significand << shift
+    } else {
+        shift_round(significand, (-shift) as u32)
+    };
+    if mantissa == 1 << man_bits {
+        mantissa >>= 1;
+        binary_exponent += 1;
+    }
+
+    let biased = binary_exponent + bias;
+    if biased > max_biased {
+        return Err(PodError::OutOfRange);
+    }
+    if biased < 1 {
+        return Ok(0);
+    }
+
+    let frac_bits = man_bits - 1;
+    let sign_shift = frac_bits + (max_biased as u64 + 1).trailing_zeros();
+
+    Ok(((sign as u64) << sign_shift)
+        | ((biased as u64) << frac_bits)
+        | (mantissa & ((1 << frac_bits) - 1)))
+}
+
+// Decode a VAX float with `man_bits` mantissa bits,
+// including the hidden bit, and an exponent biased by `bias`
+fn vax_decode(bits: u64, man_bits: u32, bias: i32, max_biased: i32) -> Result<f64, PodError> {
+    let frac_bits = man_bits - 1;
+    let sign_shift = frac_bits + (max_biased as u64 + 1).trailing_zeros();
+
+    let sign = (bits >> sign_shift) & 1 != 0;
+    let biased = ((bits >> frac_bits) & max_biased as u64) as i32;
+
+    if biased == 0 {
+        // Sign bit with a zero exponent is a reserved operand
+        if sign {
+            return Err(PodError::OutOfRange);
+        }
+        return Ok(0.0);
+    }
+
+    let mantissa = (bits & ((1 << frac_bits) - 1)) | (1 << frac_bits);
+    compose(sign, mantissa, biased - bias - man_bits as i32)
+}
+
+// Read 16 bit little endian words, most significant word first
+fn read_vax_words(buffer: &[u8], words: usize) -> Result<u64, PodError> {
+    if buffer.len() < words * 2 {
+        return Err(PodError::OutOfSpace);
+    }
+
+    let mut bits = 0u64;
+    for i in 0..words {
+        bits = (bits << 16) | u16::from_le_bytes([buffer[i * 2], buffer[i * 2 + 1]]) as u64;
+    }
+
+    Ok(bits)
+}
+
+// Write 16 bit little endian words, most significant word first
+fn write_vax_words(bits: u64, buffer: &mut [u8], words: usize) -> Result<usize, PodError> {
+    if buffer.len() < words * 2 {
+        return Err(PodError::OutOfSpace);
+    }
+
+    for i in 0..words {
+        let word = (bits >> ((words - 1 - i) * 16)) as u16;
+        buffer[i * 2..i * 2 + 2].copy_from_slice(&word.to_le_bytes());
+    }
+
+    Ok(words * 2)
+}
+
+impl IbmF32 {
+    /// Create from raw bits
+    #[inline]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Get raw bits
+    #[inline]
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// Create from an `f64`
+    pub fn from_f64(value: f64) -> Result<Self, PodError> {
+        Ok(Self(ibm_encode(value, 24)? as u32))
+    }
+
+    /// Convert to an `f64`
+    pub fn to_f64(self) -> Result<f64, PodError> {
+        ibm_decode(self.0 as u64, 24)
+    }
+}
+
+impl IbmF64 {
+    /// Create from raw bits
+    #[inline]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    /// Get raw bits
+    #[inline]
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    /// Create from an `f64`
+    pub fn from_f64(value: f64) -> Result<Self, PodError> {
+        Ok(Self(ibm_encode(value, 56)?))
+    }
+
+    /// Convert to an `f64`
+    pub fn to_f64(self) -> Result<f64, PodError> {
+        ibm_decode(self.0, 56)
+    }
+}
+
+impl VaxF {
+    /// Create from raw bits, with the sign in bit 31
+    #[inline]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Get raw bits, with the sign in bit 31
+    #[inline]
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// Create from an `f64`
+    pub fn from_f64(value: f64) -> Result<Self, PodError> {
+        Ok(Self(vax_encode(value, 24, 128, 0xff)? as u32))
+    }
+
+    /// Convert to an `f64`
+    pub fn to_f64(self) -> Result<f64, PodError> {
+        vax_decode(self.0 as u64, 24, 128, 0xff)
+    }
+}
+
+impl VaxD {
+    /// Create from raw bits, with the sign in bit 63
+    #[inline]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    /// Get raw bits, with the sign in bit 63
+    #[inline]
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    /// Create from an `f64`
+    pub fn from_f64(value: f64) -> Result<Self, PodError> {
+        Ok(Self(vax_encode(value, 56, 128, 0xff)?))
+    }
+
+    /// Convert to an `f64`
+    pub fn to_f64(self) -> Result<f64, PodError> {
+        vax_decode(self.0, 56, 128, 0xff)
+    }
+}
+
+impl VaxG {
+    /// Create from raw bits, with the sign in bit 63
+    #[inline]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    /// Get raw bits, with the sign in bit 63
+    #[inline]
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    /// Create from an `f64`
+    pub fn from_f64(value: f64) -> Result<Self, PodError> {
+        Ok(Self(vax_encode(value, 53, 1024, 0x7ff)?))
+    }
+
+    /// Convert to an `f64`
+    pub fn to_f64(self) -> Result<f64, PodError> {
+        vax_decode(self.0, 53, 1024, 0x7ff)
+    }
+}
+
+impl F80 {
+    /// Create from the sign and exponent word and the significand
+    #[inline]
+    pub const fn from_parts(sign_exponent: u16, significand: u64) -> Self {
+        Self { sign_exponent, significand }
+    }
+
+    /// Get the sign and exponent word and the significand
+    #[inline]
+    pub const fn to_parts(self) -> (u16, u64) {
+        (self.sign_exponent, self.significand)
+    }
+
+    /// Create from an `f64`
+    pub fn from_f64(value: f64) -> Result<Self, PodError> {
+        let sign = (value.is_sign_negative() as u16) << 15;
+
+        if value == 0.0 {
+            return Ok(Self::from_parts(sign, 0));
+        }
+        if value.is_infinite() {
+            return Ok(Self::from_parts(sign | 0x7fff, 1 << 63));
+        }
+        if value.is_nan() {
+            let payload = (value.to_bits() & 0x000f_ffff_ffff_ffff) << 11;
+            return Ok(Self::from_parts(sign | 0x7fff, (0b11 << 62) | payload));
+        }
+
+        let (_, significand, exponent) = decompose(value);
+        let biased = (exponent + 52 + 16383) as u16;
+
+        Ok(Self::from_parts(sign | biased, significand << 11))
+    }
+
+    /// Convert to an `f64`
+    pub fn to_f64(self) -> Result<f64, PodError> {
+        let sign = self.sign_exponent & 0x8000 != 0;
+        let biased = (self.sign_exponent & 0x7fff) as i32;
+        let integer_bit = self.significand >> 63 != 0;
+
+        if biased == 0x7fff {
+            let value = if self.significand << 1 == 0 && integer_bit {
+                f64::INFINITY
+            } else {
+                f64::NAN
+            };
+            return Ok(if sign { -value } else { value });
+        }
+
+        // Unnormals
+        if biased != 0 && !integer_bit {
+            return Ok(f64::NAN);
+        }
+
+        // Denormals and pseudo denormals use the same
+        // scale as the smallest exponent
+        let exponent = biased.max(1) - 16383 - 63;
+        compose(sign, self.significand, exponent)
+    }
+}
+
+impl Zeroable for IbmF32 {
+    #[inline]
+    fn zeroed() -> Self {
+        Self(0)
+    }
+}
+
+impl Zeroable for IbmF64 {
+    #[inline]
+    fn zeroed() -> Self {
+        Self(0)
+    }
+}
+
+impl Zeroable for VaxF {
+    #[inline]
+    fn zeroed() -> Self {
+        Self(0)
+    }
+}
+
+impl Zeroable for VaxD {
+    #[inline]
+    fn zeroed() -> Self {
+        Self(0)
+    }
+}
+
+impl Zeroable for VaxG {
+    #[inline]
+    fn zeroed() -> Self {
+        Self(0)
+    }
+}
+
+impl Zeroable for F80 {
+    #[inline]
+    fn zeroed() -> Self {
+        Self::from_parts(0, 0)
+    }
+}
+
+impl Pod for IbmF32 {
+    const SIZE: usize = 4;
+
+    #[inline]
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(<u32 as Pod>::from_le_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(<u32 as Pod>::from_be_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        <u32 as Pod>::to_le_bytes(&self.0, buffer)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        <u32 as Pod>::to_be_bytes(&self.0, buffer)
+    }
+}
+
+impl Pod for IbmF64 {
+    const SIZE: usize = 8;
+
+    #[inline]
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(<u64 as Pod>::from_le_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(<u64 as Pod>::from_be_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        <u64 as Pod>::to_le_bytes(&self.0, buffer)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        <u64 as Pod>::to_be_bytes(&self.0, buffer)
+    }
+}
+
+impl Pod for VaxF {
+    const SIZE: usize = 4;
+
+    #[inline]
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(read_vax_words(buffer, 2)? as u32))
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(<u32 as Pod>::from_be_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        write_vax_words(self.0 as u64, buffer, 2)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        <u32 as Pod>::to_be_bytes(&self.0, buffer)
+    }
+}
+
+impl Pod for VaxD {
+    const SIZE: usize = 8;
+
+    #[inline]
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(read_vax_words(buffer, 4)?))
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(<u64 as Pod>::from_be_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        write_vax_words(self.0, buffer, 4)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        <u64 as Pod>::to_be_bytes(&self.0, buffer)
+    }
+}
+
+impl Pod for VaxG {
+    const SIZE: usize = 8;
+
+    #[inline]
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(read_vax_words(buffer, 4)?))
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Ok(Self(<u64 as Pod>::from_be_bytes(buffer)?))
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        write_vax_words(self.0, buffer, 4)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        <u64 as Pod>::to_be_bytes(&self.0, buffer)
+    }
+}
+
+impl Pod for F80 {
+    const SIZE: usize = 10;
+
+    #[inline]
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        Ok(Self::from_parts(
+            <u16 as Pod>::from_le_bytes(&buffer[8..])?,
+            <u64 as Pod>::from_le_bytes(buffer)?,
+        ))
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        Ok(Self::from_parts(
+            <u16 as Pod>::from_be_bytes(buffer)?,
+            <u64 as Pod>::from_be_bytes(&buffer[2..])?,
+        ))
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        <u64 as Pod>::to_le_bytes(&self.significand, buffer)?;
+        <u16 as Pod>::to_le_bytes(&self.sign_exponent, &mut buffer[8..])?;
+
+        Ok(Self::SIZE)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        <u16 as Pod>::to_be_bytes(&self.sign_exponent, buffer)?;
+        <u64 as Pod>::to_be_bytes(&self.significand, &mut buffer[2..])?;
+
+        Ok(Self::SIZE)
+    }
+}
+
+// Implements conversions and formatting through f64
+macro_rules! impl_f64_conversions {
+    ($($ty:ident),*) => {
+        $(
+            impl TryFrom<f64> for $ty {
+                type Error = PodError;
+
+                #[inline]
+                fn try_from(value: f64) -> Result<Self, Self::Error> {
+                    Self::from_f64(value)
+                }
+            }
+
+            impl TryFrom<$ty> for f64 {
+                type Error = PodError;
+
+                #[inline]
+                fn try_from(value: $ty) -> Result<Self, Self::Error> {
+                    value.to_f64()
+                }
+            }
+
+            impl fmt::Debug for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    match self.to_f64() {
+                        Ok(v) => fmt::Debug::fmt(&v, f),
+                        Err(_) => write!(f, "{}(reserved)", stringify!($ty)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_f64_conversions!(IbmF32, IbmF64, VaxF, VaxD, VaxG, F80);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pod_ibm_f32() {
+        // Read success
+        let buffer_read = [0xc2u8, 0x76u8, 0xa0u8, 0x00u8];
+        let value = <IbmF32 as Pod>::from_be_bytes(&buffer_read).unwrap();
+
+        assert_eq!(value.to_f64(), Ok(-118.625));
+        assert_eq!(<IbmF32 as Pod>::from_le_bytes(&buffer_read).map(IbmF32::to_bits), Ok(0x00a076c2));
+
+        // Read fail
+        let buffer_read_fail = [0u8; <IbmF32 as Pod>::SIZE - 1];
+
+        assert_eq!(<IbmF32 as Pod>::from_le_bytes(&buffer_read_fail), Err(PodError::OutOfSpace));
+        assert_eq!(<IbmF32 as Pod>::from_be_bytes(&buffer_read_fail), Err(PodError::OutOfSpace));
+
+        // Write success
+        let mut buffer_write = [0u8; <IbmF32 as Pod>::SIZE];
+
+        assert_eq!(
+            IbmF32::from_f64(-118.625).unwrap().to_be_bytes(&mut buffer_write),
+            Ok(<IbmF32 as Pod>::SIZE)
+        );
+        assert_eq!(buffer_write, buffer_read);
+
+        // Write fail
+        let mut buffer_write_fail = [0u8; <IbmF32 as Pod>::SIZE - 1];
+
+        assert_eq!(value.to_le_bytes(&mut buffer_write_fail), Err(PodError::OutOfSpace));
+        assert_eq!(value.to_be_bytes(&mut buffer_write_fail), Err(PodError::OutOfSpace));
+    }
+
+    #[test]
+    fn ibm_conversions() {
+        assert_eq!(IbmF32::from_f64(1.0).map(IbmF32::to_bits), Ok(0x4110_0000));
+        assert_eq!(IbmF32::from_f64(0.0).map(IbmF32::to_bits), Ok(0));
+        assert_eq!(IbmF32::from_f64(0.1).map(IbmF32::to_bits), Ok(0x4019_999a));
+        assert_eq!(IbmF32::from_f64(1e76), Err(PodError::OutOfRange));
+        assert_eq!(IbmF32::from_f64(f64::NAN), Err(PodError::OutOfRange));
+        assert_eq!(IbmF32::from_f64(f64::INFINITY), Err(PodError::OutOfRange));
+        assert_eq!(IbmF32::from_f64(1e-80).map(IbmF32::to_bits), Ok(0));
+        assert_eq!(IbmF32::from_bits(0x7fff_ffff).to_f64(), Ok((1.0 - 16f64.powi(-6)) * 16f64.powi(63)));
+
+        // Rounding carries into the next hex digit
+        assert_eq!(IbmF32::from_f64(1.0 - 2f64.powi(-30)).map(IbmF32::to_bits), Ok(0x4110_0000));
+
+        assert_eq!(IbmF64::from_f64(1.0).map(IbmF64::to_bits), Ok(0x4110_0000_0000_0000));
+        assert_eq!(IbmF64::from_f64(0.1).and_then(IbmF64::to_f64), Ok(0.1));
+        assert_eq!(IbmF64::from_f64(-123456.789e10).and_then(IbmF64::to_f64), Ok(-123456.789e10));
+        assert_eq!(f64::try_from(IbmF64::from_bits(0xc276_a000_0000_0000)), Ok(-118.625));
+    }
+
+    #[test]
+    fn pod_vax() {
+        // VAX memory order
+        let buffer_read = [0x80u8, 0x40u8, 0x00u8, 0x00u8];
+
+        assert_eq!(<VaxF as Pod>::from_le_bytes(&buffer_read).and_then(VaxF::to_f64), Ok(1.0));
+        assert_eq!(<VaxF as Pod>::from_be_bytes(&[0x40, 0x80, 0, 0]).and_then(VaxF::to_f64), Ok(1.0));
+
+        let mut buffer_write = [0u8; <VaxG as Pod>::SIZE];
+
+        assert_eq!(
+            VaxG::from_f64(1.0).unwrap().to_le_bytes(&mut buffer_write),
+            Ok(<VaxG as Pod>::SIZE)
+        );
+        assert_eq!(buffer_write, [0x10, 0x40, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(<VaxG as Pod>::from_le_bytes(&buffer_write).and_then(VaxG::to_f64), Ok(1.0));
+
+        let mut buffer_write = [0u8; <VaxD as Pod>::SIZE];
+        let value = VaxD::from_f64(-0.75).unwrap();
+
+        value.to_le_bytes(&mut buffer_write).unwrap();
+        assert_eq!(<VaxD as Pod>::from_le_bytes(&buffer_write), Ok(value));
+        value.to_be_bytes(&mut buffer_write).unwrap();
+        assert_eq!(<VaxD as Pod>::from_be_bytes(&buffer_write), Ok(value));
+
+        // Fail
+        assert_eq!(<VaxD as Pod>::from_le_bytes(&buffer_write[1..]), Err(PodError::OutOfSpace));
+        assert_eq!(value.to_le_bytes(&mut buffer_write[1..]), Err(PodError::OutOfSpace));
+    }
+
+    #[test]
+    fn vax_conversions() {
+        assert_eq!(VaxF::from_f64(1.0).map(VaxF::to_bits), Ok(0x4080_0000));
+        assert_eq!(VaxF::from_f64(-1.0).map(VaxF::to_bits), Ok(0xc080_0000));
+        assert_eq!(VaxF::from_f64(-0.0).map(VaxF::to_bits), Ok(0));
+        assert_eq!(VaxF::from_f64(0.1).and_then(VaxF::to_f64), Ok(0.1f32 as f64));
+        assert_eq!(VaxF::from_f64(2f64.powi(127)), Err(PodError::OutOfRange));
+        assert_eq!(VaxF::from_f64(2f64.powi(126)).and_then(VaxF::to_f64), Ok(2f64.powi(126)));
+        assert_eq!(VaxF::from_f64(2f64.powi(-130)).map(VaxF::to_bits), Ok(0));
+        assert_eq!(VaxF::from_bits(0x8000_0000).to_f64(), Err(PodError::OutOfRange));
+
+        assert_eq!(VaxD::from_f64(1.0).map(VaxD::to_bits), Ok(0x4080_0000_0000_0000));
+        assert_eq!(VaxD::from_f64(0.1).and_then(VaxD::to_f64), Ok(0.1));
+        assert_eq!(VaxD::from_f64(1e39), Err(PodError::OutOfRange));
+
+        assert_eq!(VaxG::from_f64(1.0).map(VaxG::to_bits), Ok(0x4010_0000_0000_0000));
+        assert_eq!(VaxG::from_f64(0.1).and_then(VaxG::to_f64), Ok(0.1));
+        assert_eq!(VaxG::from_f64(f64::MAX), Err(PodError::OutOfRange));
+        assert_eq!(VaxG::from_f64(2f64.powi(1022)).and_then(VaxG::to_f64), Ok(2f64.powi(1022)));
+        assert_eq!(VaxG::from_f64(f64::MIN_POSITIVE).and_then(VaxG::to_f64), Ok(f64::MIN_POSITIVE));
+        assert_eq!(VaxG::from_f64(f64::from_bits(1)).map(VaxG::to_bits), Ok(0));
+    }
+
+    #[test]
+    fn pod_f80() {
+        let buffer_read = [0u8, 0, 0, 0, 0, 0, 0, 0x80, 0xff, 0x3f];
+        let value = <F80 as Pod>::from_le_bytes(&buffer_read).unwrap();
+
+        assert_eq!(value.to_parts(), (0x3fff, 0x8000_0000_0000_0000));
+        assert_eq!(value.to_f64(), Ok(1.0));
+
+        let mut buffer_write = [0u8; <F80 as Pod>::SIZE];
+
+        assert_eq!(value.to_le_bytes(&mut buffer_write), Ok(<F80 as Pod>::SIZE));
+        assert_eq!(buffer_write, buffer_read);
+        assert_eq!(value.to_be_bytes(&mut buffer_write), Ok(<F80 as Pod>::SIZE));
+        assert_eq!(buffer_write, [0x3f, 0xff, 0x80, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(<F80 as Pod>::from_be_bytes(&buffer_write), Ok(value));
+
+        // Fail
+        assert_eq!(<F80 as Pod>::from_le_bytes(&buffer_read[1..]), Err(PodError::OutOfSpace));
+        assert_eq!(value.to_be_bytes(&mut buffer_write[1..]), Err(PodError::OutOfSpace));
+    }
+
+    #[test]
+    fn f80_conversions() {
+        for value in [1.0, -2.5, 0.1, f64::MAX, f64::MIN_POSITIVE, f64::from_bits(1), 1e300, -0.0] {
+            let f80 = F80::from_f64(value).unwrap();
+            assert_eq!(f80.to_f64().map(f64::to_bits), Ok(value.to_bits()));
+        }
+
+        assert_eq!(F80::from_f64(f64::INFINITY).and_then(F80::to_f64), Ok(f64::INFINITY));
+        assert!(F80::from_f64(f64::NAN).and_then(F80::to_f64).unwrap().is_nan());
+
+        // Rounding to 53 bits, ties to even
+        assert_eq!(F80::from_parts(0x3fff, 0x8000_0000_0000_0400).to_f64(), Ok(1.0));
+        assert_eq!(F80::from_parts(0x3fff, 0x8000_0000_0000_0c00).to_f64(), Ok(1.0 + 2f64.powi(-51)));
+        assert_eq!(F80::from_parts(0x3fff, 0x8000_0000_0000_0401).to_f64(), Ok(1.0 + 2f64.powi(-52)));
+
+        // Out of f64 range
+        assert_eq!(F80::from_parts(0x7ffe, 0x8000_0000_0000_0000).to_f64(), Err(PodError::OutOfRange));
+        assert_eq!(F80::from_parts(0x0001, 0x8000_0000_0000_0000).to_f64(), Ok(0.0));
+
+        // Unnormal
+        assert!(F80::from_parts(0x3fff, 0x4000_0000_0000_0000).to_f64().unwrap().is_nan());
+
+        // Pseudo denormal, read with the smallest exponent
+        assert_eq!(F80::from_parts(0x0000, 0x8000_0000_0000_0000).to_f64(), Ok(0.0));
+        assert_eq!(F80::from_parts(0x8000, 0xc000_0000_0000_0000).to_f64().map(f64::to_bits), Ok((-0.0f64).to_bits()));
+    }
+}
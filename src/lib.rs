@@ -41,6 +41,612 @@
 //! println!("Foo wrote {} bytes to byte buffer: {:#?}", bytes_written, bytes_from_foo);
 //! ```
 //! 
+//! # Fixed byte order
+//! Fields wrapped in [`Be`] or [`Le`] (or an alias such as
+//! [`U32Be`] or [`I16Le`]) are always serialized in that byte
+//! order, whichever of ``from_le_bytes`` or ``from_be_bytes``
+//! is called. This allows mixed endian layouts with the derive.
+//! 
+//! ```
+//! #[derive(Debug, Zeroable, Pod)]
+//! struct Header {
+//!     magic: U32Be,
+//!     length: u16,
+//! }
+//! 
+//! let header = Header::from_le_bytes(&[0, 0, 0, 1, 2, 0])?;
+//! assert_eq!(header.magic.get(), 1);
+//! assert_eq!(header.length, 2);
+//! ```
+//! 
+//! # Other integer widths
+//! [`UInt`] and [`Int`] are integers of any byte width, with
+//! aliases such as [`U24`], [`I48`] and [`U256`]. Signed values
+//! are sign extended when converted to native integers, and
+//! conversions that don't fit fail with ``PodError::OutOfRange``.
+//! 
+//! # Half precision floats
+//! [`F16`] (IEEE binary16) and [`BF16`] (bfloat16) convert
+//! to ``f32`` losslessly and from ``f32`` rounding to nearest,
+//! ties to even.
+//! 
+//! # Legacy floats
+//! [`IbmF32`] and [`IbmF64`] (IBM System/360 hexadecimal floats),
+//! [`VaxF`], [`VaxD`] and [`VaxG`] (VAX floats) and [`F80`] (x87
+//! extended precision) convert to and from ``f64``. Conversions
+//! that don't fit fail with ``PodError::OutOfRange``.
+//! 
+//! # Fixed point and scaled values
+//! [`Fixed<I, FRAC>`](Fixed) is a binary fixed point number stored
+//! as the integer ``I`` with ``FRAC`` fractional bits, with aliases
+//! such as [`Q15`] and [`I16F16`]. Conversions from ``f64`` round
+//! to nearest and saturate.
+//! 
+//! A derived struct can also expose an ``f64`` field that is stored
+//! as ``raw * scale + offset`` in an integer:
+//! 
+//! ```
+//! #[derive(Debug, Zeroable, Pod)]
+//! struct Reading {
+//!     #[pod(repr(i16), scale = 0.01, offset = -40.0)]
+//!     temperature: f64,
+//! }
+//! ```
+//! 
+//! # ASCII and BCD numbers
+//! [`AsciiOctal`], [`AsciiDecimal`] and [`AsciiHex`] are ``N`` byte
+//! numbers written as ASCII digits, as found in TAR, ``ar`` and
+//! ``cpio`` headers. Decoding ignores surrounding spaces and NUL
+//! bytes, and encoding pads to exactly ``N`` bytes with
+//! [`ZeroPadded`] (the default), [`SpacePadded`] or [`NulPadded`].
+//! [`Bcd`] is an ``N`` byte packed binary coded decimal number.
+//! Malformed digits and values that don't fit fail with
+//! ``PodError::OutOfRange``.
+//! 
+//! ```
+//! #[derive(Debug, Zeroable, Pod)]
+//! struct ArHeader {
+//!     size: AsciiDecimal<10, SpacePadded>,
+//!     mode: AsciiOctal<8, SpacePadded>,
+//! }
+//! ```
+//! 
+//! # Fixed width strings
+//! [`FixedStr<N, P, E>`](FixedStr) is text stored in exactly ``N``
+//! bytes. The padding ``P`` is [`NulPadded`] (the default),
+//! [`NulTerminated`] or [`SpacePadded`], and the encoding ``E`` is
+//! [`Utf8`] (the default) or [`Ascii`]. Invalid text, a missing
+//! terminator or text that doesn't fit fail with
+//! ``PodError::OutOfRange``.
+//! 
+//! ```
+//! #[derive(Debug, Zeroable, Pod)]
+//! struct Entry {
+//!     name: FixedStr<16>,
+//!     label: FixedStr<8, SpacePadded, Ascii>,
+//! }
+//! 
+//! let name = FixedStr::<16>::try_from("kernel")?;
+//! assert_eq!(name.as_str(), "kernel");
+//! ```
+//! 
+//! # UTF-16 and code page strings
+//! [`Utf16Str<N, P>`](Utf16Str) is UTF-16 text stored in exactly ``N``
+//! code units, little or big endian following the ``from_le_bytes`` or
+//! ``from_be_bytes`` choice. [`CodePageStr<N, CP, P>`](CodePageStr) is
+//! text stored in exactly ``N`` bytes of a single byte code page, one of
+//! [`Cp437`], [`Latin1`] and [`Windows1252`] or any type implementing
+//! [`CodePage`]. Both convert to and from ``String``, and unpaired
+//! surrogates and unmappable characters fail with ``PodError::OutOfRange``.
+//! 
+//! ```
+//! #[derive(Debug, Zeroable, Pod)]
+//! struct DirEntry {
+//!     short_name: CodePageStr<11, Cp437, SpacePadded>,
+//!     long_name: Utf16Str<13>,
+//! }
+//! ```
+//! 
+//! # Variable length integers
+//! [`Uleb128`], [`Sleb128`], [`ZigZag<T>`](ZigZag) and [`Vlq`] have
+//! a variable size byte representation, so they implement [`VarInt`]
+//! instead of ``Pod``. They decode from a slice or cursor, reporting
+//! the bytes consumed. Over-long encodings and encodings longer than
+//! ``VarInt::MAX_SIZE`` fail with ``PodError::OutOfRange``.
+//! 
+//! ```
+//! let mut cursor = &[0xe5, 0x8e, 0x26, 0x7f][..];
+//! assert_eq!(Uleb128::read(&mut cursor)?.get(), 624485);
+//! assert_eq!(Sleb128::read(&mut cursor)?.get(), -1);
+//! ```
+//! 
+//! # Variable size types
+//! [`DynPod`] is the variable size sibling of ``Pod``: decoding
+//! reports the number of bytes consumed and ``encoded_len`` gives
+//! the size of a value's byte representation. Every ``Pod`` type is
+//! also ``DynPod``, as are the variable length integers, and structs
+//! mixing fixed and variable size fields can derive it.
+//! 
+//! ```
+//! #[derive(Debug, DynPod)]
+//! struct Record {
+//!     tag: u8,
+//!     length: Uleb128,
+//!     checksum: u32,
+//! }
+//! 
+//! let (record, bytes_read) = Record::decode_le(&[1, 0xac, 0x02, 0, 0, 0, 0])?;
+//! assert_eq!(record.length.get(), 300);
+//! assert_eq!(bytes_read, record.encoded_len());
+//! ```
+//! 
+//! ``Vec<T>`` fields take their number of elements from an earlier
+//! field with ``#[pod(count = $field)]``, from a prefix of type
+//! ``$type`` with ``#[pod(len_prefix = $type)]``, or read to the end
+//! of the buffer with ``#[pod(until_eof)]``. A ``count`` field is
+//! written from the length of its ``Vec``, so the two always agree.
+//! 
+//! ```
+//! #[derive(Debug, DynPod)]
+//! struct Table {
+//!     num_entries: u16,
+//!     #[pod(count = num_entries)]
+//!     entries: Vec<u32>,
+//! }
+//! ```
+//! 
+//! # Field offsets
+//! Deriving ``Pod`` for a struct with named fields also generates an
+//! ``OFFSET_$FIELD`` constant per field, and ``read_$field_le`` and
+//! ``write_$field_le`` helpers (with ``_be`` twins) that decode or
+//! encode one field of an encoded struct without touching the rest.
+//! 
+//! ```
+//! #[derive(Debug, Zeroable, Pod)]
+//! struct FileHeader {
+//!     magic: u32,
+//!     timestamp: u64,
+//!     checksum: u16,
+//! }
+//! 
+//! let mut bytes = [0u8; FileHeader::SIZE];
+//! FileHeader::write_checksum_le(&mut bytes, 0xbeef)?;
+//! assert_eq!(FileHeader::OFFSET_CHECKSUM, 12);
+//! assert_eq!(FileHeader::read_checksum_le(&bytes)?, 0xbeef);
+//! ```
+//! 
+//! # Views
+//! Structs with ``#[pod(view)]`` get a ``FooView<'a>`` and a
+//! ``FooViewMut<'a>`` wrapping encoded bytes, with a getter per field
+//! that decodes only that field, and setters on the mutable view that
+//! re-encode in place. Fields marked ``#[pod(view)]`` return nested
+//! views, see [`PodView`], [`PodViewMut`] and [`HasView`].
+//! 
+//! ```
+//! #[derive(Debug, Zeroable, Pod)]
+//! #[pod(view)]
+//! struct Header {
+//!     kind: u8,
+//!     length: u16,
+//! }
+//! 
+//! #[derive(Debug, Zeroable, Pod)]
+//! #[pod(view)]
+//! struct Entry {
+//!     id: u32,
+//!     #[pod(view)]
+//!     header: Header,
+//! }
+//! 
+//! let mut buffer = [1, 0, 0, 0, 7, 3, 0];
+//! assert_eq!(EntryView::new_le(&buffer)?.header()?.length()?, 3);
+//! 
+//! let mut entry = EntryViewMut::new_le(&mut buffer)?;
+//! entry.set_id(2)?;
+//! entry.header_mut()?.set_kind(8)?;
+//! assert_eq!(buffer, [2, 0, 0, 0, 8, 3, 0]);
+//! ```
+//! 
+//! # Borrowed decoding
+//! [`BorrowPod`] decodes without copying: ``&'a [u8; N]`` fields and
+//! derived ``&'a [u8]`` and ``&'a str`` fields point into the input
+//! buffer. Slice and string fields take their length the same way
+//! ``Vec<T>`` fields do, and every ``DynPod`` type is also ``BorrowPod``.
+//! 
+//! ```
+//! #[derive(Debug, BorrowPod)]
+//! struct Packet<'a> {
+//!     length: u16,
+//!     #[pod(count = length)]
+//!     payload: &'a [u8],
+//!     #[pod(len_prefix = u8)]
+//!     name: &'a str,
+//! }
+//! 
+//! let buffer = [2, 0, 0xaa, 0xbb, 2, b'h', b'i'];
+//! let (packet, bytes_read) = Packet::decode_borrowed_le(&buffer)?;
+//! assert_eq!(packet.payload, &[0xaa, 0xbb]);
+//! assert_eq!(packet.name, "hi");
+//! assert_eq!(bytes_read, 7);
+//! ```
+//! 
+//! # Bit fields
+//! Integer, ``bool`` and enum fields marked ``#[pod(bits = N)]`` pack
+//! into shared storage units, ``u8`` unless the struct sets
+//! ``storage($type)``. Bits are numbered from the most significant bit
+//! unless the struct sets ``bit_order = lsb``, values that don't fit
+//! fail to encode with ``PodError::OutOfRange``, and each run of bit
+//! fields takes its bit count rounded up to whole storage units.
+//! 
+//! ```
+//! #[derive(Debug, Zeroable, Pod)]
+//! struct Ipv4Start {
+//!     #[pod(bits = 4)]
+//!     version: u8,
+//!     #[pod(bits = 4)]
+//!     ihl: u8,
+//!     tos: u8,
+//!     length: u16,
+//! }
+//! 
+//! let header = Ipv4Start { version: 4, ihl: 5, tos: 0, length: 20 };
+//! let mut bytes = [0u8; Ipv4Start::SIZE];
+//! header.to_be_bytes(&mut bytes)?;
+//! assert_eq!(bytes, [0x45, 0, 0, 20]);
+//! ```
+//! 
+//! # Bit streams
+//! [`BitReader`] and [`BitWriter`] read and write values that aren't
+//! byte aligned, such as H.264 parameter sets or FLAC frame headers,
+//! most or least significant bit first. Besides plain and signed bit
+//! reads they handle Exp-Golomb codes, and ``Pod`` types can be read
+//! and written once the stream is back at a byte boundary.
+//! 
+//! ```
+//! let buffer = [0b1010_0110, 0b0100_0000, 0x12, 0x34];
+//! let mut reader = BitReader::new(&buffer, BitOrder::Msb);
+//! 
+//! assert_eq!(reader.read_bit()?, true);
+//! assert_eq!(reader.read_ue()?, 1);
+//! assert_eq!(reader.read_bits(3)?, 0b011);
+//! assert_eq!(reader.read_se()?, 2);
+//! reader.align();
+//! assert_eq!(reader.read_pod_be::<u16>()?, 0x1234);
+//! ```
+//! 
+//! # Flags
+//! ``#[derive(PodFlags)]`` turns a struct of ``bool`` fields, or an enum
+//! of single bit flags, into a set of flags stored in an integer, with
+//! set operations and a ``Debug`` listing the set flags by name. Bits
+//! that aren't flags are rejected on decode, unless the type sets
+//! ``unknown_bits = preserve`` or ``unknown_bits = drop``, see [`PodFlags`].
+//! 
+//! ```
+//! #[derive(Debug, Clone, Copy, PodFlags)]
+//! #[pod(repr(u32), unknown_bits = drop)]
+//! enum Feature {
+//!     Compression,
+//!     Encryption,
+//!     #[pod(bit = 4)]
+//!     Checksum,
+//! }
+//! 
+//! let features = FeatureFlags::from_le_bytes(&[0x13, 0, 0, 0x80])?;
+//! assert!(features.contains(Feature::Checksum));
+//! assert_eq!(features.bits(), 0x13);
+//! assert_eq!(format!("{:?}", Feature::Compression | Feature::Checksum), "FeatureFlags(Compression | Checksum)");
+//! ```
+//! 
+//! # Padding and reserved bytes
+//! Fields marked ``#[pod(skip)]`` aren't encoded and are filled with
+//! ``Zeroable::zeroed`` on decode, or ``Default::default`` with
+//! ``#[pod(skip = default)]``. ``#[pod(pad_before = N)]`` and
+//! ``#[pod(pad_after = N)]`` add padding bytes around a field, and
+//! [`Reserved<N>`](Reserved) is a field type for reserved bytes. Both are
+//! written as zeros and ignored on decode, unless the struct sets
+//! ``#[pod(strict)]`` for padding or the field is ``Reserved<N, Strict>``,
+//! which fail with ``PodError::OutOfRange`` when the bytes aren't zeros.
+//! The struct's ``strict`` doesn't reach ``Reserved`` fields, so a strict
+//! struct requires them spelled ``Reserved<N, Strict>`` or
+//! ``Reserved<N, Lenient>``.
+//! 
+//! ```
+//! #[derive(Debug, PartialEq, Zeroable, Pod)]
+//! #[pod(strict)]
+//! struct Entry {
+//!     kind: u8,
+//!     #[pod(pad_before = 1)]
+//!     length: u16,
+//!     reserved: Reserved<4, Lenient>,
+//!     #[pod(skip)]
+//!     visited: bool,
+//! }
+//! 
+//! let entry = Entry::from_le_bytes(&[1, 0, 8, 0, 0xff, 0xff, 0xff, 0xff])?;
+//! assert_eq!(Entry::SIZE, 8);
+//! assert_eq!(Entry::OFFSET_LENGTH, 2);
+//! assert_eq!(Entry::from_le_bytes(&[1, 1, 8, 0, 0, 0, 0, 0]), Err(PodError::OutOfRange));
+//! ```
+//! 
+//! # Magic numbers and constants
+//! A struct with ``#[pod(magic = b"...")]`` starts with the magic number,
+//! and a field with ``#[pod(const = expression)]`` always holds the
+//! constant. Both are always written on encode, and decoding fails with
+//! ``PodError::BadMagic``, carrying the expected and found bytes, if they
+//! don't match. ``matches`` checks if a buffer starts with the magic
+//! number, which is cheap enough to sniff formats.
+//! 
+//! ```
+//! #[derive(Debug, PartialEq, Zeroable, Pod)]
+//! #[pod(magic = b"RIFF")]
+//! struct Chunk {
+//!     size: u32,
+//!     #[pod(const = 1)]
+//!     version: u16,
+//! }
+//! 
+//! let bytes = [b'R', b'I', b'F', b'F', 6, 0, 0, 0, 1, 0];
+//! assert!(Chunk::matches(&bytes));
+//! assert_eq!(Chunk::from_le_bytes(&bytes)?, Chunk { size: 6, version: 1 });
+//! assert_eq!(
+//!     Chunk::from_le_bytes(&[b'R', b'I', b'F', b'X', 6, 0, 0, 0, 1, 0]),
+//!     Err(PodError::BadMagic { expected: b"RIFF".to_vec(), found: b"RIFX".to_vec() })
+//! );
+//! ```
+//! 
+//! # Validation
+//! Derived ``from_le_bytes`` and ``from_be_bytes`` can check decoded
+//! fields with ``#[pod(assert = expression)]``, which can refer to the
+//! struct's fields by name, and ``#[pod(range = a..=b)]``. A struct with
+//! ``#[pod(validate = path::to_fn)]`` also passes the decoded value to
+//! the function, which returns ``Result<(), PodError>``. Failed checks
+//! return ``PodError::Invalid``, carrying the field name and a message.
+//! 
+//! ```
+//! #[derive(Debug, PartialEq, Zeroable, Pod)]
+//! #[pod(validate = check_record)]
+//! struct Record {
+//!     #[pod(assert = version <= 3)]
+//!     version: u8,
+//!     #[pod(range = -90.0..=90.0)]
+//!     latitude: f32,
+//!     length: u8,
+//!     capacity: u8,
+//! }
+//! 
+//! fn check_record(record: &Record) -> Result<(), PodError> {
+//!     if record.length > record.capacity {
+//!         return Err(PodError::Invalid { field: "length", message: String::from("longer than capacity") });
+//!     }
+//!     Ok(())
+//! }
+//! 
+//! assert_eq!(
+//!     Record::from_le_bytes(&[4, 0, 0, 0, 0, 1, 2]),
+//!     Err(PodError::Invalid { field: "version", message: String::from("assertion failed: version <= 3") })
+//! );
+//! assert_eq!(
+//!     Record::from_le_bytes(&[1, 0, 0, 0, 0, 3, 2]),
+//!     Err(PodError::Invalid { field: "length", message: String::from("longer than capacity") })
+//! );
+//! ```
+//! 
+//! # Custom field codecs
+//! Fields can have a custom codec with ``#[pod(with = module)]``, where the
+//! module has a ``SIZE`` constant and ``from_le_bytes``, ``from_be_bytes``,
+//! ``to_le_bytes`` and ``to_be_bytes`` functions shaped like the ones of
+//! [`Pod`], for encodings that have no reusable type. The functions get
+//! exactly ``SIZE`` bytes, failing with ``PodError::OutOfSpace`` before
+//! they are called if the buffer is shorter. ``#[pod(map = function)]``
+//! and ``#[pod(try_map = function)]`` decode the ``Pod`` type in
+//! ``#[pod(repr(type))]`` and convert it, with ``try_map`` returning
+//! ``Result<T, PodError>``, and encode the field through the ``From<&T>``
+//! implementation of the ``repr`` type.
+//! 
+//! ```
+//! #[derive(Debug, PartialEq, Zeroable)]
+//! struct Time {
+//!     hour: u8,
+//!     minute: u8,
+//! }
+//! 
+//! #[derive(Debug, Clone, Copy, PartialEq, Zeroable)]
+//! enum Mode {
+//!     #[zero]
+//!     Off,
+//!     On,
+//! }
+//! 
+//! mod bcd_time {
+//!     use super::*;
+//! 
+//!     pub const SIZE: usize = 2;
+//! 
+//!     fn from_bcd(byte: u8) -> Result<u8, PodError> {
+//!         if byte >> 4 > 9 || byte & 0xf > 9 {
+//!             return Err(PodError::OutOfRange);
+//!         }
+//!         Ok((byte >> 4) * 10 + (byte & 0xf))
+//!     }
+//! 
+//!     pub fn from_le_bytes(buffer: &[u8]) -> Result<Time, PodError> {
+//!         Ok(Time { hour: from_bcd(buffer[0])?, minute: from_bcd(buffer[1])? })
+//!     }
+//! 
+//!     pub fn from_be_bytes(buffer: &[u8]) -> Result<Time, PodError> {
+//!         from_le_bytes(buffer)
+//!     }
+//! 
+//!     pub fn to_le_bytes(time: &Time, buffer: &mut [u8]) -> Result<usize, PodError> {
+//!         buffer[0] = ((time.hour / 10) << 4) | (time.hour % 10);
+//!         buffer[1] = ((time.minute / 10) << 4) | (time.minute % 10);
+//!         Ok(SIZE)
+//!     }
+//! 
+//!     pub fn to_be_bytes(time: &Time, buffer: &mut [u8]) -> Result<usize, PodError> {
+//!         to_le_bytes(time, buffer)
+//!     }
+//! }
+//! 
+//! impl Mode {
+//!     fn from_raw(raw: u8) -> Result<Self, PodError> {
+//!         match raw {
+//!             0 => Ok(Mode::Off),
+//!             1 => Ok(Mode::On),
+//!             _ => Err(PodError::OutOfRange),
+//!         }
+//!     }
+//! }
+//! 
+//! impl From<&Mode> for u8 {
+//!     fn from(mode: &Mode) -> u8 {
+//!         *mode as u8
+//!     }
+//! }
+//! 
+//! #[derive(Debug, PartialEq, Zeroable, Pod)]
+//! struct Reading {
+//!     #[pod(with = bcd_time)]
+//!     time: Time,
+//!     #[pod(repr(u8), try_map = Mode::from_raw)]
+//!     mode: Mode,
+//! }
+//! 
+//! let reading = Reading::from_le_bytes(&[0x23, 0x59, 1])?;
+//! assert_eq!(reading.time.hour, 23);
+//! assert_eq!(Reading::from_le_bytes(&[0x23, 0x59, 2]), Err(PodError::OutOfRange));
+//! ```
+//! 
+//! # Computed fields
+//! Fields with ``#[pod(calc = expression)]`` are filled automatically on
+//! encode, ignoring their value, from an expression that can use ``self``
+//! and ``Self``. Decoding keeps the decoded value, unless the field also
+//! has ``check``, in which case it's checked against the computed value and
+//! a mismatch fails with ``PodError::Invalid``. The field's ``write_$field``
+//! helpers and view setter write the value they're given, which a checked
+//! field then has to match to decode.
+//! 
+//! ```
+//! #[derive(Debug, PartialEq, Zeroable, Pod)]
+//! struct Header {
+//!     #[pod(calc = Self::SIZE as u16, check)]
+//!     header_size: u16,
+//!     #[pod(calc = self.entries.len() as u8, check)]
+//!     count: u8,
+//!     entries: [u16; 2],
+//! }
+//! 
+//! let mut bytes = [0u8; Header::SIZE];
+//! Header { header_size: 0, count: 0, entries: [7, 9] }.to_le_bytes(&mut bytes)?;
+//! assert_eq!(bytes, [7, 0, 2, 7, 0, 9, 0]);
+//! assert_eq!(
+//!     Header::from_le_bytes(&[7, 0, 3, 7, 0, 9, 0]),
+//!     Err(PodError::Invalid { field: "count", message: String::from("expected 2, found 3") })
+//! );
+//! ```
+//! 
+//! # Conditional fields
+//! Derived [`DynPod`] and [`BorrowPod`] types can have ``Option<T>`` fields with
+//! ``#[pod(if = expression)]``, which are only in the bytes if the expression,
+//! which can refer to earlier fields by name, is true. On encode the expression
+//! is evaluated again from the fields it refers to, and it must be true exactly
+//! when the field is ``Some``, failing with ``PodError::Invalid`` otherwise.
+//! 
+//! ```
+//! const HAS_EXTENSION: u8 = 0x01;
+//! 
+//! #[derive(Debug, PartialEq, DynPod)]
+//! struct Header {
+//!     flags: u8,
+//!     #[pod(if = flags & HAS_EXTENSION != 0)]
+//!     extension: Option<u16>,
+//!     length: u8,
+//! }
+//! 
+//! assert_eq!(Header::decode_le(&[1, 7, 0, 3])?, (Header { flags: 1, extension: Some(7), length: 3 }, 4));
+//! assert_eq!(Header::decode_le(&[0, 3])?, (Header { flags: 0, extension: None, length: 3 }, 2));
+//! 
+//! let header = Header { flags: 0, extension: Some(7), length: 3 };
+//! assert!(matches!(header.encode_le(&mut [0u8; 4]), Err(PodError::Invalid { field: "extension", .. })));
+//! ```
+//! 
+//! # Absolute offsets and alignment
+//! A field with ``#[pod(offset = N)]`` starts at the absolute offset ``N``.
+//! Fields with ``repr`` or ``scale`` use ``#[pod(at = N)]`` instead, since
+//! their ``offset`` is added to the value. A field with ``#[pod(align = N)]``
+//! starts at the next multiple of ``N``, and a struct with
+//! ``#[pod(size = N)]`` is padded to exactly ``N`` bytes. The bytes in
+//! between are written as zeros and checked like padding with
+//! ``#[pod(strict)]``, and a field overlapping the fields before its offset,
+//! or fields exceeding the struct's size, fail to compile.
+//! 
+//! ```
+//! #[derive(Debug, PartialEq, Zeroable, Pod)]
+//! #[pod(size = 512)]
+//! struct BootSector {
+//!     jump: [u8; 3],
+//!     #[pod(offset = 0x0b)]
+//!     bytes_per_sector: u16,
+//!     #[pod(align = 8)]
+//!     volume_id: u64,
+//!     #[pod(offset = 0x1fe)]
+//!     signature: u16,
+//! }
+//! 
+//! assert_eq!(BootSector::SIZE, 512);
+//! assert_eq!(BootSector::OFFSET_VOLUME_ID, 16);
+//! 
+//! let mut sector = [0xffu8; 512];
+//! let sector_data = BootSector { jump: [0xeb, 0x3c, 0x90], bytes_per_sector: 512, volume_id: 1, signature: 0xaa55 };
+//! sector_data.to_le_bytes(&mut sector)?;
+//! assert_eq!(&sector[3..11], &[0; 8]);
+//! assert_eq!(&sector[510..], &[0x55, 0xaa]);
+//! assert_eq!(BootSector::from_le_bytes(&sector)?, sector_data);
+//! ```
+//! 
+//! # C layout
+//! A struct with ``#[pod(layout = "c")]`` is laid out like a C compiler would
+//! lay out the same struct, aligning every field to its natural alignment and
+//! padding the end to the struct's alignment, instead of packing the fields
+//! tightly. The target is x86_64 by default, and ``#[pod(target = "i386")]`` or
+//! ``#[pod(target = "arm32")]`` choose another, which matters for 8 byte
+//! values. Fields must be primitives, arrays or types with a ``C_ALIGN``
+//! constant, which C layout structs get so they can be nested. With
+//! ``#[pod(twin = Type)]``, the offsets and size are checked at compile time
+//! against a ``#[repr(C)]`` struct with the same field names, when compiling
+//! for the target.
+//! 
+//! ```
+//! #[repr(C)]
+//! struct RawEntry {
+//!     kind: u8,
+//!     length: u32,
+//!     start: u64,
+//! }
+//! 
+//! #[derive(Debug, PartialEq, Zeroable, Pod)]
+//! #[pod(layout = "c", twin = RawEntry)]
+//! struct Entry {
+//!     kind: u8,
+//!     length: u32,
+//!     start: u64,
+//! }
+//! 
+//! #[derive(Debug, PartialEq, Zeroable, Pod)]
+//! #[pod(layout = "c", target = "i386")]
+//! struct LegacyEntry {
+//!     kind: u8,
+//!     start: u64,
+//!     flags: u16,
+//! }
+//! 
+//! assert_eq!(Entry::SIZE, 16);
+//! assert_eq!(Entry::OFFSET_LENGTH, 4);
+//! assert_eq!(Entry::C_ALIGN, 8);
+//! assert_eq!(LegacyEntry::SIZE, 16);
+//! assert_eq!(LegacyEntry::OFFSET_START, 4);
+//! ```
+//! 
 //! # Upcoming
 //! In following versions more primitive types will be supported,
 //! some ``std`` types will be supported, and the derive macros will
@@ -49,11 +655,58 @@
 // Define modules
 mod zeroable;
 mod pod;
+mod endian;
+mod int;
+mod half;
+mod legacy_float;
+mod fixed;
+mod encoded_int;
+mod fixed_str;
+mod utf16_str;
+mod code_page_str;
+mod varint;
+mod dyn_pod;
+mod borrow_pod;
+mod view;
+mod bit_field;
+mod bit_stream;
+mod pod_flags;
+mod reserved;
 
 // Re-exports
 pub use zeroable::Zeroable;
 pub use pod::{Pod, PodError};
+pub use dyn_pod::{DynPod, LengthInt};
+pub use borrow_pod::BorrowPod;
+pub use view::{PodView, PodViewMut, HasView};
+pub use bit_field::BitField;
+pub use bit_stream::{BitOrder, BitReader, BitWriter};
+pub use pod_flags::PodFlags;
+pub use reserved::{ReservedCheck, Lenient, Strict, Reserved};
+pub use endian::{
+    Be, Le,
+    U16Be, U32Be, U64Be, U128Be, I16Be, I32Be, I64Be, I128Be, F32Be, F64Be,
+    U16Le, U32Le, U64Le, U128Le, I16Le, I32Le, I64Le, I128Le, F32Le, F64Le,
+};
+pub use int::{
+    UInt, Int,
+    U24, U40, U48, U56, U256, I24, I40, I48, I56, I256,
+};
+pub use half::{F16, BF16};
+pub use legacy_float::{IbmF32, IbmF64, VaxF, VaxD, VaxG, F80};
+pub use fixed::{FixedInt, Fixed, Q7, Q15, Q31, I8F8, I16F16, U16F16};
+pub use encoded_int::{
+    Padding, ZeroPadded, SpacePadded, NulPadded,
+    AsciiOctal, AsciiDecimal, AsciiHex, Bcd,
+};
+pub use fixed_str::{StrPadding, StrEncoding, NulTerminated, Utf8, Ascii, FixedStr};
+pub use utf16_str::Utf16Str;
+pub use code_page_str::{CodePage, Cp437, Latin1, Windows1252, CodePageStr};
+pub use varint::{VarInt, ZigZagInt, Uleb128, Sleb128, ZigZag, Vlq};
 pub use safe_pod_derive::{
     Zeroable,
-    Pod
+    Pod,
+    DynPod,
+    BorrowPod,
+    PodFlags
 };
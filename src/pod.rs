@@ -11,6 +11,21 @@ pub enum PodError {
     /// The bytes in the buffer are not in 
     /// the permitted range for the type
     OutOfRange,
+    /// The bytes in the buffer are not the
+    /// magic number or constant of the type
+    BadMagic {
+        /// Bytes the type requires
+        expected: Vec<u8>,
+        /// Bytes found in the buffer
+        found: Vec<u8>,
+    },
+    /// A decoded value failed validation
+    Invalid {
+        /// Name of the field that failed validation
+        field: &'static str,
+        /// Reason the value is invalid
+        message: String,
+    },
 }
 
 impl std::fmt::Display for PodError {
@@ -18,6 +33,8 @@ impl std::fmt::Display for PodError {
         match &self {
             Self::OutOfSpace => write!(f, "NotEnughSpace"),
             Self::OutOfRange => write!(f, "NotInRange"),
+            Self::BadMagic { expected, found } => write!(f, "BadMagic(expected {:02x?}, found {:02x?})", expected, found),
+            Self::Invalid { field, message } => write!(f, "Invalid({}: {})", field, message),
         }
     }
 }
@@ -0,0 +1,25 @@
+use crate::pod::{Pod, PodError};
+
+/// A set of single bit flags stored in an integer
+///
+/// Derived with `#[derive(PodFlags)]`, which also implements [`Pod`]
+/// on top of the integer's byte representation.
+pub trait PodFlags: Pod {
+    /// Integer the flags are stored in
+    type Bits: Pod;
+
+    /// Bits of all the known flags
+    const KNOWN: Self::Bits;
+
+    /// Get the raw bits, including unknown
+    /// bits if the type preserves them
+    fn bits(&self) -> Self::Bits;
+
+    /// Create instance from raw bits, applying the type's policy
+    /// for unknown bits, failing with [`PodError::OutOfRange`]
+    /// if the policy rejects them
+    fn from_bits(bits: Self::Bits) -> Result<Self, PodError>;
+
+    /// Create instance from raw bits, dropping unknown bits
+    fn from_bits_truncate(bits: Self::Bits) -> Self;
+}
@@ -0,0 +1,145 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use crate::pod::{Pod, PodError};
+use crate::zeroable::Zeroable;
+
+/// How reserved bytes are checked on decode
+pub trait ReservedCheck {
+    /// Whether the bytes must be zeros
+    const STRICT: bool;
+}
+
+/// Reserved bytes are ignored on decode
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Lenient;
+
+/// Reserved bytes must be zeros on decode
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Strict;
+
+impl ReservedCheck for Lenient {
+    const STRICT: bool = false;
+}
+
+impl ReservedCheck for Strict {
+    const STRICT: bool = true;
+}
+
+/// `N` reserved bytes, which are written as zeros
+///
+/// Decoding ignores the bytes, unless `C` is [`Strict`], in which case
+/// it fails with [`PodError::OutOfRange`] if any of them isn't zero.
+pub struct Reserved<const N: usize, C: ReservedCheck = Lenient> {
+    marker: PhantomData<C>,
+}
+
+impl<const N: usize, C: ReservedCheck> Reserved<N, C> {
+    /// Create reserved bytes
+    #[inline]
+    pub const fn new() -> Self {
+        Self { marker: PhantomData }
+    }
+}
+
+impl<const N: usize, C: ReservedCheck> Clone for Reserved<N, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, C: ReservedCheck> Copy for Reserved<N, C> { }
+
+impl<const N: usize, C: ReservedCheck> PartialEq for Reserved<N, C> {
+    #[inline]
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<const N: usize, C: ReservedCheck> Eq for Reserved<N, C> { }
+
+impl<const N: usize, C: ReservedCheck> Hash for Reserved<N, C> {
+    #[inline]
+    fn hash<H: Hasher>(&self, _state: &mut H) { }
+}
+
+impl<const N: usize, C: ReservedCheck> Default for Reserved<N, C> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, C: ReservedCheck> fmt::Debug for Reserved<N, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Reserved<{}>", N)
+    }
+}
+
+impl<const N: usize, C: ReservedCheck> Zeroable for Reserved<N, C> {
+    #[inline]
+    fn zeroed() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, C: ReservedCheck> Pod for Reserved<N, C> {
+    const SIZE: usize = N;
+
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        if C::STRICT && buffer[..N].iter().any(|b| *b != 0) {
+            return Err(PodError::OutOfRange);
+        }
+
+        Ok(Self::new())
+    }
+
+    #[inline]
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        Self::from_le_bytes(buffer)
+    }
+
+    #[inline]
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        buffer[..N].fill(0);
+
+        Ok(N)
+    }
+
+    #[inline]
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        self.to_le_bytes(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pod_reserved() {
+        // Read
+        assert_eq!(<Reserved<3> as Pod>::from_le_bytes(&[1, 2, 3]), Ok(Reserved::new()));
+        assert_eq!(<Reserved<2, Strict> as Pod>::from_be_bytes(&[0, 0, 1]), Ok(Reserved::new()));
+        assert_eq!(<Reserved<2, Strict> as Pod>::from_le_bytes(&[0, 1]), Err(PodError::OutOfRange));
+        assert_eq!(<Reserved<4> as Pod>::from_le_bytes(&[0, 0, 0]), Err(PodError::OutOfSpace));
+
+        // Write
+        let mut buffer_write = [0xffu8; 4];
+
+        assert_eq!(Reserved::<3>::new().to_le_bytes(&mut buffer_write), Ok(3));
+        assert_eq!(buffer_write, [0, 0, 0, 0xff]);
+        assert_eq!(Reserved::<3>::new().to_be_bytes(&mut buffer_write[2..]), Err(PodError::OutOfSpace));
+    }
+}
@@ -0,0 +1,299 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use crate::encoded_int::NulPadded;
+use crate::fixed_str::{text_len, StrPadding};
+use crate::pod::{Pod, PodError};
+use crate::zeroable::Zeroable;
+
+/// A UTF-16 string stored in exactly `N` code units (`2 * N` bytes),
+/// padded according to `P`
+///
+/// The code units are little endian with `from_le_bytes`/`to_le_bytes`
+/// and big endian with `from_be_bytes`/`to_be_bytes`. Decoding fails
+/// with [`PodError::OutOfRange`] on unpaired surrogates.
+pub struct Utf16Str<const N: usize, P: StrPadding = NulPadded> {
+    units: [u16; N],
+    len: usize,
+    padding: PhantomData<P>,
+}
+
+impl<const N: usize, P: StrPadding> Utf16Str<N, P> {
+    /// Longest text in code units that fits
+    pub const CAPACITY: usize = if P::TERMINATED { N.saturating_sub(1) } else { N };
+
+    /// Create from text, failing with [`PodError::OutOfRange`] if it's
+    /// longer than [`CAPACITY`](Self::CAPACITY) code units, or wouldn't
+    /// decode to the same text, because it contains a NUL when NUL padded
+    /// or ends with a space when space padded
+    pub fn new(text: &str) -> Result<Self, PodError> {
+        let mut units = [P::BYTE as u16; N];
+
+        let mut len = 0;
+        for unit in text.encode_utf16() {
+            if len == Self::CAPACITY {
+                return Err(PodError::OutOfRange);
+            }
+
+            units[len] = unit;
+            len += 1;
+        }
+
+        let ambiguous = match P::BYTE {
+            0 => units[..len].contains(&0),
+            _ => units[..len].last() == Some(&(P::BYTE as u16)),
+        };
+        if ambiguous {
+            return Err(PodError::OutOfRange);
+        }
+
+        Ok(Self { units, len, padding: PhantomData })
+    }
+
+    /// Get the code units of the text, without padding
+    #[inline]
+    pub fn as_units(&self) -> &[u16] {
+        &self.units[..self.len]
+    }
+
+    /// Iterate over the characters of the text
+    #[inline]
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        // Contents are validated on construction
+        char::decode_utf16(self.as_units().iter().copied())
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Length of the text in code units
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the text is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Create from code units, stripping padding and checking surrogates
+    fn from_units(units: [u16; N]) -> Result<Self, PodError> {
+        let len = text_len::<P, u16>(&units, P::BYTE as u16)?;
+
+        if char::decode_utf16(units[..len].iter().copied()).any(|c| c.is_err()) {
+            return Err(PodError::OutOfRange);
+        }
+
+        Ok(Self { units, len, padding: PhantomData })
+    }
+}
+
+impl<const N: usize, P: StrPadding> Clone for Utf16Str<N, P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, P: StrPadding> Copy for Utf16Str<N, P> { }
+
+impl<const N: usize, P: StrPadding> PartialEq for Utf16Str<N, P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_units() == other.as_units()
+    }
+}
+
+impl<const N: usize, P: StrPadding> Eq for Utf16Str<N, P> { }
+
+impl<const N: usize, P: StrPadding> PartialEq<str> for Utf16Str<N, P> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl<const N: usize, P: StrPadding> PartialEq<&str> for Utf16Str<N, P> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl<const N: usize, P: StrPadding> Hash for Utf16Str<N, P> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_units().hash(state)
+    }
+}
+
+impl<const N: usize, P: StrPadding> Default for Utf16Str<N, P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl<const N: usize, P: StrPadding> fmt::Debug for Utf16Str<N, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&String::from(*self), f)
+    }
+}
+
+impl<const N: usize, P: StrPadding> fmt::Display for Utf16Str<N, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.chars() {
+            fmt::Write::write_char(f, c)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const N: usize, P: StrPadding> TryFrom<&str> for Utf16Str<N, P> {
+    type Error = PodError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const N: usize, P: StrPadding> TryFrom<String> for Utf16Str<N, P> {
+    type Error = PodError;
+
+    #[inline]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
+impl<const N: usize, P: StrPadding> From<Utf16Str<N, P>> for String {
+    #[inline]
+    fn from(value: Utf16Str<N, P>) -> Self {
+        value.chars().collect()
+    }
+}
+
+impl<const N: usize, P: StrPadding> Zeroable for Utf16Str<N, P> {
+    #[inline]
+    fn zeroed() -> Self {
+        Self { units: [P::BYTE as u16; N], len: 0, padding: PhantomData }
+    }
+}
+
+impl<const N: usize, P: StrPadding> Pod for Utf16Str<N, P> {
+    const SIZE: usize = N * 2;
+
+    fn from_le_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        let mut units = [0u16; N];
+        for (unit, bytes) in units.iter_mut().zip(buffer.chunks_exact(2)) {
+            *unit = u16::from_le_bytes([bytes[0], bytes[1]]);
+        }
+
+        Self::from_units(units)
+    }
+
+    fn from_be_bytes(buffer: &[u8]) -> Result<Self, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        let mut units = [0u16; N];
+        for (unit, bytes) in units.iter_mut().zip(buffer.chunks_exact(2)) {
+            *unit = u16::from_be_bytes([bytes[0], bytes[1]]);
+        }
+
+        Self::from_units(units)
+    }
+
+    fn to_le_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        for (unit, bytes) in self.units.iter().zip(buffer.chunks_exact_mut(2)) {
+            bytes.copy_from_slice(&u16::to_le_bytes(*unit));
+        }
+
+        Ok(Self::SIZE)
+    }
+
+    fn to_be_bytes(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        if buffer.len() < Self::SIZE {
+            return Err(PodError::OutOfSpace);
+        }
+
+        for (unit, bytes) in self.units.iter().zip(buffer.chunks_exact_mut(2)) {
+            bytes.copy_from_slice(&u16::to_be_bytes(*unit));
+        }
+
+        Ok(Self::SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoded_int::SpacePadded;
+    use crate::fixed_str::NulTerminated;
+
+    use super::*;
+
+    #[test]
+    fn pod_utf16_str() {
+        // Read success
+        let buffer_read = [b'h', 0, b'i', 0, 0x3d, 0xd8, 0x00, 0xde, 0, 0];
+
+        assert_eq!(<Utf16Str<5> as Pod>::from_le_bytes(&buffer_read).unwrap(), "hi\u{1f600}");
+        assert_eq!(<Utf16Str<5> as Pod>::from_le_bytes(&buffer_read).map(|s| s.len()), Ok(4));
+        assert_eq!(<Utf16Str<2> as Pod>::from_be_bytes(&[0, b'o', 0, b'k']).unwrap(), "ok");
+        assert_eq!(<Utf16Str<3, SpacePadded> as Pod>::from_be_bytes(&[0, b'a', 0, b' ', 0, b' ']).unwrap(), "a");
+
+        // Read fail
+        assert_eq!(<Utf16Str<2> as Pod>::from_le_bytes(&[0x3d, 0xd8, b'a', 0]), Err(PodError::OutOfRange));
+        assert_eq!(<Utf16Str<2> as Pod>::from_le_bytes(&[0x00, 0xde, 0, 0]), Err(PodError::OutOfRange));
+        assert_eq!(<Utf16Str<2, NulTerminated> as Pod>::from_le_bytes(&[b'a', 0, b'b', 0]), Err(PodError::OutOfRange));
+        assert_eq!(<Utf16Str<2> as Pod>::from_le_bytes(&[b'a', 0, b'b']), Err(PodError::OutOfSpace));
+
+        // Write success
+        let mut buffer_write = [0xffu8; 6];
+
+        assert_eq!(Utf16Str::<3>::new("é").unwrap().to_le_bytes(&mut buffer_write), Ok(6));
+        assert_eq!(buffer_write, [0xe9, 0x00, 0, 0, 0, 0]);
+        assert_eq!(Utf16Str::<3>::new("é").unwrap().to_be_bytes(&mut buffer_write), Ok(6));
+        assert_eq!(buffer_write, [0x00, 0xe9, 0, 0, 0, 0]);
+
+        // Write fail
+        assert_eq!(Utf16Str::<3>::new("é").unwrap().to_le_bytes(&mut buffer_write[1..]), Err(PodError::OutOfSpace));
+    }
+
+    #[test]
+    fn utf16_str_conversions() {
+        assert_eq!(Utf16Str::<2>::try_from("\u{1f600}").map(|s| s.len()), Ok(2));
+        assert_eq!(Utf16Str::<2, NulTerminated>::try_from("\u{1f600}"), Err(PodError::OutOfRange));
+        assert_eq!(Utf16Str::<2>::try_from(String::from("abc")), Err(PodError::OutOfRange));
+        assert_eq!(String::from(Utf16Str::<4>::new("abc").unwrap()), "abc");
+        assert_eq!(Utf16Str::<4>::new("abc").unwrap().to_string(), "abc");
+        assert!(Utf16Str::<4>::zeroed().is_empty());
+    }
+
+    #[test]
+    fn utf16_str_ambiguous_text() {
+        // Text that would decode differently
+        assert_eq!(Utf16Str::<4>::new("a\0b"), Err(PodError::OutOfRange));
+        assert_eq!(Utf16Str::<4, NulTerminated>::new("a\0"), Err(PodError::OutOfRange));
+        assert_eq!(Utf16Str::<4, SpacePadded>::new("ab "), Err(PodError::OutOfRange));
+
+        // Spaces before the end and NUL in space padded text roundtrip
+        let mut buffer = [0u8; 8];
+        for text in [" a b", "a\0b"] {
+            Utf16Str::<4, SpacePadded>::new(text).unwrap().to_le_bytes(&mut buffer).unwrap();
+            assert_eq!(<Utf16Str<4, SpacePadded> as Pod>::from_le_bytes(&buffer).unwrap().to_string(), text);
+        }
+    }
+}
@@ -0,0 +1,647 @@
+use std::io::Cursor;
+use std::mem;
+
+use crate::dyn_pod::DynPod;
+use crate::pod::PodError;
+use crate::zeroable::Zeroable;
+
+/// A variable length integer, which has the same byte
+/// representation in both byte orders
+///
+/// The size of the byte representation of a value is given by
+/// [`DynPod::encoded_len`]. Decoding fails with [`PodError::OutOfSpace`] if the buffer ends
+/// before the encoding does, and with [`PodError::OutOfRange`] if
+/// the encoding is longer than [`MAX_SIZE`](VarInt::MAX_SIZE),
+/// over-long or doesn't fit the type.
+pub trait VarInt: DynPod {
+    /// Maximum size of the byte representation
+    const MAX_SIZE: usize;
+
+    /// Decode from the start of `buffer`, returning the value
+    /// and the number of bytes consumed
+    fn decode(buffer: &[u8]) -> Result<(Self, usize), PodError>;
+
+    /// Encode to the start of `buffer`, returning the number
+    /// of bytes written
+    fn encode(&self, buffer: &mut [u8]) -> Result<usize, PodError>;
+
+    /// Decode from the start of `cursor` and advance it past
+    /// the bytes consumed
+    fn read(cursor: &mut &[u8]) -> Result<Self, PodError> {
+        let (value, len) = Self::decode(cursor)?;
+        *cursor = &cursor[len..];
+
+        Ok(value)
+    }
+
+    /// Decode at the position of `cursor` and advance it past
+    /// the bytes consumed
+    fn read_cursor<T: AsRef<[u8]>>(cursor: &mut Cursor<T>) -> Result<Self, PodError> {
+        let buffer = cursor.get_ref().as_ref();
+        let position = (cursor.position() as usize).min(buffer.len());
+        let (value, len) = Self::decode(&buffer[position..])?;
+        cursor.set_position((position + len) as u64);
+
+        Ok(value)
+    }
+
+    /// Encode to the start of `cursor` and advance it past
+    /// the bytes written
+    fn write(&self, cursor: &mut &mut [u8]) -> Result<(), PodError> {
+        let len = self.encode(cursor)?;
+        let buffer = mem::take(cursor);
+        *cursor = &mut buffer[len..];
+
+        Ok(())
+    }
+}
+
+// Decode an unsigned LEB128 value of at most `bits` bits
+fn decode_uleb(buffer: &[u8], bits: u32) -> Result<(u64, usize), PodError> {
+    let max_len = bits.div_ceil(7) as usize;
+
+    let mut value = 0u64;
+    for (i, byte) in buffer.iter().enumerate() {
+        if i == max_len {
+            return Err(PodError::OutOfRange);
+        }
+
+        // Bits that don't fit in the value must be zero
+        let shift = 7 * i as u32;
+        let group = (byte & 0x7f) as u64;
+        if shift + 7 > bits && group >> (bits - shift) != 0 {
+            return Err(PodError::OutOfRange);
+        }
+        value |= group << shift;
+
+        if byte & 0x80 == 0 {
+            // Over-long encodings end in a zero group
+            if i > 0 && *byte == 0 {
+                return Err(PodError::OutOfRange);
+            }
+
+            return Ok((value, i + 1));
+        }
+    }
+
+    Err(PodError::OutOfSpace)
+}
+
+// Encode an unsigned LEB128 value, returning the bytes and their count
+fn uleb_bytes(mut value: u64) -> ([u8; 10], usize) {
+    let mut bytes = [0u8; 10];
+    let mut len = 0;
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            bytes[len] = byte;
+            return (bytes, len + 1);
+        }
+
+        bytes[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+// Encode a signed LEB128 value, returning the bytes and their count
+fn sleb_bytes(mut value: i64) -> ([u8; 10], usize) {
+    let mut bytes = [0u8; 10];
+    let mut len = 0;
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+            bytes[len] = byte;
+            return (bytes, len + 1);
+        }
+
+        bytes[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+// Copy encoded bytes to the start of `buffer`
+fn write_bytes(bytes: &[u8], buffer: &mut [u8]) -> Result<usize, PodError> {
+    if buffer.len() < bytes.len() {
+        return Err(PodError::OutOfSpace);
+    }
+
+    buffer[..bytes.len()].copy_from_slice(bytes);
+
+    Ok(bytes.len())
+}
+
+/// An unsigned LEB128 integer, as used by DWARF, WebAssembly
+/// and protobuf varints
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uleb128(u64);
+
+impl Uleb128 {
+    /// Create from a value
+    #[inline]
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Get the value
+    #[inline]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    // Size of the byte representation
+    #[inline]
+    fn byte_len(&self) -> usize {
+        uleb_bytes(self.0).1
+    }
+}
+
+impl VarInt for Uleb128 {
+    const MAX_SIZE: usize = 10;
+
+    #[inline]
+    fn decode(buffer: &[u8]) -> Result<(Self, usize), PodError> {
+        let (value, len) = decode_uleb(buffer, u64::BITS)?;
+
+        Ok((Self(value), len))
+    }
+
+    #[inline]
+    fn encode(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        let (bytes, len) = uleb_bytes(self.0);
+
+        write_bytes(&bytes[..len], buffer)
+    }
+}
+
+/// A signed LEB128 integer, as used by DWARF and WebAssembly
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sleb128(i64);
+
+impl Sleb128 {
+    /// Create from a value
+    #[inline]
+    pub const fn new(value: i64) -> Self {
+        Self(value)
+    }
+
+    /// Get the value
+    #[inline]
+    pub const fn get(self) -> i64 {
+        self.0
+    }
+
+    // Size of the byte representation
+    #[inline]
+    fn byte_len(&self) -> usize {
+        sleb_bytes(self.0).1
+    }
+}
+
+impl VarInt for Sleb128 {
+    const MAX_SIZE: usize = 10;
+
+    fn decode(buffer: &[u8]) -> Result<(Self, usize), PodError> {
+        let mut value = 0i64;
+        for (i, byte) in buffer.iter().enumerate() {
+            if i == Self::MAX_SIZE {
+                return Err(PodError::OutOfRange);
+            }
+
+            // The last group only holds the sign bit
+            let shift = 7 * i as u32;
+            let group = byte & 0x7f;
+            if shift == 63 && group != 0 && group != 0x7f {
+                return Err(PodError::OutOfRange);
+            }
+            value |= (group as i64) << shift;
+
+            if byte & 0x80 == 0 {
+                // Over-long encodings end in a group that only repeats the sign
+                if i > 0 {
+                    let sign = buffer[i - 1] & 0x40 != 0;
+                    if (*byte == 0 && !sign) || (*byte == 0x7f && sign) {
+                        return Err(PodError::OutOfRange);
+                    }
+                }
+
+                // Sign extend
+                if shift + 7 < 64 && group & 0x40 != 0 {
+                    value |= -1i64 << (shift + 7);
+                }
+
+                return Ok((Self(value), i + 1));
+            }
+        }
+
+        Err(PodError::OutOfSpace)
+    }
+
+    #[inline]
+    fn encode(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        let (bytes, len) = sleb_bytes(self.0);
+
+        write_bytes(&bytes[..len], buffer)
+    }
+}
+
+/// A signed integer that can be zigzag encoded
+pub trait ZigZagInt: Copy {
+    /// Number of bits in the integer
+    const BITS: u32;
+
+    /// Map to an unsigned value, interleaving positive and negative values
+    fn zigzag(self) -> u64;
+
+    /// Map back from an unsigned value, which must fit in `BITS` bits
+    fn unzigzag(value: u64) -> Self;
+}
+
+// Implements ZigZagInt for the signed native integer types
+macro_rules! impl_zigzag_int {
+    ($($ty:ty => $unsigned:ty),*) => {
+        $(
+            impl ZigZagInt for $ty {
+                const BITS: u32 = <$ty>::BITS;
+
+                #[inline]
+                fn zigzag(self) -> u64 {
+                    ((self << 1) ^ (self >> (<$ty>::BITS - 1))) as $unsigned as u64
+                }
+
+                #[inline]
+                fn unzigzag(value: u64) -> Self {
+                    let value = value as $unsigned;
+
+                    ((value >> 1) as $ty) ^ -((value & 1) as $ty)
+                }
+            }
+        )*
+    };
+}
+
+impl_zigzag_int!(i8 => u8, i16 => u16, i32 => u32, i64 => u64);
+
+/// A zigzag encoded signed integer stored as unsigned LEB128,
+/// as used by protobuf `sint32` and `sint64` fields
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZigZag<T>(T);
+
+impl<T: ZigZagInt> ZigZag<T> {
+    /// Create from a value
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Get the value
+    #[inline]
+    pub const fn get(self) -> T {
+        self.0
+    }
+
+    // Size of the byte representation
+    #[inline]
+    fn byte_len(&self) -> usize {
+        uleb_bytes(self.0.zigzag()).1
+    }
+}
+
+impl<T: ZigZagInt> VarInt for ZigZag<T> {
+    const MAX_SIZE: usize = T::BITS.div_ceil(7) as usize;
+
+    #[inline]
+    fn decode(buffer: &[u8]) -> Result<(Self, usize), PodError> {
+        let (value, len) = decode_uleb(buffer, T::BITS)?;
+
+        Ok((Self(T::unzigzag(value)), len))
+    }
+
+    #[inline]
+    fn encode(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        let (bytes, len) = uleb_bytes(self.0.zigzag());
+
+        write_bytes(&bytes[..len], buffer)
+    }
+}
+
+/// A MIDI variable length quantity, big endian groups
+/// of 7 bits holding at most 28 bits
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vlq(u32);
+
+impl Vlq {
+    /// Largest value a VLQ can hold
+    pub const MAX: u32 = 0x0fff_ffff;
+
+    /// Create from a value, failing with [`PodError::OutOfRange`]
+    /// if it's larger than [`Vlq::MAX`]
+    #[inline]
+    pub const fn new(value: u32) -> Result<Self, PodError> {
+        if value > Self::MAX {
+            return Err(PodError::OutOfRange);
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Get the value
+    #[inline]
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    // Size of the byte representation
+    #[inline]
+    fn byte_len(&self) -> usize {
+        match self.0 {
+            0..=0x7f => 1,
+            0x80..=0x3fff => 2,
+            0x4000..=0x1f_ffff => 3,
+            _ => 4,
+        }
+    }
+}
+
+impl VarInt for Vlq {
+    const MAX_SIZE: usize = 4;
+
+    fn decode(buffer: &[u8]) -> Result<(Self, usize), PodError> {
+        let mut value = 0u32;
+        for (i, byte) in buffer.iter().enumerate() {
+            if i == Self::MAX_SIZE {
+                return Err(PodError::OutOfRange);
+            }
+
+            // Over-long encodings start with an empty group
+            if i == 0 && *byte == 0x80 {
+                return Err(PodError::OutOfRange);
+            }
+
+            value = (value << 7) | (byte & 0x7f) as u32;
+
+            if byte & 0x80 == 0 {
+                return Ok((Self(value), i + 1));
+            }
+        }
+
+        Err(PodError::OutOfSpace)
+    }
+
+    fn encode(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+        let len = self.byte_len();
+        if buffer.len() < len {
+            return Err(PodError::OutOfSpace);
+        }
+
+        for (i, byte) in buffer[..len].iter_mut().enumerate() {
+            let shift = 7 * (len - 1 - i);
+            *byte = ((self.0 >> shift) & 0x7f) as u8;
+
+            if i + 1 < len {
+                *byte |= 0x80;
+            }
+        }
+
+        Ok(len)
+    }
+}
+
+// Implements Zeroable and conversions for the variable length integers
+macro_rules! impl_var_int {
+    ($($ty:ty => $native:ty),*) => {
+        $(
+            impl Zeroable for $ty {
+                #[inline]
+                fn zeroed() -> Self {
+                    Self::default()
+                }
+            }
+
+            impl From<$ty> for $native {
+                #[inline]
+                fn from(value: $ty) -> Self {
+                    value.get()
+                }
+            }
+        )*
+    };
+}
+
+impl_var_int!(Uleb128 => u64, Sleb128 => i64, Vlq => u32);
+
+// Implements DynPod for variable length integers,
+// which have the same encoding in both byte orders
+macro_rules! impl_dyn_pod {
+    ($(impl$(<$generic:ident: $bound:path>)? for $ty:ty),*) => {
+        $(
+            impl$(<$generic: $bound>)? DynPod for $ty {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    self.byte_len()
+                }
+
+                #[inline]
+                fn decode_le(buffer: &[u8]) -> Result<(Self, usize), PodError> {
+                    Self::decode(buffer)
+                }
+
+                #[inline]
+                fn decode_be(buffer: &[u8]) -> Result<(Self, usize), PodError> {
+                    Self::decode(buffer)
+                }
+
+                #[inline]
+                fn encode_le(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+                    self.encode(buffer)
+                }
+
+                #[inline]
+                fn encode_be(&self, buffer: &mut [u8]) -> Result<usize, PodError> {
+                    self.encode(buffer)
+                }
+            }
+        )*
+    };
+}
+
+impl_dyn_pod!(
+    impl for Uleb128,
+    impl for Sleb128,
+    impl for Vlq,
+    impl<T: ZigZagInt> for ZigZag<T>
+);
+
+impl From<u64> for Uleb128 {
+    #[inline]
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<i64> for Sleb128 {
+    #[inline]
+    fn from(value: i64) -> Self {
+        Self(value)
+    }
+}
+
+impl TryFrom<u32> for Vlq {
+    type Error = PodError;
+
+    #[inline]
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<T: ZigZagInt + Default> Zeroable for ZigZag<T> {
+    #[inline]
+    fn zeroed() -> Self {
+        Self(T::default())
+    }
+}
+
+impl<T: ZigZagInt> From<T> for ZigZag<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn var_int_uleb128() {
+        // Read success
+        assert_eq!(Uleb128::decode(&[0x00]), Ok((Uleb128::new(0), 1)));
+        assert_eq!(Uleb128::decode(&[0xe5, 0x8e, 0x26, 0xff]), Ok((Uleb128::new(624485), 3)));
+        assert_eq!(
+            Uleb128::decode(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]),
+            Ok((Uleb128::new(u64::MAX), 10))
+        );
+
+        // Read fail
+        assert_eq!(Uleb128::decode(&[0xe5, 0x8e]), Err(PodError::OutOfSpace));
+        assert_eq!(Uleb128::decode(&[0x80, 0x00]), Err(PodError::OutOfRange));
+        assert_eq!(
+            Uleb128::decode(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02]),
+            Err(PodError::OutOfRange)
+        );
+        assert_eq!(Uleb128::decode(&[0x80; 16]), Err(PodError::OutOfRange));
+
+        // Write
+        let mut buffer_write = [0u8; 3];
+
+        assert_eq!(Uleb128::new(624485).encoded_len(), 3);
+        assert_eq!(Uleb128::new(624485).encode(&mut buffer_write), Ok(3));
+        assert_eq!(buffer_write, [0xe5, 0x8e, 0x26]);
+        assert_eq!(Uleb128::new(624485).encode(&mut buffer_write[1..]), Err(PodError::OutOfSpace));
+    }
+
+    #[test]
+    fn var_int_sleb128() {
+        // Read success
+        assert_eq!(Sleb128::decode(&[0x7f]), Ok((Sleb128::new(-1), 1)));
+        assert_eq!(Sleb128::decode(&[0xc0, 0xbb, 0x78]), Ok((Sleb128::new(-123456), 3)));
+        assert_eq!(Sleb128::decode(&[0x80, 0x01]), Ok((Sleb128::new(128), 2)));
+        assert_eq!(
+            Sleb128::decode(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f]),
+            Ok((Sleb128::new(i64::MIN), 10))
+        );
+
+        // Read fail
+        assert_eq!(Sleb128::decode(&[0xff, 0x7f]), Err(PodError::OutOfRange));
+        assert_eq!(Sleb128::decode(&[0x80, 0x00]), Err(PodError::OutOfRange));
+        assert_eq!(
+            Sleb128::decode(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01]),
+            Err(PodError::OutOfRange)
+        );
+
+        // Write
+        let mut buffer_write = [0u8; 10];
+
+        assert_eq!(Sleb128::new(-123456).encode(&mut buffer_write), Ok(3));
+        assert_eq!(buffer_write[..3], [0xc0, 0xbb, 0x78]);
+        assert_eq!(Sleb128::new(64).encoded_len(), 2);
+        assert_eq!(Sleb128::new(-64).encoded_len(), 1);
+        assert_eq!(Sleb128::new(i64::MIN).encode(&mut buffer_write), Ok(10));
+        assert_eq!(Sleb128::decode(&buffer_write), Ok((Sleb128::new(i64::MIN), 10)));
+    }
+
+    #[test]
+    fn var_int_zigzag() {
+        assert_eq!(ZigZag::new(0i32).encoded_len(), 1);
+        assert_eq!((-1i32).zigzag(), 1);
+        assert_eq!(1i32.zigzag(), 2);
+        assert_eq!(i32::MIN.zigzag(), u32::MAX as u64);
+        assert_eq!(i64::unzigzag(3), -2);
+        assert_eq!(<ZigZag<i32> as VarInt>::MAX_SIZE, 5);
+
+        let mut buffer_write = [0u8; 5];
+
+        assert_eq!(ZigZag::new(-300i32).encode(&mut buffer_write), Ok(2));
+        assert_eq!(ZigZag::<i32>::decode(&buffer_write), Ok((ZigZag::new(-300), 2)));
+        assert_eq!(ZigZag::<i8>::decode(&[0x80, 0x02]), Err(PodError::OutOfRange));
+        assert_eq!(ZigZag::<i32>::decode(&[0xff, 0xff, 0xff, 0xff, 0x1f]), Err(PodError::OutOfRange));
+    }
+
+    #[test]
+    fn var_int_vlq() {
+        // Read success
+        assert_eq!(Vlq::decode(&[0x7f]), Ok((Vlq::new(0x7f).unwrap(), 1)));
+        assert_eq!(Vlq::decode(&[0x81, 0x00]), Ok((Vlq::new(0x80).unwrap(), 2)));
+        assert_eq!(Vlq::decode(&[0xff, 0xff, 0xff, 0x7f]), Ok((Vlq::new(Vlq::MAX).unwrap(), 4)));
+
+        // Read fail
+        assert_eq!(Vlq::decode(&[0x80, 0x7f]), Err(PodError::OutOfRange));
+        assert_eq!(Vlq::decode(&[0xff, 0xff, 0xff, 0xff, 0x7f]), Err(PodError::OutOfRange));
+        assert_eq!(Vlq::decode(&[0x81]), Err(PodError::OutOfSpace));
+
+        // Write
+        let mut buffer_write = [0u8; 4];
+
+        assert_eq!(Vlq::new(0x4000).unwrap().encode(&mut buffer_write), Ok(3));
+        assert_eq!(buffer_write[..3], [0x81, 0x80, 0x00]);
+        assert_eq!(Vlq::new(Vlq::MAX + 1), Err(PodError::OutOfRange));
+    }
+
+    #[test]
+    fn var_int_cursors() {
+        let buffer_read = [0xe5u8, 0x8e, 0x26, 0x7f, 0x81, 0x00];
+        let mut cursor = &buffer_read[..];
+
+        assert_eq!(Uleb128::read(&mut cursor).map(u64::from), Ok(624485));
+        assert_eq!(Sleb128::read(&mut cursor).map(i64::from), Ok(-1));
+        assert_eq!(Vlq::read(&mut cursor).map(u32::from), Ok(0x80));
+        assert!(cursor.is_empty());
+        assert_eq!(Uleb128::read(&mut cursor), Err(PodError::OutOfSpace));
+
+        let mut cursor = Cursor::new(buffer_read);
+        cursor.set_position(3);
+
+        assert_eq!(Sleb128::read_cursor(&mut cursor).map(i64::from), Ok(-1));
+        assert_eq!(cursor.position(), 4);
+
+        let mut buffer_write = [0u8; 4];
+        let mut cursor = &mut buffer_write[..];
+
+        assert_eq!(Uleb128::new(300).write(&mut cursor), Ok(()));
+        assert_eq!(Vlq::new(5).unwrap().write(&mut cursor), Ok(()));
+        assert_eq!(cursor.len(), 1);
+        assert_eq!(buffer_write, [0xac, 0x02, 0x05, 0x00]);
+    }
+
+    #[test]
+    fn var_int_dyn_pod() {
+        let buffer_read = [0xacu8, 0x02];
+
+        assert_eq!(<Uleb128 as DynPod>::decode_be(&buffer_read), Ok((Uleb128::new(300), 2)));
+        assert_eq!(DynPod::encoded_len(&ZigZag::new(-1i64)), 1);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::pod::{Pod, PodError};
+
+/// A read-only view over the encoded bytes of a [`Pod`] type,
+/// decoding fields on demand
+///
+/// Views are generated by `#[derive(Pod)]` on structs with
+/// `#[pod(view)]`, with a getter per field.
+pub trait PodView<'a>: Sized {
+    /// The type the bytes encode
+    type Target: Pod;
+
+    /// Create a view over little endian bytes, failing with
+    /// [`PodError::OutOfSpace`] if there are fewer than `Target::SIZE`
+    fn new_le(bytes: &'a [u8]) -> Result<Self, PodError>;
+
+    /// Create a view over big endian bytes, failing with
+    /// [`PodError::OutOfSpace`] if there are fewer than `Target::SIZE`
+    fn new_be(bytes: &'a [u8]) -> Result<Self, PodError>;
+
+    /// Get the bytes the view covers
+    fn as_bytes(&self) -> &'a [u8];
+
+    /// Decode the whole value
+    fn get(&self) -> Result<Self::Target, PodError>;
+}
+
+/// A mutable view over the encoded bytes of a [`Pod`] type,
+/// decoding fields on demand and re-encoding them in place
+///
+/// Mutable views are generated alongside [`PodView`]s, with a
+/// getter and a setter per field.
+pub trait PodViewMut<'a>: Sized {
+    /// The type the bytes encode
+    type Target: Pod;
+
+    /// Create a view over little endian bytes, failing with
+    /// [`PodError::OutOfSpace`] if there are fewer than `Target::SIZE`
+    fn new_le(bytes: &'a mut [u8]) -> Result<Self, PodError>;
+
+    /// Create a view over big endian bytes, failing with
+    /// [`PodError::OutOfSpace`] if there are fewer than `Target::SIZE`
+    fn new_be(bytes: &'a mut [u8]) -> Result<Self, PodError>;
+
+    /// Get the bytes the view covers
+    fn as_bytes(&self) -> &[u8];
+
+    /// Decode the whole value
+    fn get(&self) -> Result<Self::Target, PodError>;
+
+    /// Encode the whole value in place
+    fn set(&mut self, value: &Self::Target) -> Result<(), PodError>;
+}
+
+/// A [`Pod`] type with generated views
+pub trait HasView: Pod {
+    /// Read-only view type
+    type View<'a>: PodView<'a, Target = Self>;
+
+    /// Mutable view type
+    type ViewMut<'a>: PodViewMut<'a, Target = Self>;
+}
@@ -121,6 +121,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn zeroed_bool() {
         assert_eq!(bool::zeroed(), false);
     }